@@ -0,0 +1,214 @@
+/**/
+
+use crate::ParseError;
+use crate::Part;
+use crate::Puzzle;
+
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage
+{
+	Parse,
+	One,
+	Two,
+}
+
+impl std::fmt::Display for Stage
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		match self
+		{
+			Stage::Parse => f.pad("parse"),
+			Stage::One => f.pad("one"),
+			Stage::Two => f.pad("two"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats
+{
+	pub runs: usize,
+	pub min: Duration,
+	pub median: Duration,
+	pub p95: Duration,
+}
+
+impl Stats
+{
+	pub fn from_samples(samples: &mut [Duration]) -> Stats
+	{
+		assert!(!samples.is_empty());
+		samples.sort();
+		let n = samples.len();
+		let median = if n % 2 == 1
+		{
+			samples[n / 2]
+		}
+		else
+		{
+			(samples[n / 2 - 1] + samples[n / 2]) / 2
+		};
+		// The nearest-rank percentile, so the p95 of few runs is the maximum.
+		let p95 = samples[(n * 95).div_ceil(100) - 1];
+		Stats {
+			runs: n,
+			min: samples[0],
+			median,
+			p95,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement
+{
+	pub name: &'static str,
+	pub stage: Stage,
+	pub stats: Stats,
+}
+
+/// Times parsing and the given parts of a puzzle, each `runs` times over,
+/// solving them with the implementation of that name.
+pub fn measure(
+	puzzle: &Puzzle,
+	input: &str,
+	parts: &[Part],
+	implementation: &str,
+	runs: usize,
+) -> Result<Vec<Measurement>, ParseError>
+{
+	let parsed = puzzle.parse(input)?;
+	let mut measurements = Vec::new();
+	let mut samples = time_runs(runs, || {
+		black_box(puzzle.parse(black_box(input)).ok());
+	});
+	measurements.push(Measurement {
+		name: puzzle.name,
+		stage: Stage::Parse,
+		stats: Stats::from_samples(&mut samples),
+	});
+	for &part in parts
+	{
+		let stage = match part
+		{
+			Part::One => Stage::One,
+			Part::Two => Stage::Two,
+		};
+		// There is no second part on the last day. An input without an
+		// answer takes as long to find out about as any other.
+		if !puzzle.has_variant(implementation, part)
+		{
+			continue;
+		}
+		black_box(puzzle.solve_variant(implementation, part, &parsed));
+		let mut samples = time_runs(runs, || {
+			black_box(puzzle.solve_variant(
+				implementation,
+				part,
+				black_box(&parsed),
+			));
+		});
+		measurements.push(Measurement {
+			name: puzzle.name,
+			stage,
+			stats: Stats::from_samples(&mut samples),
+		});
+	}
+	Ok(measurements)
+}
+
+fn time_runs(runs: usize, mut f: impl FnMut()) -> Vec<Duration>
+{
+	(0..runs)
+		.map(|_| {
+			let start = Instant::now();
+			f();
+			start.elapsed()
+		})
+		.collect()
+}
+
+/// Formats a duration with three significant digits, such as "12.3µs".
+pub fn format_duration(duration: Duration) -> String
+{
+	let nanos = duration.as_nanos() as f64;
+	let (value, unit) = if nanos < 1e3
+	{
+		(nanos, "ns")
+	}
+	else if nanos < 1e6
+	{
+		(nanos / 1e3, "µs")
+	}
+	else if nanos < 1e9
+	{
+		(nanos / 1e6, "ms")
+	}
+	else
+	{
+		(nanos / 1e9, "s")
+	};
+	let precision = if unit == "ns"
+	{
+		0
+	}
+	else if value < 10.0
+	{
+		2
+	}
+	else if value < 100.0
+	{
+		1
+	}
+	else
+	{
+		0
+	};
+	format!("{:.*}{}", precision, value, unit)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	fn micros(values: &[u64]) -> Vec<Duration>
+	{
+		values.iter().map(|&x| Duration::from_micros(x)).collect()
+	}
+
+	#[test]
+	fn stats_of_odd_runs()
+	{
+		let stats = Stats::from_samples(&mut micros(&[5, 1, 4, 2, 3]));
+		assert_eq!(stats.runs, 5);
+		assert_eq!(stats.min, Duration::from_micros(1));
+		assert_eq!(stats.median, Duration::from_micros(3));
+		assert_eq!(stats.p95, Duration::from_micros(5));
+	}
+
+	#[test]
+	fn stats_of_many_runs()
+	{
+		let mut samples = micros(&(1..=40).rev().collect::<Vec<u64>>());
+		let stats = Stats::from_samples(&mut samples);
+		assert_eq!(stats.median, Duration::from_nanos(20500));
+		assert_eq!(stats.p95, Duration::from_micros(38));
+	}
+
+	#[test]
+	fn format_durations()
+	{
+		assert_eq!(format_duration(Duration::from_nanos(56)), "56ns");
+		assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+		assert_eq!(format_duration(Duration::from_nanos(12345)), "12.3µs");
+		assert_eq!(format_duration(Duration::from_micros(4567)), "4.57ms");
+		assert_eq!(format_duration(Duration::from_millis(1500)), "1.50s");
+	}
+}
@@ -0,0 +1,296 @@
+/**/
+
+use crate::report::print_header;
+use crate::report::print_row;
+use crate::Command;
+use crate::Format;
+use crate::Options;
+
+use aoc2022::day01;
+use aoc2022::day01::Distribution;
+use aoc2022::day01::Elf;
+use aoc2022::input::Source;
+use aoc2022::json;
+use aoc2022::json::Value;
+
+const DEFAULT_PERCENTILES: [f64; 4] = [25.0, 75.0, 90.0, 99.0];
+// The longest bar of a histogram.
+const HISTOGRAM_WIDTH: usize = 40;
+
+// What the calories command reports instead of the ranking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Report
+{
+	Stats,
+	Histogram(usize),
+	Outliers,
+}
+
+pub fn set_report(options: &mut Options, report: Report) -> Result<(), String>
+{
+	if options.report.is_some()
+	{
+		return Err(
+			"cannot combine --stats, --histogram and --outliers".to_string()
+		);
+	}
+	options.report = Some(report);
+	Ok(())
+}
+
+pub fn check_options(options: &Options) -> Result<(), String>
+{
+	let allowed = Options {
+		command: Command::Calories,
+		source: options.source.clone(),
+		format: options.format,
+		top: options.top,
+		report: options.report,
+		percentiles: options.percentiles.clone(),
+		..Default::default()
+	};
+	crate::check_only(
+		options,
+		allowed,
+		"calories takes only --input, --input-dir, --top, --stats, \
+		 --percentile, --histogram, --outliers and --format",
+	)?;
+	if options.top.is_some() && options.report.is_some()
+	{
+		return Err("cannot use --top with --stats, --histogram or --outliers"
+			.to_string());
+	}
+	if !options.percentiles.is_empty() && options.report != Some(Report::Stats)
+	{
+		return Err("cannot use --percentile without --stats".to_string());
+	}
+	Ok(())
+}
+
+pub fn run(options: Options) -> Result<(), String>
+{
+	let source = options.source.unwrap_or_else(Source::from_env);
+	let format = options.format.unwrap_or(Format::Text);
+	let reader = source.open("day01").map_err(|error| error.to_string())?;
+	let elves = day01::read_elves(reader);
+	match options.report
+	{
+		None => report_ranking(elves, options.top, format),
+		Some(Report::Stats) =>
+		{
+			report_stats(elves, &options.percentiles, format)
+		}
+		Some(Report::Histogram(num_buckets)) =>
+		{
+			report_histogram(elves, num_buckets, format)
+		}
+		Some(Report::Outliers) => report_outliers(elves, format),
+	}
+}
+
+type Elves = day01::Elves<Box<dyn std::io::BufRead>>;
+
+fn report_ranking(
+	elves: Elves,
+	top: Option<usize>,
+	format: Format,
+) -> Result<(), String>
+{
+	let mut top = day01::TopK::new(top.unwrap_or(usize::MAX));
+	for elf in elves
+	{
+		top.push(elf.map_err(|error| error.to_string())?);
+	}
+	print_header(
+		format,
+		format_args!(
+			"{:>5} {:>5} {:>5} {:>9}",
+			"rank", "elf", "items", "total"
+		),
+	);
+	for ranked in top.into_ranked()
+	{
+		let elf = ranked.elf;
+		let fields = [
+			("rank", ranked.rank.into()),
+			("elf", elf.number.into()),
+			("items", elf.num_items.into()),
+			("total", elf.total.into()),
+		];
+		print_row(
+			format,
+			&fields,
+			format_args!(
+				"{:>5} {:>5} {:>5} {:>9}",
+				ranked.rank, elf.number, elf.num_items, elf.total
+			),
+		);
+	}
+	Ok(())
+}
+
+fn read_distribution(elves: Elves) -> Result<Distribution, String>
+{
+	let elves: Vec<Elf> = elves
+		.collect::<Result<_, _>>()
+		.map_err(|error| error.to_string())?;
+	Distribution::new(elves).ok_or_else(|| "there are no elves".to_string())
+}
+
+fn report_stats(
+	elves: Elves,
+	percentiles: &[f64],
+	format: Format,
+) -> Result<(), String>
+{
+	let distribution = read_distribution(elves)?;
+	let percentiles = match percentiles
+	{
+		[] => &DEFAULT_PERCENTILES[..],
+		percentiles => percentiles,
+	};
+	let mut fields: Vec<(String, Value)> = vec![
+		("elves".to_string(), distribution.count().into()),
+		("min".to_string(), distribution.min().into()),
+		("max".to_string(), distribution.max().into()),
+		("mean".to_string(), distribution.mean().into()),
+		("std_dev".to_string(), distribution.std_dev().into()),
+		("median".to_string(), distribution.median().into()),
+	];
+	for &p in percentiles
+	{
+		fields.push((format!("p{}", p), distribution.percentile(p).into()));
+	}
+	if format == Format::Json
+	{
+		let fields: Vec<(&str, Value)> = fields
+			.iter()
+			.map(|(key, value)| (key.as_str(), value.clone()))
+			.collect();
+		println!("{}", json::object(&fields));
+		return Ok(());
+	}
+	for (key, value) in fields
+	{
+		let label = key.replace('_', " ");
+		match value
+		{
+			Value::Float(x) => println!("{:<8} {:>12.1}", label, x),
+			value => println!("{:<8} {:>12}", label, value.to_string()),
+		}
+	}
+	Ok(())
+}
+
+fn report_histogram(
+	elves: Elves,
+	num_buckets: usize,
+	format: Format,
+) -> Result<(), String>
+{
+	let distribution = read_distribution(elves)?;
+	let histogram = distribution.histogram(num_buckets);
+	let max_count = histogram.iter().map(|bucket| bucket.count).max();
+	let max_count = max_count.unwrap_or_default().max(1);
+	print_header(
+		format,
+		format_args!("{:>9} {:>9} {:>5}", "low", "high", "elves"),
+	);
+	for bucket in histogram
+	{
+		let fields = [
+			("low", bucket.low.into()),
+			("high", bucket.high.into()),
+			("elves", bucket.count.into()),
+		];
+		let length = (bucket.count * HISTOGRAM_WIDTH).div_ceil(max_count);
+		print_row(
+			format,
+			&fields,
+			format_args!(
+				"{:>9} {:>9} {:>5}  {}",
+				bucket.low,
+				bucket.high,
+				bucket.count,
+				"#".repeat(length)
+			),
+		);
+	}
+	Ok(())
+}
+
+fn report_outliers(elves: Elves, format: Format) -> Result<(), String>
+{
+	let distribution = read_distribution(elves)?;
+	let fences = distribution.fences();
+	let outliers = distribution.outliers();
+	print_header(
+		format,
+		format_args!(
+			"Outliers carry fewer than {} or more than {} calories",
+			fences.low, fences.high
+		),
+	);
+	print_header(
+		format,
+		format_args!("{:>5} {:>5} {:>9}", "elf", "items", "total"),
+	);
+	for elf in &outliers
+	{
+		let fields = [
+			("elf", elf.number.into()),
+			("items", elf.num_items.into()),
+			("total", elf.total.into()),
+		];
+		print_row(
+			format,
+			&fields,
+			format_args!(
+				"{:>5} {:>5} {:>9}",
+				elf.number, elf.num_items, elf.total
+			),
+		);
+	}
+	if format == Format::Json
+	{
+		let fields = [
+			("low_fence", fences.low.into()),
+			("high_fence", fences.high.into()),
+			("outliers", outliers.len().into()),
+		];
+		println!("{}", json::object(&fields));
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	fn elves(input: &'static str) -> Elves
+	{
+		let reader: Box<dyn std::io::BufRead> = Box::new(input.as_bytes());
+		day01::read_elves(reader)
+	}
+
+	#[test]
+	fn stats_without_elves()
+	{
+		for input in ["", "\n", "\n\n"]
+		{
+			let error = read_distribution(elves(input)).unwrap_err();
+			assert_eq!(error, "there are no elves");
+		}
+	}
+
+	#[test]
+	fn stats_with_trailing_blank_line()
+	{
+		let distribution = read_distribution(elves("1\n2\n\n3\n\n")).unwrap();
+		assert_eq!(distribution.count(), 2);
+		assert_eq!(distribution.min(), 3);
+		assert_eq!(distribution.median(), 3.0);
+	}
+}
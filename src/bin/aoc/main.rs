@@ -0,0 +1,1766 @@
+/**/
+
+mod calories;
+mod report;
+mod strategy;
+
+use aoc2022::bench;
+use aoc2022::frames;
+use aoc2022::generate;
+use aoc2022::input::Source;
+use aoc2022::json;
+use aoc2022::json::Value;
+use aoc2022::parallel;
+use aoc2022::parallel::Status;
+use aoc2022::scaffold;
+use aoc2022::solution;
+use aoc2022::trace;
+use aoc2022::trace::Level;
+use aoc2022::verify;
+use aoc2022::verify::Answers;
+use aoc2022::verify::Verdict;
+use aoc2022::Answer;
+use aoc2022::Part;
+use aoc2022::Puzzle;
+use aoc2022::PUZZLES;
+use calories::Report;
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+const USAGE: &str = "Usage:
+  aoc run <DAY|all> [--part <1|2>] [--input <PATH>] [--input-dir <DIR>]
+                    [--verify] [--record] [--answers <PATH>]
+                    [--format <text|json>] [--jobs <N>] [--timeout <SECONDS>]
+                    [--impl <NAME>] [--cross-check]
+  aoc bench <DAY|all> [--part <1|2>] [--input <PATH>] [--input-dir <DIR>]
+                      [--runs <N>] [--format <table|tsv|json>] [--impl <NAME>]
+  aoc new-day <DAY|NAME>
+  aoc generate <DAY> [--seed <N>] [--scale <N>]
+  aoc calories [--input <PATH>] [--input-dir <DIR>] [--top <N>]
+               [--stats [--percentile <P>]...] [--histogram <BUCKETS>]
+               [--outliers] [--format <text|json>]
+  aoc strategy [--input <PATH>] [--input-dir <DIR>] [--rules <PATH>]
+               [--mappings [--target <SCORE>]]
+               [--tournament <ROUNDS> [--seed <N>]] [--format <text|json>]
+
+Both run and bench also take [--log <off|info|debug|trace>], and run also takes
+[--frames <DIR>] [--frame-format <gif|cast|ppm>] [--frame-every <N>].
+
+DAY is a day number (\"16\") or a puzzle name (\"day16\", \"infi\").
+Use \"--input -\" to read the input from stdin. Without --input, each day
+reads DIR/day16.txt or DIR/day16/input.txt, where DIR is --input-dir,
+$AOC_INPUT_DIR or the source tree. Days without an input are skipped.
+A day whose input does not parse or has no answer is reported, and the
+command fails once it has run the other days.
+
+With --verify, each answer is compared against the answer recorded for that
+day, part and input, and reported as pass, fail or unknown. With --record,
+unknown answers are added to the answers file as well. The answers file is
+--answers or DIR/answers.txt.
+
+The bench command times parsing and each part N times (default 10) and
+reports the minimum, median and 95th percentile per day. With --format tsv,
+it prints tab-separated nanoseconds instead.
+
+With --jobs, run solves up to N days at the same time and then prints a
+table with the answers, how long each day took and whether the answers match
+the recorded ones. A day that takes longer than the timeout (default 60
+seconds) is reported and left running in the background until the end.
+Such a day no longer counts towards the N jobs, so more than N days can be
+running at the same time. Days that do not parse or that panic are
+reported as errors.
+
+With --format json, both run and bench print one JSON object per line. For
+each answer, these have the day, part, answer, type (number, text, art or
+letters) and the time taken to parse and to solve in nanoseconds, as well as
+the verdict with --verify. For each stage of a benchmark, they have the same
+statistics as the table.
+
+Some days can solve a part in more than one way, such as with a naive
+solver next to the optimized \"default\" one. With --impl, run and bench use
+the implementation of that name, and skip the days without one. With
+--cross-check, run solves each part with every implementation it has and
+reports whether they give the same answer, failing if any of them do not.
+
+With --log, or $AOC_LOG if it is not given, the solvers report on stderr
+what they are doing, from nothing at all (off, the default) to everything
+(trace). The answers on stdout stay the same.
+
+With --frames, the days that simulate something (09, 14, 17, 22, 23 and 24)
+save what they render along the way as DIR/day14-part1.gif, an asciicast
+(.cast) or a directory of PPM images. With --frame-every, only every Nth
+frame is kept.
+
+The new-day command copies src/template to src/day26 (or src/NAME for a
+side event such as \"infi\"), with empty provided.txt and input.txt files,
+and registers it in src/lib.rs. After a rebuild, the new day is part of
+\"run all\" and can be verified and recorded like the others. Its tests
+check the example in provided.txt against src/answers.txt, once its answers
+are recorded with \"aoc run DAY --input src/DAY/provided.txt --record
+--answers src/answers.txt\".
+
+The generate command prints a random input for some of the days, the same
+for the same seed (default 0). The scale is the number of lines or the
+width of the grid, and defaults to about the size of an actual input. Pipe
+it into \"aoc run DAY --input -\" to solve it.
+
+The calories command reads the input of day 01 one line at a time and ranks
+the elves by the calories they carry, listing the number of each elf (from
+1), how many items they carry and their total. With --top, only the N elves
+with the most calories are listed. Instead of the ranking, --stats describes
+the distribution of the totals: the count, minimum, maximum, mean, standard
+deviation, median and some percentiles, or the ones given with --percentile.
+With --histogram, the range of totals is split into up to BUCKETS buckets of
+equal width, and with --outliers, it lists the elves that carry more than one
+and a half times the interquartile range above the third quartile, or as far
+below the first.
+
+The strategy command scores the strategy guide of day 02 twice: once with
+the second column as the move to play, and once as how the round should end.
+With --rules, it plays another game from a file such as src/day02/rpsls.txt,
+which lists the moves in a cycle where each one beats the half of the others
+right before it, the symbols of both columns, what they mean and how rounds
+are scored. With --mappings, it scores every way to read the second column as
+distinct moves, as well as the desired outcomes, from the highest score to the
+lowest, and names the best one. With --target, it lists only the mappings
+that give that score. With --tournament, it plays matches of that many rounds
+between each two of four strategies: playing the moves of the guide over and
+over, beating the move the opponent played most often, beating the move that
+most often followed the opponent's last move, and playing at random with the
+given seed (default 0). It reports how often each of them won and their
+average score per round.";
+
+const DEFAULT_RUNS: usize = 10;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+// Longer answers are cut short in the summary table.
+const SUMMARY_WIDTH: usize = 20;
+
+pub fn main()
+{
+	let args: Vec<String> = std::env::args().skip(1).collect();
+	let options = match parse_options(&args)
+	{
+		Ok(options) => options,
+		Err(message) =>
+		{
+			eprintln!("Error: {}", message);
+			eprintln!();
+			eprintln!("{}", USAGE);
+			std::process::exit(1);
+		}
+	};
+	match run(options)
+	{
+		Ok(()) => (),
+		Err(message) =>
+		{
+			eprintln!("Error: {}", message);
+			std::process::exit(1);
+		}
+	}
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Command
+{
+	#[default]
+	Run,
+	Bench,
+	NewDay,
+	Generate,
+	Calories,
+	Strategy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format
+{
+	Text,
+	Table,
+	Tsv,
+	Json,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Options
+{
+	command: Command,
+	selection: Option<String>,
+	part: Option<Part>,
+	source: Option<Source>,
+	verify: bool,
+	record: bool,
+	answers: Option<PathBuf>,
+	runs: Option<usize>,
+	format: Option<Format>,
+	log: Option<Level>,
+	frames: Option<PathBuf>,
+	frame_format: Option<frames::Format>,
+	frame_every: Option<usize>,
+	seed: Option<u64>,
+	scale: Option<usize>,
+	jobs: Option<usize>,
+	timeout: Option<Duration>,
+	implementation: Option<String>,
+	cross_check: bool,
+	top: Option<usize>,
+	report: Option<Report>,
+	percentiles: Vec<f64>,
+	rules: Option<PathBuf>,
+	mappings: bool,
+	target: Option<i64>,
+	tournament: Option<usize>,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String>
+{
+	let mut args = args.iter();
+	let command = match args.next().map(|x| x.as_str())
+	{
+		Some("run") => Command::Run,
+		Some("bench") => Command::Bench,
+		Some("new-day") => Command::NewDay,
+		Some("generate") => Command::Generate,
+		Some("calories") => Command::Calories,
+		Some("strategy") => Command::Strategy,
+		Some(command) => return Err(format!("unknown command '{}'", command)),
+		None => return Err("missing command".to_string()),
+	};
+	let mut options = Options {
+		command,
+		..Default::default()
+	};
+	while let Some(arg) = args.next()
+	{
+		match arg.as_str()
+		{
+			"--part" | "-p" =>
+			{
+				let value = args.next().ok_or("missing value for --part")?;
+				let part = match value.as_str()
+				{
+					"1" | "one" => Part::One,
+					"2" | "two" => Part::Two,
+					_ => return Err(format!("invalid part '{}'", value)),
+				};
+				options.part = Some(part);
+			}
+			"--input" | "-i" =>
+			{
+				let value = args.next().ok_or("missing value for --input")?;
+				options.source = Some(Source::from_arg(value));
+			}
+			"--input-dir" =>
+			{
+				let value =
+					args.next().ok_or("missing value for --input-dir")?;
+				options.source = Some(Source::Directory(value.into()));
+			}
+			"--verify" => options.verify = true,
+			"--record" =>
+			{
+				options.verify = true;
+				options.record = true;
+			}
+			"--answers" =>
+			{
+				let value = args.next().ok_or("missing value for --answers")?;
+				options.answers = Some(value.into());
+			}
+			"--runs" | "-n" =>
+			{
+				let value = args.next().ok_or("missing value for --runs")?;
+				let runs = match value.parse()
+				{
+					Ok(runs) if runs > 0 => runs,
+					_ =>
+					{
+						return Err(format!(
+							"invalid number of runs '{}'",
+							value
+						))
+					}
+				};
+				options.runs = Some(runs);
+			}
+			"--format" =>
+			{
+				let value = args.next().ok_or("missing value for --format")?;
+				let format = match value.as_str()
+				{
+					"text" => Format::Text,
+					"table" => Format::Table,
+					"tsv" => Format::Tsv,
+					"json" => Format::Json,
+					_ => return Err(format!("invalid format '{}'", value)),
+				};
+				options.format = Some(format);
+			}
+			"--log" =>
+			{
+				let value = args.next().ok_or("missing value for --log")?;
+				let level = value
+					.parse()
+					.map_err(|_| format!("invalid log level '{}'", value))?;
+				options.log = Some(level);
+			}
+			"--frames" =>
+			{
+				let value = args.next().ok_or("missing value for --frames")?;
+				options.frames = Some(value.into());
+			}
+			"--frame-format" =>
+			{
+				let value =
+					args.next().ok_or("missing value for --frame-format")?;
+				let format = value
+					.parse()
+					.map_err(|_| format!("invalid frame format '{}'", value))?;
+				options.frame_format = Some(format);
+			}
+			"--frame-every" =>
+			{
+				let value =
+					args.next().ok_or("missing value for --frame-every")?;
+				let every = match value.parse()
+				{
+					Ok(every) if every > 0 => every,
+					_ =>
+					{
+						return Err(format!(
+							"invalid number of frames '{}'",
+							value
+						))
+					}
+				};
+				options.frame_every = Some(every);
+			}
+			"--seed" =>
+			{
+				let value = args.next().ok_or("missing value for --seed")?;
+				let seed = value
+					.parse()
+					.map_err(|_| format!("invalid seed '{}'", value))?;
+				options.seed = Some(seed);
+			}
+			"--scale" =>
+			{
+				let value = args.next().ok_or("missing value for --scale")?;
+				let scale = value
+					.parse()
+					.map_err(|_| format!("invalid scale '{}'", value))?;
+				options.scale = Some(scale);
+			}
+			"--jobs" | "-j" =>
+			{
+				let value = args.next().ok_or("missing value for --jobs")?;
+				let jobs = match value.parse()
+				{
+					Ok(jobs) if jobs > 0 => jobs,
+					_ =>
+					{
+						return Err(format!(
+							"invalid number of jobs '{}'",
+							value
+						))
+					}
+				};
+				options.jobs = Some(jobs);
+			}
+			"--timeout" =>
+			{
+				let value = args.next().ok_or("missing value for --timeout")?;
+				let timeout = match value.parse::<f64>()
+				{
+					Ok(seconds) if seconds > 0.0 && seconds.is_finite() =>
+					{
+						Duration::from_secs_f64(seconds)
+					}
+					_ => return Err(format!("invalid timeout '{}'", value)),
+				};
+				options.timeout = Some(timeout);
+			}
+			"--impl" =>
+			{
+				let value = args.next().ok_or("missing value for --impl")?;
+				options.implementation = Some(value.to_string());
+			}
+			"--cross-check" => options.cross_check = true,
+			"--top" =>
+			{
+				let value = args.next().ok_or("missing value for --top")?;
+				let top = value.parse().map_err(|_| {
+					format!("invalid number of elves '{}'", value)
+				})?;
+				options.top = Some(top);
+			}
+			"--stats" => calories::set_report(&mut options, Report::Stats)?,
+			"--histogram" =>
+			{
+				let value =
+					args.next().ok_or("missing value for --histogram")?;
+				let num_buckets = match value.parse()
+				{
+					Ok(num_buckets) if num_buckets > 0 => num_buckets,
+					_ =>
+					{
+						return Err(format!(
+							"invalid number of buckets '{}'",
+							value
+						))
+					}
+				};
+				calories::set_report(
+					&mut options,
+					Report::Histogram(num_buckets),
+				)?;
+			}
+			"--outliers" =>
+			{
+				calories::set_report(&mut options, Report::Outliers)?
+			}
+			"--rules" =>
+			{
+				let value = args.next().ok_or("missing value for --rules")?;
+				options.rules = Some(value.into());
+			}
+			"--mappings" => options.mappings = true,
+			"--tournament" =>
+			{
+				let value =
+					args.next().ok_or("missing value for --tournament")?;
+				let num_rounds = match value.parse()
+				{
+					Ok(num_rounds) if num_rounds > 0 => num_rounds,
+					_ =>
+					{
+						return Err(format!(
+							"invalid number of rounds '{}'",
+							value
+						))
+					}
+				};
+				options.tournament = Some(num_rounds);
+			}
+			"--target" =>
+			{
+				let value = args.next().ok_or("missing value for --target")?;
+				let target = value
+					.parse()
+					.map_err(|_| format!("invalid score '{}'", value))?;
+				options.target = Some(target);
+			}
+			"--percentile" =>
+			{
+				let value =
+					args.next().ok_or("missing value for --percentile")?;
+				let p = match value.parse::<f64>()
+				{
+					Ok(p) if p > 0.0 && p <= 100.0 => p,
+					_ => return Err(format!("invalid percentile '{}'", value)),
+				};
+				options.percentiles.push(p);
+			}
+			flag if flag.starts_with('-') =>
+			{
+				return Err(format!("unknown option '{}'", flag));
+			}
+			_ if options.selection.is_none() =>
+			{
+				options.selection = Some(arg.to_string());
+			}
+			_ => return Err(format!("unexpected argument '{}'", arg)),
+		}
+	}
+	// The summary of --jobs checks the recorded answers as well.
+	if options.answers.is_some() && !options.verify && options.jobs.is_none()
+	{
+		return Err("cannot use --answers without --verify".to_string());
+	}
+	if options.timeout.is_some() && options.jobs.is_none()
+	{
+		return Err("cannot use --timeout without --jobs".to_string());
+	}
+	if options.frames.is_none()
+		&& (options.frame_format.is_some() || options.frame_every.is_some())
+	{
+		return Err("cannot use --frame-format or --frame-every without \
+		            --frames"
+			.to_string());
+	}
+	match options.command
+	{
+		Command::Run if options.runs.is_some() =>
+		{
+			return Err("cannot use --runs without bench".to_string());
+		}
+		Command::Run | Command::Calories | Command::Strategy
+			if matches!(options.format, Some(Format::Table | Format::Tsv)) =>
+		{
+			return Err(
+				"cannot use --format table or tsv without bench".to_string()
+			);
+		}
+		Command::Bench if options.format == Some(Format::Text) =>
+		{
+			return Err("cannot use --format text with bench".to_string());
+		}
+		Command::Bench if options.verify =>
+		{
+			return Err("cannot use --verify with bench".to_string());
+		}
+		Command::Bench if options.frames.is_some() =>
+		{
+			return Err("cannot use --frames with bench".to_string());
+		}
+		Command::Bench if options.jobs.is_some() =>
+		{
+			return Err("cannot use --jobs with bench".to_string());
+		}
+		Command::Bench if options.cross_check =>
+		{
+			return Err("cannot use --cross-check with bench".to_string());
+		}
+		Command::Run
+			if options.cross_check
+				&& (options.verify
+					|| options.jobs.is_some()
+					|| options.frames.is_some()
+					|| options.implementation.is_some()) =>
+		{
+			return Err("cannot use --cross-check with --verify, --jobs, \
+			            --frames or --impl"
+				.to_string());
+		}
+		Command::Run if options.jobs.is_some() && options.frames.is_some() =>
+		{
+			return Err("cannot use --frames with --jobs".to_string());
+		}
+		Command::Run if options.verify && options.frames.is_some() =>
+		{
+			return Err("cannot use --frames with --verify".to_string());
+		}
+		_ => (),
+	}
+	if options.command == Command::Calories
+	{
+		calories::check_options(&options)?;
+		return Ok(options);
+	}
+	if options.command == Command::Strategy
+	{
+		strategy::check_options(&options)?;
+		return Ok(options);
+	}
+	if options.rules.is_some()
+		|| options.mappings
+		|| options.target.is_some()
+		|| options.tournament.is_some()
+	{
+		return Err("cannot use --rules, --mappings, --target or \
+		            --tournament without strategy"
+			.to_string());
+	}
+	if options.top.is_some()
+		|| options.report.is_some()
+		|| !options.percentiles.is_empty()
+	{
+		return Err("cannot use --top, --stats, --percentile, --histogram or \
+		            --outliers without calories"
+			.to_string());
+	}
+	let selection = options.selection.as_deref().ok_or("missing day")?;
+	if options.command == Command::NewDay
+	{
+		let allowed = Options {
+			command: Command::NewDay,
+			selection: options.selection.clone(),
+			..Default::default()
+		};
+		check_only(&options, allowed, "new-day takes no options")?;
+		scaffold::puzzle_name(selection)?;
+	}
+	else if options.command == Command::Generate
+	{
+		let allowed = Options {
+			command: Command::Generate,
+			selection: options.selection.clone(),
+			seed: options.seed,
+			scale: options.scale,
+			..Default::default()
+		};
+		check_only(
+			&options,
+			allowed,
+			"generate takes only --seed and --scale",
+		)?;
+		if generate::find_generator(selection).is_none()
+		{
+			return Err(format!("no generator for '{}'", selection));
+		}
+	}
+	else if options.seed.is_some() || options.scale.is_some()
+	{
+		return Err("cannot use --seed or --scale without generate".to_string());
+	}
+	else if selection == "all"
+	{
+		if let Some(Source::File(_) | Source::Stdin) = options.source
+		{
+			return Err("cannot use --input with all days".to_string());
+		}
+	}
+	else if aoc2022::find_puzzle(selection).is_none()
+	{
+		return Err(format!("unknown day '{}'", selection));
+	}
+	Ok(options)
+}
+
+// A command that takes only some of the options lists them in `allowed` and
+// leaves the rest at their defaults.
+fn check_only(
+	options: &Options,
+	allowed: Options,
+	message: &str,
+) -> Result<(), String>
+{
+	if *options != allowed
+	{
+		return Err(message.to_string());
+	}
+	Ok(())
+}
+
+fn run(options: Options) -> Result<(), String>
+{
+	if options.command == Command::NewDay
+	{
+		let selection = options.selection.as_deref().unwrap_or_default();
+		return create_new_day(selection);
+	}
+	if options.command == Command::Generate
+	{
+		let selection = options.selection.as_deref().unwrap_or_default();
+		let generator = generate::find_generator(selection)
+			.ok_or_else(|| format!("no generator for '{}'", selection))?;
+		let seed = options.seed.unwrap_or_default();
+		let scale = options.scale.unwrap_or(generator.default_scale);
+		print!("{}", generator.generate(seed, scale));
+		return Ok(());
+	}
+	if options.command == Command::Calories
+	{
+		return calories::run(options);
+	}
+	if options.command == Command::Strategy
+	{
+		return strategy::run(options);
+	}
+	let mut puzzles: Vec<&Puzzle> = match options.selection.as_deref()
+	{
+		Some("all") => PUZZLES.iter().collect(),
+		Some(selection) =>
+		{
+			aoc2022::find_puzzle(selection).into_iter().collect()
+		}
+		None => Vec::new(),
+	};
+	let parts = match options.part
+	{
+		Some(part) => vec![part],
+		None => vec![Part::One, Part::Two],
+	};
+	let level = match options.log
+	{
+		Some(level) => level,
+		None => trace::level_from_env()?.unwrap_or_default(),
+	};
+	trace::set_level(level);
+	let source = options.source.unwrap_or_else(Source::from_env);
+	let implementation = options
+		.implementation
+		.as_deref()
+		.unwrap_or(solution::DEFAULT_VARIANT);
+	select_implementation(&mut puzzles, &parts, implementation)?;
+	if options.cross_check
+	{
+		let format = options.format.unwrap_or(Format::Text);
+		return run_cross_check(&puzzles, &parts, &source, format);
+	}
+	if options.command == Command::Bench
+	{
+		let runs = options.runs.unwrap_or(DEFAULT_RUNS);
+		let format = options.format.unwrap_or(Format::Table);
+		return run_benchmarks(
+			&puzzles,
+			&parts,
+			&source,
+			implementation,
+			runs,
+			format,
+		);
+	}
+	let format = options.format.unwrap_or(Format::Text);
+	if let Some(jobs) = options.jobs
+	{
+		let path = options
+			.answers
+			.clone()
+			.unwrap_or_else(|| default_answers_path(&source));
+		let settings = Settings {
+			jobs,
+			timeout: options.timeout.unwrap_or(DEFAULT_TIMEOUT),
+			record: options.record,
+			format,
+			implementation: implementation.to_string(),
+		};
+		return run_in_parallel(puzzles, &parts, &source, &path, settings);
+	}
+	if options.verify
+	{
+		let path = options
+			.answers
+			.unwrap_or_else(|| default_answers_path(&source));
+		return run_verification(
+			&puzzles,
+			&parts,
+			&source,
+			&path,
+			implementation,
+			options.record,
+			format,
+		);
+	}
+	// Like the other modes, skip the days without an input, but fail if
+	// none of them has one. A day that fails does not stop the others.
+	let mut num_skipped: usize = 0;
+	let mut num_errors: usize = 0;
+	for puzzle in puzzles.iter()
+	{
+		let input = match source.load(puzzle.name)
+		{
+			Ok(input) => input,
+			Err(error) if format == Format::Json =>
+			{
+				let fields = [
+					("day", puzzle.name.into()),
+					("skipped", error.to_string().into()),
+				];
+				println!("{}", json::object(&fields));
+				num_skipped += 1;
+				continue;
+			}
+			Err(error) =>
+			{
+				eprintln!("{}: skipped, {}", puzzle.name, error);
+				num_skipped += 1;
+				continue;
+			}
+		};
+		if puzzles.len() > 1 && format == Format::Text
+		{
+			println!("{}", puzzle.name);
+		}
+		let start = Instant::now();
+		let parsed = match puzzle.parse(&input)
+		{
+			Ok(parsed) => parsed,
+			Err(error) =>
+			{
+				print_error(puzzle.name, None, &error.to_string(), format);
+				num_errors += 1;
+				continue;
+			}
+		};
+		let parse_time = start.elapsed();
+		let mut has_error = false;
+		for part in &parts
+		{
+			if options.frames.is_some()
+			{
+				frames::start(options.frame_every.unwrap_or(1));
+			}
+			let start = Instant::now();
+			let answer = puzzle.solve_variant(implementation, *part, &parsed);
+			let solve_time = start.elapsed();
+			if let Some(dir) = &options.frames
+			{
+				let format = options.frame_format.unwrap_or_default();
+				save_frames(dir, puzzle.name, *part, format)?;
+			}
+			let answer = match answer
+			{
+				Some(answer) => answer,
+				None if puzzle.has_variant(implementation, *part) =>
+				{
+					let error = "no answer for this input";
+					print_error(puzzle.name, Some(*part), error, format);
+					has_error = true;
+					continue;
+				}
+				None => continue,
+			};
+			if format == Format::Json
+			{
+				let mut fields = answer_fields(puzzle.name, *part, &answer);
+				fields.push(("parse_ns", parse_time.into()));
+				fields.push(("solve_ns", solve_time.into()));
+				println!("{}", json::object(&fields));
+			}
+			else
+			{
+				print_answer(*part, &answer);
+			}
+		}
+		if has_error
+		{
+			num_errors += 1;
+		}
+	}
+	if num_skipped == puzzles.len()
+	{
+		return Err("none of the days has an input".to_string());
+	}
+	if num_errors > 0
+	{
+		return Err(format!("{} days failed with an error", num_errors));
+	}
+	Ok(())
+}
+
+// Like skipped days, errors go to stderr unless the output is JSON. Errors
+// that are not about one part come from parsing, and name the day already.
+fn print_error(name: &str, part: Option<Part>, error: &str, format: Format)
+{
+	if format == Format::Json
+	{
+		let mut fields = vec![("day", name.into())];
+		if let Some(part) = part
+		{
+			fields.push(("part", part.number().into()));
+		}
+		fields.push(("error", error.into()));
+		println!("{}", json::object(&fields));
+		return;
+	}
+	match part
+	{
+		Some(part) => eprintln!("{} part {}: {}", name, part, error),
+		None => eprintln!("{}", error),
+	}
+}
+
+// Only the days with the chosen implementation of one of the parts are run,
+// and it is an error if there are none.
+fn select_implementation(
+	puzzles: &mut Vec<&Puzzle>,
+	parts: &[Part],
+	name: &str,
+) -> Result<(), String>
+{
+	if name == solution::DEFAULT_VARIANT
+	{
+		return Ok(());
+	}
+	let has_variant = |puzzle: &Puzzle| {
+		parts
+			.iter()
+			.any(|&part| puzzle.variants(part).contains(&name))
+	};
+	match puzzles.as_slice()
+	{
+		[puzzle] if !has_variant(puzzle) =>
+		{
+			let mut names: Vec<&str> = parts
+				.iter()
+				.flat_map(|&part| puzzle.variants(part))
+				.collect();
+			names.sort();
+			names.dedup();
+			Err(format!(
+				"{} has no implementation '{}', only {}",
+				puzzle.name,
+				name,
+				names.join(", ")
+			))
+		}
+		_ =>
+		{
+			puzzles.retain(|puzzle| has_variant(puzzle));
+			if puzzles.is_empty()
+			{
+				return Err(format!("no day has an implementation '{}'", name));
+			}
+			Ok(())
+		}
+	}
+}
+
+fn save_frames(
+	dir: &Path,
+	name: &str,
+	part: Part,
+	format: frames::Format,
+) -> Result<(), String>
+{
+	let Some(recording) = frames::stop()
+	else
+	{
+		return Ok(());
+	};
+	// Most days have nothing to show.
+	if recording.frames().is_empty()
+	{
+		return Ok(());
+	}
+	let part = part.number();
+	let path = dir.join(format!("{}-part{}", name, part));
+	std::fs::create_dir_all(dir)
+		.and_then(|()| frames::save(recording.frames(), format, &path))
+		.map_err(|error| format!("{}: {}", path.display(), error))?;
+	eprintln!(
+		"Saved {} frames of {} part {}",
+		recording.frames().len(),
+		name,
+		part
+	);
+	Ok(())
+}
+
+fn create_new_day(selection: &str) -> Result<(), String>
+{
+	let name = scaffold::puzzle_name(selection)?;
+	let src = aoc2022::input::default_dir();
+	for path in scaffold::new_puzzle(&src, &name)?
+	{
+		println!("Wrote {}", path.display());
+	}
+	println!(
+		"Rebuild, fill in provided.txt and input.txt, then solve with \"aoc \
+		 run {} --record\".",
+		name
+	);
+	Ok(())
+}
+
+fn default_answers_path(source: &Source) -> PathBuf
+{
+	match source
+	{
+		Source::Directory(dir) => dir.join(verify::ANSWERS_FILENAME),
+		Source::File(_) | Source::Stdin => verify::ANSWERS_FILENAME.into(),
+	}
+}
+
+#[derive(Debug, Default)]
+struct Tally
+{
+	passed: usize,
+	failed: usize,
+	unknown: usize,
+	skipped: usize,
+	// Days that did not parse or that panicked.
+	errors: usize,
+	timed_out: usize,
+}
+
+impl Tally
+{
+	fn count(&mut self, verdict: &Verdict)
+	{
+		match verdict
+		{
+			Verdict::Pass => self.passed += 1,
+			Verdict::Fail { .. } => self.failed += 1,
+			Verdict::Unknown => self.unknown += 1,
+		}
+	}
+
+	fn outcome(&self) -> Result<(), String>
+	{
+		let mut problems = Vec::new();
+		if self.failed > 0
+		{
+			problems.push(format!("{} answers did not match", self.failed));
+		}
+		if self.errors > 0
+		{
+			problems.push(format!("{} days failed with an error", self.errors));
+		}
+		if self.timed_out > 0
+		{
+			problems.push(format!("{} days timed out", self.timed_out));
+		}
+		match problems.split_last()
+		{
+			None => Ok(()),
+			Some((last, [])) => Err(last.clone()),
+			Some((last, rest)) =>
+			{
+				Err(format!("{} and {}", rest.join(", "), last))
+			}
+		}
+	}
+}
+
+fn run_verification(
+	puzzles: &[&Puzzle],
+	parts: &[Part],
+	source: &Source,
+	path: &Path,
+	implementation: &str,
+	record: bool,
+	format: Format,
+) -> Result<(), String>
+{
+	let mut answers = Answers::load(path)?;
+	let mut tally = Tally::default();
+	let mut recorded: usize = 0;
+	for puzzle in puzzles.iter()
+	{
+		// Not every day needs to have an input, but one that does not parse
+		// fails the verification as much as a wrong answer does.
+		let input = match source.load(puzzle.name)
+		{
+			Ok(input) => input,
+			Err(error) if format == Format::Json =>
+			{
+				let fields = [
+					("day", puzzle.name.into()),
+					("skipped", error.to_string().into()),
+				];
+				println!("{}", json::object(&fields));
+				tally.skipped += 1;
+				continue;
+			}
+			Err(error) =>
+			{
+				println!("{}: skipped, {}", puzzle.name, error);
+				tally.skipped += 1;
+				continue;
+			}
+		};
+		let start = Instant::now();
+		let parsed = match puzzle.parse(&input)
+		{
+			Ok(parsed) => parsed,
+			Err(error) if format == Format::Json =>
+			{
+				let fields = [
+					("day", puzzle.name.into()),
+					("verdict", "error".into()),
+					("error", error.to_string().into()),
+				];
+				println!("{}", json::object(&fields));
+				tally.errors += 1;
+				continue;
+			}
+			Err(error) =>
+			{
+				println!("{}: ERROR, {}", puzzle.name, error);
+				tally.errors += 1;
+				continue;
+			}
+		};
+		let parse_time = start.elapsed();
+		for part in parts
+		{
+			let start = Instant::now();
+			let answer =
+				match puzzle.solve_variant(implementation, *part, &parsed)
+				{
+					Some(answer) => answer,
+					None if puzzle.has_variant(implementation, *part) =>
+					{
+						print_no_answer(puzzle.name, *part, format);
+						tally.errors += 1;
+						continue;
+					}
+					None => continue,
+				};
+			let solve_time = start.elapsed();
+			let key = verify::Key::new(puzzle.name, *part, &input);
+			let verdict = answers.check(&key, &answer);
+			if format == Format::Json
+			{
+				let times = [parse_time, solve_time];
+				print_verdict_json(
+					puzzle.name,
+					*part,
+					&answer,
+					&verdict,
+					times,
+				);
+			}
+			else
+			{
+				print_verdict(puzzle.name, *part, &answer, &verdict);
+			}
+			tally.count(&verdict);
+			if record && verdict == Verdict::Unknown
+			{
+				answers.record(key, &answer);
+				recorded += 1;
+			}
+		}
+	}
+	if format == Format::Json
+	{
+		let fields = [
+			("passed", tally.passed.into()),
+			("failed", tally.failed.into()),
+			("unknown", tally.unknown.into()),
+			("skipped", tally.skipped.into()),
+			("errors", tally.errors.into()),
+			("recorded", recorded.into()),
+		];
+		println!("{}", json::object(&fields));
+	}
+	else
+	{
+		println!(
+			"{} passed, {} failed, {} unknown, {} days skipped, {} errors",
+			tally.passed,
+			tally.failed,
+			tally.unknown,
+			tally.skipped,
+			tally.errors
+		);
+	}
+	if recorded > 0
+	{
+		answers.save(path)?;
+		if format != Format::Json
+		{
+			println!("Recorded {} answers in {}", recorded, path.display());
+		}
+	}
+	tally.outcome()
+}
+
+struct Settings
+{
+	jobs: usize,
+	timeout: Duration,
+	record: bool,
+	format: Format,
+	implementation: String,
+}
+
+struct Solved
+{
+	parse_time: Duration,
+	answers: Vec<(Part, Answer, Duration)>,
+}
+
+fn run_in_parallel(
+	puzzles: Vec<&'static Puzzle>,
+	parts: &[Part],
+	source: &Source,
+	path: &Path,
+	settings: Settings,
+) -> Result<(), String>
+{
+	let format = settings.format;
+	let mut answers = Answers::load(path)?;
+	let mut tally = Tally::default();
+	let mut recorded: usize = 0;
+	// Reading the inputs is quick, and stdin can only be read once anyway.
+	let mut jobs = Vec::new();
+	for puzzle in puzzles
+	{
+		match source.load(puzzle.name)
+		{
+			Ok(input) => jobs.push((puzzle, input)),
+			Err(error) =>
+			{
+				if format == Format::Json
+				{
+					let fields = [
+						("day", puzzle.name.into()),
+						("skipped", error.to_string().into()),
+					];
+					println!("{}", json::object(&fields));
+				}
+				else
+				{
+					eprintln!("{}: skipped, {}", puzzle.name, error);
+				}
+				tally.skipped += 1;
+			}
+		}
+	}
+	let inputs: Vec<(&Puzzle, String)> = jobs.clone();
+	let parts = parts.to_vec();
+	let implementation = settings.implementation.clone();
+	let start = Instant::now();
+	let results = parallel::map(
+		jobs,
+		settings.jobs,
+		Some(settings.timeout),
+		move |job| {
+			let (puzzle, input) = job;
+			solve_day(puzzle, &input, &parts, &implementation)
+		},
+	);
+	let elapsed = start.elapsed();
+	if format != Format::Json
+	{
+		println!(
+			"{:<6} {:<w$} {:<w$} {:>9}  result",
+			"day",
+			"part one",
+			"part two",
+			"time",
+			w = SUMMARY_WIDTH
+		);
+	}
+	for ((puzzle, input), status) in inputs.iter().zip(results)
+	{
+		let solved = match status
+		{
+			Status::Done(Ok(solved)) => solved,
+			Status::Done(Err(error)) =>
+			{
+				tally.errors += 1;
+				print_problem(puzzle.name, "error", &error, format);
+				continue;
+			}
+			Status::Panicked(message) =>
+			{
+				tally.errors += 1;
+				let error = format!("panicked: {}", message);
+				print_problem(puzzle.name, "error", &error, format);
+				continue;
+			}
+			Status::TimedOut =>
+			{
+				tally.timed_out += 1;
+				let error = format!(
+					"timed out after {}",
+					bench::format_duration(settings.timeout)
+				);
+				print_problem(puzzle.name, "timeout", &error, format);
+				continue;
+			}
+		};
+		let mut cells = ["-".to_string(), "-".to_string()];
+		let mut verdicts = Vec::new();
+		let mut time = solved.parse_time;
+		for (part, answer, solve_time) in solved.answers
+		{
+			let key = verify::Key::new(puzzle.name, part, input);
+			let verdict = answers.check(&key, &answer);
+			tally.count(&verdict);
+			if format == Format::Json
+			{
+				let times = [solved.parse_time, solve_time];
+				print_verdict_json(puzzle.name, part, &answer, &verdict, times);
+			}
+			cells[part.number() - 1] = summarize(&answer);
+			verdicts.push(match verdict
+			{
+				Verdict::Pass => "pass",
+				Verdict::Fail { .. } => "FAIL",
+				Verdict::Unknown => "unknown",
+			});
+			time += solve_time;
+			if settings.record && verdict == Verdict::Unknown
+			{
+				answers.record(key, &answer);
+				recorded += 1;
+			}
+		}
+		if format != Format::Json
+		{
+			println!(
+				"{:<6} {:<w$} {:<w$} {:>9}  {}",
+				puzzle.name,
+				cells[0],
+				cells[1],
+				bench::format_duration(time),
+				verdicts.join(", "),
+				w = SUMMARY_WIDTH
+			);
+		}
+	}
+	if format == Format::Json
+	{
+		let fields = [
+			("passed", tally.passed.into()),
+			("failed", tally.failed.into()),
+			("unknown", tally.unknown.into()),
+			("skipped", tally.skipped.into()),
+			("errors", tally.errors.into()),
+			("timed_out", tally.timed_out.into()),
+			("recorded", recorded.into()),
+			("wall_ns", elapsed.into()),
+		];
+		println!("{}", json::object(&fields));
+	}
+	else
+	{
+		println!(
+			"{} passed, {} failed, {} unknown, {} days skipped, {} errors, {} \
+			 timed out in {}",
+			tally.passed,
+			tally.failed,
+			tally.unknown,
+			tally.skipped,
+			tally.errors,
+			tally.timed_out,
+			bench::format_duration(elapsed)
+		);
+	}
+	if recorded > 0
+	{
+		answers.save(path)?;
+		if format != Format::Json
+		{
+			println!("Recorded {} answers in {}", recorded, path.display());
+		}
+	}
+	tally.outcome()
+}
+
+fn solve_day(
+	puzzle: &Puzzle,
+	input: &str,
+	parts: &[Part],
+	implementation: &str,
+) -> Result<Solved, String>
+{
+	let start = Instant::now();
+	let parsed = puzzle.parse(input).map_err(|error| error.to_string())?;
+	let parse_time = start.elapsed();
+	let mut answers = Vec::new();
+	for part in parts
+	{
+		let start = Instant::now();
+		match puzzle.solve_variant(implementation, *part, &parsed)
+		{
+			Some(answer) => answers.push((*part, answer, start.elapsed())),
+			None if puzzle.has_variant(implementation, *part) =>
+			{
+				return Err(format!("no answer for part {}", part));
+			}
+			None => (),
+		}
+	}
+	Ok(Solved {
+		parse_time,
+		answers,
+	})
+}
+
+fn print_problem(name: &str, verdict: &str, error: &str, format: Format)
+{
+	if format == Format::Json
+	{
+		let fields = [
+			("day", name.into()),
+			("verdict", verdict.into()),
+			("error", error.into()),
+		];
+		println!("{}", json::object(&fields));
+	}
+	else
+	{
+		let verdict = verdict.to_uppercase();
+		println!("{:<6} {}, {}", name, verdict, error);
+	}
+}
+
+fn summarize_outcome(answer: &Option<Answer>) -> String
+{
+	match answer
+	{
+		Some(answer) => summarize(answer),
+		None => "no answer".to_string(),
+	}
+}
+
+// Drawn answers that cannot be read do not fit in the table.
+fn summarize(answer: &Answer) -> String
+{
+	if answer.is_multiline()
+	{
+		return "(art)".to_string();
+	}
+	let text = answer.to_string();
+	if text.chars().count() > SUMMARY_WIDTH
+	{
+		let mut text: String = text.chars().take(SUMMARY_WIDTH - 3).collect();
+		text.push_str("...");
+		text
+	}
+	else
+	{
+		text
+	}
+}
+
+fn run_benchmarks(
+	puzzles: &[&Puzzle],
+	parts: &[Part],
+	source: &Source,
+	implementation: &str,
+	runs: usize,
+	format: Format,
+) -> Result<(), String>
+{
+	match format
+	{
+		Format::Table => println!(
+			"{:<6} {:<5} {:>5} {:>9} {:>9} {:>9}",
+			"day", "stage", "runs", "min", "median", "p95"
+		),
+		Format::Tsv => println!("day\tstage\truns\tmin_ns\tmedian_ns\tp95_ns"),
+		Format::Text | Format::Json => (),
+	}
+	let mut num_errors: usize = 0;
+	for puzzle in puzzles.iter()
+	{
+		// Keep stdout clean for the table, even if some inputs are missing
+		// or do not parse.
+		let input = match source.load(puzzle.name)
+		{
+			Ok(input) => input,
+			Err(error) =>
+			{
+				eprintln!("{}: skipped, {}", puzzle.name, error);
+				continue;
+			}
+		};
+		match bench::measure(puzzle, &input, parts, implementation, runs)
+		{
+			Ok(measurements) =>
+			{
+				for measurement in measurements
+				{
+					print_measurement(&measurement, format);
+				}
+			}
+			Err(error) =>
+			{
+				eprintln!("{}", error);
+				num_errors += 1;
+			}
+		}
+	}
+	if num_errors > 0
+	{
+		return Err(format!("{} days failed with an error", num_errors));
+	}
+	Ok(())
+}
+
+fn print_measurement(measurement: &bench::Measurement, format: Format)
+{
+	let stats = &measurement.stats;
+	match format
+	{
+		Format::Table => println!(
+			"{:<6} {:<5} {:>5} {:>9} {:>9} {:>9}",
+			measurement.name,
+			measurement.stage,
+			stats.runs,
+			bench::format_duration(stats.min),
+			bench::format_duration(stats.median),
+			bench::format_duration(stats.p95),
+		),
+		Format::Tsv => println!(
+			"{}\t{}\t{}\t{}\t{}\t{}",
+			measurement.name,
+			measurement.stage,
+			stats.runs,
+			stats.min.as_nanos(),
+			stats.median.as_nanos(),
+			stats.p95.as_nanos(),
+		),
+		Format::Text | Format::Json =>
+		{
+			let fields = [
+				("day", measurement.name.into()),
+				("stage", measurement.stage.to_string().into()),
+				("runs", stats.runs.into()),
+				("min_ns", stats.min.into()),
+				("median_ns", stats.median.into()),
+				("p95_ns", stats.p95.into()),
+			];
+			println!("{}", json::object(&fields));
+		}
+	}
+}
+
+fn run_cross_check(
+	puzzles: &[&Puzzle],
+	parts: &[Part],
+	source: &Source,
+	format: Format,
+) -> Result<(), String>
+{
+	let puzzles: Vec<&Puzzle> = puzzles
+		.iter()
+		.copied()
+		.filter(|puzzle| {
+			parts.iter().any(|&part| puzzle.variants(part).len() > 1)
+		})
+		.collect();
+	if puzzles.is_empty()
+	{
+		return Err(
+			"there are no other implementations to cross-check".to_string()
+		);
+	}
+	let mut agreed: usize = 0;
+	let mut disagreed: usize = 0;
+	let mut skipped: usize = 0;
+	let mut errors: usize = 0;
+	for puzzle in puzzles
+	{
+		let input = match source.load(puzzle.name)
+		{
+			Ok(input) => input,
+			Err(error) =>
+			{
+				print_problem(
+					puzzle.name,
+					"skipped",
+					&error.to_string(),
+					format,
+				);
+				skipped += 1;
+				continue;
+			}
+		};
+		let parsed = match puzzle.parse(&input)
+		{
+			Ok(parsed) => parsed,
+			Err(error) =>
+			{
+				print_problem(puzzle.name, "error", &error.to_string(), format);
+				errors += 1;
+				continue;
+			}
+		};
+		for &part in parts
+		{
+			let names = puzzle.variants(part);
+			if names.len() < 2
+			{
+				continue;
+			}
+			let mut results = Vec::new();
+			for name in names
+			{
+				let start = Instant::now();
+				let answer = puzzle.solve_variant(name, part, &parsed);
+				results.push((name, answer, start.elapsed()));
+			}
+			let Some((_, expected, _)) = results.first()
+			else
+			{
+				continue;
+			};
+			let agrees =
+				results.iter().all(|(_, answer, _)| answer == expected);
+			if agrees
+			{
+				agreed += 1;
+			}
+			else
+			{
+				disagreed += 1;
+			}
+			if format == Format::Json
+			{
+				for (name, answer, solve_time) in &results
+				{
+					let mut fields = match answer
+					{
+						Some(answer) =>
+						{
+							answer_fields(puzzle.name, part, answer)
+						}
+						None => vec![
+							("day", puzzle.name.into()),
+							("part", part.number().into()),
+							("answer", Value::Null),
+						],
+					};
+					fields.push(("implementation", (*name).into()));
+					fields.push(("solve_ns", (*solve_time).into()));
+					fields.push(("agrees", (answer == expected).into()));
+					println!("{}", json::object(&fields));
+				}
+			}
+			else if agrees
+			{
+				let times: Vec<String> = results
+					.iter()
+					.map(|(name, _, time)| {
+						format!("{} in {}", name, bench::format_duration(*time))
+					})
+					.collect();
+				println!(
+					"{} part {}: agree on {}, {}",
+					puzzle.name,
+					part,
+					summarize_outcome(expected),
+					times.join(", ")
+				);
+			}
+			else
+			{
+				let answers: Vec<String> = results
+					.iter()
+					.map(|(name, answer, _)| {
+						format!("{} gives {}", name, summarize_outcome(answer))
+					})
+					.collect();
+				println!(
+					"{} part {}: MISMATCH, {}",
+					puzzle.name,
+					part,
+					answers.join(", ")
+				);
+			}
+		}
+	}
+	if format == Format::Json
+	{
+		let fields = [
+			("agreed", agreed.into()),
+			("disagreed", disagreed.into()),
+			("skipped", skipped.into()),
+			("errors", errors.into()),
+		];
+		println!("{}", json::object(&fields));
+	}
+	else
+	{
+		println!(
+			"{} parts agree, {} disagree, {} days skipped, {} errors",
+			agreed, disagreed, skipped, errors
+		);
+	}
+	if disagreed > 0
+	{
+		return Err(format!(
+			"{} parts have implementations that disagree",
+			disagreed
+		));
+	}
+	if errors > 0
+	{
+		return Err(format!("{} days failed with an error", errors));
+	}
+	Ok(())
+}
+
+fn print_verdict(name: &str, part: Part, answer: &Answer, verdict: &Verdict)
+{
+	match verdict
+	{
+		Verdict::Pass => println!("{} part {}: pass", name, part),
+		Verdict::Fail { expected } if answer.is_multiline() =>
+		{
+			println!("{} part {}: FAIL, expected:", name, part);
+			println!("{}", expected);
+			println!("but got:");
+			println!("{}", answer);
+		}
+		Verdict::Fail { expected } =>
+		{
+			println!(
+				"{} part {}: FAIL, expected {} but got {}",
+				name, part, expected, answer
+			);
+		}
+		Verdict::Unknown => println!("{} part {}: unknown", name, part),
+	}
+}
+
+fn print_no_answer(name: &str, part: Part, format: Format)
+{
+	if format == Format::Json
+	{
+		let fields = [
+			("day", name.into()),
+			("part", part.number().into()),
+			("verdict", "error".into()),
+			("error", "no answer for this input".into()),
+		];
+		println!("{}", json::object(&fields));
+	}
+	else
+	{
+		println!("{} part {}: ERROR, no answer for this input", name, part);
+	}
+}
+
+fn print_verdict_json(
+	name: &str,
+	part: Part,
+	answer: &Answer,
+	verdict: &Verdict,
+	[parse_time, solve_time]: [Duration; 2],
+)
+{
+	let mut fields = answer_fields(name, part, answer);
+	fields.push(("parse_ns", parse_time.into()));
+	fields.push(("solve_ns", solve_time.into()));
+	match verdict
+	{
+		Verdict::Pass => fields.push(("verdict", "pass".into())),
+		Verdict::Fail { expected } =>
+		{
+			fields.push(("verdict", "fail".into()));
+			fields.push(("expected", expected.as_str().into()));
+		}
+		Verdict::Unknown => fields.push(("verdict", "unknown".into())),
+	}
+	println!("{}", json::object(&fields));
+}
+
+// Numbers stay numbers, and drawn letters keep their art next to the text.
+fn answer_fields(
+	name: &str,
+	part: Part,
+	answer: &Answer,
+) -> Vec<(&'static str, Value)>
+{
+	let value = match answer
+	{
+		Answer::Number(number) => Value::from(*number),
+		_ => Value::from(answer.to_string()),
+	};
+	let mut fields = vec![
+		("day", name.into()),
+		("part", part.number().into()),
+		("answer", value),
+		("type", answer.kind().into()),
+	];
+	if let Answer::Letters { art, .. } = answer
+	{
+		fields.push(("art", art.as_str().into()));
+	}
+	fields
+}
+
+fn print_answer(part: Part, answer: &Answer)
+{
+	if answer.is_multiline()
+	{
+		println!("Part {}: \n{}", part, answer);
+	}
+	else
+	{
+		println!("Part {}: {}", part, answer);
+	}
+}
@@ -0,0 +1,30 @@
+/**/
+
+use crate::Format;
+
+use aoc2022::json;
+use aoc2022::json::Value;
+
+use std::fmt::Arguments;
+
+// The headings of a text report; JSON rows name their own fields.
+pub fn print_header(format: Format, text: Arguments)
+{
+	if format == Format::Text
+	{
+		println!("{}", text);
+	}
+}
+
+// One row of a report, as a JSON object or as a line of text.
+pub fn print_row(format: Format, fields: &[(&str, Value)], text: Arguments)
+{
+	if format == Format::Json
+	{
+		println!("{}", json::object(fields));
+	}
+	else
+	{
+		println!("{}", text);
+	}
+}
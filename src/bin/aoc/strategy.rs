@@ -0,0 +1,258 @@
+/**/
+
+use crate::report::print_header;
+use crate::report::print_row;
+use crate::Command;
+use crate::Format;
+use crate::Options;
+
+use aoc2022::day02;
+use aoc2022::day02::Interpretation;
+use aoc2022::input::Source;
+use aoc2022::json;
+
+use std::path::Path;
+
+pub fn check_options(options: &Options) -> Result<(), String>
+{
+	let allowed = Options {
+		command: Command::Strategy,
+		source: options.source.clone(),
+		format: options.format,
+		rules: options.rules.clone(),
+		mappings: options.mappings,
+		target: options.target,
+		tournament: options.tournament,
+		seed: options.seed,
+		..Default::default()
+	};
+	crate::check_only(
+		options,
+		allowed,
+		"strategy takes only --input, --input-dir, --rules, --mappings, \
+		 --target, --tournament, --seed and --format",
+	)?;
+	if options.target.is_some() && !options.mappings
+	{
+		return Err("cannot use --target without --mappings".to_string());
+	}
+	if options.seed.is_some() && options.tournament.is_none()
+	{
+		return Err(
+			"cannot use --seed without --tournament or generate".to_string()
+		);
+	}
+	if options.mappings && options.tournament.is_some()
+	{
+		return Err("cannot use --mappings with --tournament".to_string());
+	}
+	Ok(())
+}
+
+pub fn run(options: Options) -> Result<(), String>
+{
+	let source = options.source.unwrap_or_else(Source::from_env);
+	let format = options.format.unwrap_or(Format::Text);
+	let rules = match &options.rules
+	{
+		Some(path) => load_rules(path)?,
+		None => day02::Rules::standard(),
+	};
+	let input = source.load("day02").map_err(|error| error.to_string())?;
+	let guide = day02::Guide::parse(rules, &input)
+		.map_err(|error| error.in_day("day02").to_string())?;
+	if options.mappings
+	{
+		return audit_mappings(&guide, options.target, format);
+	}
+	if let Some(num_rounds) = options.tournament
+	{
+		let seed = options.seed.unwrap_or_default();
+		return run_tournament(&guide, num_rounds, seed, format);
+	}
+	score_strategy(&guide, format)
+}
+
+fn load_rules(path: &Path) -> Result<day02::Rules, String>
+{
+	let text = std::fs::read_to_string(path).map_err(|error| {
+		format!("cannot read {}: {}", path.display(), error)
+	})?;
+	text.parse()
+		.map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+fn score_strategy(guide: &day02::Guide, format: Format) -> Result<(), String>
+{
+	for interpretation in [Interpretation::Response, Interpretation::Outcome]
+	{
+		let score = guide.total_score(interpretation);
+		let fields = [
+			("interpretation", interpretation.name().into()),
+			("score", i64::from(score).into()),
+		];
+		print_row(
+			format,
+			&fields,
+			format_args!("{:<9} {:>9}", interpretation.name(), score),
+		);
+	}
+	Ok(())
+}
+
+fn audit_mappings(
+	guide: &day02::Guide,
+	target: Option<i64>,
+	format: Format,
+) -> Result<(), String>
+{
+	let mut mappings = guide.mappings();
+	let num_mappings = mappings.len();
+	let best = day02::best_mapping(&mappings).cloned();
+	if let Some(target) = target
+	{
+		mappings.retain(|mapping| mapping.score == target);
+	}
+	mappings.sort_by_key(|mapping| std::cmp::Reverse(mapping.score));
+	print_header(
+		format,
+		format_args!("{:>9}  {:<14} mapping", "score", "interpretation"),
+	);
+	for mapping in &mappings
+	{
+		let interpretation = mapping.meaning.interpretation().name();
+		let description = guide.describe(&mapping.meaning);
+		let fields = [
+			("interpretation", interpretation.into()),
+			("mapping", description.as_str().into()),
+			("score", mapping.score.into()),
+		];
+		print_row(
+			format,
+			&fields,
+			format_args!(
+				"{:>9}  {:<14} {}",
+				mapping.score, interpretation, description
+			),
+		);
+	}
+	match (target, &best)
+	{
+		(Some(target), _) =>
+		{
+			let fields = [
+				("mappings", num_mappings.into()),
+				("target", target.into()),
+				("matching", mappings.len().into()),
+			];
+			print_row(
+				format,
+				&fields,
+				format_args!(
+					"{} of {} mappings score {}",
+					mappings.len(),
+					num_mappings,
+					target
+				),
+			);
+		}
+		(None, Some(best)) =>
+		{
+			let fields = [
+				("mappings", num_mappings.into()),
+				("best_score", Some(best.score).into()),
+			];
+			print_row(
+				format,
+				&fields,
+				format_args!(
+					"The best of {} mappings is {} with {}",
+					num_mappings,
+					guide.describe(&best.meaning),
+					best.score
+				),
+			);
+		}
+		// Without any mappings there is only something to say in JSON.
+		(None, None) if format == Format::Json =>
+		{
+			let fields = [
+				("mappings", num_mappings.into()),
+				("best_score", None::<i64>.into()),
+			];
+			println!("{}", json::object(&fields));
+		}
+		(None, None) => (),
+	}
+	match target
+	{
+		Some(target) if mappings.is_empty() =>
+		{
+			Err(format!("no mapping scores {}", target))
+		}
+		_ => Ok(()),
+	}
+}
+
+fn run_tournament(
+	guide: &day02::Guide,
+	num_rounds: usize,
+	seed: u64,
+	format: Format,
+) -> Result<(), String>
+{
+	if guide.lines.is_empty()
+	{
+		return Err("the strategy guide is empty".to_string());
+	}
+	let mut strategies: Vec<Box<dyn day02::Strategy>> = vec![
+		Box::new(day02::FixedGuide::new(guide)),
+		Box::new(day02::Frequency::default()),
+		Box::new(day02::Markov::default()),
+		Box::new(day02::Random::new(seed)),
+	];
+	let mut standings =
+		day02::tournament(&guide.rules, &mut strategies, num_rounds);
+	standings.sort_by_key(|standing| std::cmp::Reverse(standing.score));
+	print_header(
+		format,
+		format_args!(
+			"{:<10} {:>7} {:>7} {:>7} {:>7} {:>8} {:>9}",
+			"strategy",
+			"rounds",
+			"wins",
+			"draws",
+			"losses",
+			"win rate",
+			"avg score"
+		),
+	);
+	for standing in standings
+	{
+		let fields = [
+			("strategy", standing.name.as_str().into()),
+			("rounds", standing.rounds.into()),
+			("wins", standing.wins.into()),
+			("draws", standing.draws.into()),
+			("losses", standing.losses.into()),
+			("score", standing.score.into()),
+			("win_rate", standing.win_rate().into()),
+			("expected_score", standing.expected_score().into()),
+		];
+		print_row(
+			format,
+			&fields,
+			format_args!(
+				"{:<10} {:>7} {:>7} {:>7} {:>7} {:>7.1}% {:>9.2}",
+				standing.name,
+				standing.rounds,
+				standing.wins,
+				standing.draws,
+				standing.losses,
+				100.0 * standing.win_rate(),
+				standing.expected_score()
+			),
+		);
+	}
+	Ok(())
+}
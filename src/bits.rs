@@ -0,0 +1,705 @@
+/**/
+
+use crate::grid::Position;
+
+const WIDTH_OF_WORD: usize = 64;
+
+/// The words that hold the bits of a row. An array keeps a narrow row inline,
+/// so that the row is Copy and combining two of them compiles down to a few
+/// instructions. A Vec holds a row of any width.
+pub trait Words:
+	Clone + std::fmt::Debug + Eq + AsRef<[u64]> + AsMut<[u64]>
+{
+	/// Whether a row of this many bits fits.
+	fn fits(width: usize) -> bool;
+
+	fn zeroed(num_words: usize) -> Self;
+
+	fn resize(&mut self, num_words: usize);
+}
+
+impl<const N: usize> Words for [u64; N]
+{
+	fn fits(width: usize) -> bool
+	{
+		width <= N * WIDTH_OF_WORD
+	}
+
+	fn zeroed(num_words: usize) -> Self
+	{
+		assert!(num_words <= N);
+		[0; N]
+	}
+
+	// The words beyond the width are zero, so there is nothing to do.
+	fn resize(&mut self, num_words: usize)
+	{
+		assert!(num_words <= N);
+	}
+}
+
+impl Words for Vec<u64>
+{
+	fn fits(_width: usize) -> bool
+	{
+		true
+	}
+
+	fn zeroed(num_words: usize) -> Self
+	{
+		vec![0; num_words]
+	}
+
+	fn resize(&mut self, num_words: usize)
+	{
+		Vec::resize(self, num_words, 0);
+	}
+}
+
+/// Calls a function that is generic over the Words of its rows with the
+/// narrowest array that fits the width, or with a Vec if none does.
+#[macro_export]
+macro_rules! with_words {
+	($width:expr, $f:ident($($arg:expr),*)) => {
+		match $width
+		{
+			width if width <= 64 => $f::<[u64; 1]>($($arg),*),
+			width if width <= 128 => $f::<[u64; 2]>($($arg),*),
+			width if width <= 256 => $f::<[u64; 4]>($($arg),*),
+			width if width <= 512 => $f::<[u64; 8]>($($arg),*),
+			_ => $f::<Vec<u64>>($($arg),*),
+		}
+	};
+}
+
+/// A row of bits whose width is chosen at runtime. Bits beyond the width are
+/// always zero, so shifting left drops the bits that fall off the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitRow<W: Words = Vec<u64>>
+{
+	words: W,
+	width: usize,
+}
+
+impl<W: Words> BitRow<W>
+{
+	pub fn new(width: usize) -> BitRow<W>
+	{
+		assert!(W::fits(width));
+		BitRow {
+			words: W::zeroed(width.div_ceil(WIDTH_OF_WORD)),
+			width,
+		}
+	}
+
+	/// Copies the row into other words, which must fit its width.
+	pub fn repack<V: Words>(&self) -> BitRow<V>
+	{
+		let mut row: BitRow<V> = BitRow::new(self.width);
+		let n = self.width.div_ceil(WIDTH_OF_WORD);
+		row.words.as_mut()[0..n].copy_from_slice(&self.words()[0..n]);
+		row
+	}
+
+	pub fn width(&self) -> usize
+	{
+		self.width
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool
+	{
+		self.words().iter().all(|&word| word == 0)
+	}
+
+	pub fn get_bit(&self, offset: usize) -> bool
+	{
+		assert!(offset < self.width);
+		(self.words()[offset / WIDTH_OF_WORD] >> (offset % WIDTH_OF_WORD)) & 1
+			!= 0
+	}
+
+	pub fn set_bit(&mut self, offset: usize)
+	{
+		assert!(offset < self.width);
+		self.words_mut()[offset / WIDTH_OF_WORD] |=
+			1 << (offset % WIDTH_OF_WORD);
+	}
+
+	pub fn clear_bit(&mut self, offset: usize)
+	{
+		assert!(offset < self.width);
+		self.words_mut()[offset / WIDTH_OF_WORD] &=
+			!(1 << (offset % WIDTH_OF_WORD));
+	}
+
+	pub fn toggle_bit(&mut self, offset: usize)
+	{
+		assert!(offset < self.width);
+		self.words_mut()[offset / WIDTH_OF_WORD] ^=
+			1 << (offset % WIDTH_OF_WORD);
+	}
+
+	pub fn clear(&mut self)
+	{
+		self.words_mut().fill(0);
+	}
+
+	#[inline]
+	pub fn count_ones(&self) -> u32
+	{
+		self.words().iter().map(|word| word.count_ones()).sum()
+	}
+
+	/// The number of zeros above the most significant one, within the width.
+	pub fn leading_zeros(&self) -> u32
+	{
+		let words = self.words();
+		match words.iter().rposition(|&word| word != 0)
+		{
+			Some(k) =>
+			{
+				let skipped = (words.len() - 1 - k) * WIDTH_OF_WORD;
+				let padding = words.len() * WIDTH_OF_WORD - self.width;
+				let zeros = words[k].leading_zeros() as usize;
+				(skipped + zeros - padding) as u32
+			}
+			None => self.width as u32,
+		}
+	}
+
+	pub fn trailing_zeros(&self) -> u32
+	{
+		let words = self.words();
+		match words.iter().position(|&word| word != 0)
+		{
+			Some(k) => (k * WIDTH_OF_WORD) as u32 + words[k].trailing_zeros(),
+			None => self.width as u32,
+		}
+	}
+
+	/// The offsets of all ones, from least to most significant.
+	pub fn ones(&self) -> impl Iterator<Item = usize> + '_
+	{
+		(0..self.width).filter(|&offset| self.get_bit(offset))
+	}
+
+	/// Grows or shrinks the row, dropping any bits beyond the new width.
+	pub fn resize(&mut self, width: usize)
+	{
+		assert!(W::fits(width));
+		self.words.resize(width.div_ceil(WIDTH_OF_WORD));
+		self.width = width;
+		self.mask();
+	}
+
+	/// Renders the least significant bit first.
+	pub fn render(&self, one: char, zero: char) -> String
+	{
+		(0..self.width)
+			.map(|offset| {
+				if self.get_bit(offset)
+				{
+					one
+				}
+				else
+				{
+					zero
+				}
+			})
+			.collect()
+	}
+
+	#[inline]
+	fn words(&self) -> &[u64]
+	{
+		self.words.as_ref()
+	}
+
+	#[inline]
+	fn words_mut(&mut self) -> &mut [u64]
+	{
+		self.words.as_mut()
+	}
+
+	// Clears the bits beyond the width. This goes word by word rather than
+	// filling a slice, so that it unrolls for arrays instead of calling out.
+	#[inline]
+	fn mask(&mut self)
+	{
+		let width = self.width;
+		for (k, word) in self.words_mut().iter_mut().enumerate()
+		{
+			let n = width.saturating_sub(k * WIDTH_OF_WORD);
+			if n < WIDTH_OF_WORD
+			{
+				*word &= (1 << n) - 1;
+			}
+		}
+	}
+
+	// Applies a function to both rows one word at a time.
+	#[inline]
+	fn zip_with(&mut self, rhs: &BitRow<W>, f: impl Fn(&mut u64, u64))
+	{
+		debug_assert_eq!(self.width, rhs.width);
+		for (a, &b) in self.words_mut().iter_mut().zip(rhs.words())
+		{
+			f(a, b);
+		}
+	}
+}
+
+impl<W: Words> Default for BitRow<W>
+{
+	fn default() -> BitRow<W>
+	{
+		BitRow::new(0)
+	}
+}
+
+impl<W: Words> std::ops::Not for BitRow<W>
+{
+	type Output = BitRow<W>;
+
+	#[inline]
+	fn not(mut self) -> BitRow<W>
+	{
+		for word in self.words_mut()
+		{
+			*word = !*word;
+		}
+		self.mask();
+		self
+	}
+}
+
+impl<W: Words> std::ops::Not for &BitRow<W>
+{
+	type Output = BitRow<W>;
+
+	#[inline]
+	fn not(self) -> BitRow<W>
+	{
+		!self.clone()
+	}
+}
+
+// Wide rows are not Copy, so each operator is implemented for every
+// combination of owned and borrowed operands. Both operands must have the
+// same width.
+macro_rules! bitwise {
+	($trait:ident, $method:ident, $assign_trait:ident, $assign:ident) => {
+		impl<W: Words> std::ops::$assign_trait<&BitRow<W>> for BitRow<W>
+		{
+			#[inline]
+			fn $assign(&mut self, rhs: &BitRow<W>)
+			{
+				self.zip_with(rhs, |a, b| {
+					std::ops::$assign_trait::$assign(a, b)
+				});
+			}
+		}
+
+		impl<W: Words> std::ops::$assign_trait for BitRow<W>
+		{
+			#[inline]
+			fn $assign(&mut self, rhs: BitRow<W>)
+			{
+				std::ops::$assign_trait::$assign(self, &rhs);
+			}
+		}
+
+		impl<W: Words> std::ops::$trait<&BitRow<W>> for BitRow<W>
+		{
+			type Output = BitRow<W>;
+
+			#[inline]
+			fn $method(mut self, rhs: &BitRow<W>) -> BitRow<W>
+			{
+				std::ops::$assign_trait::$assign(&mut self, rhs);
+				self
+			}
+		}
+
+		impl<W: Words> std::ops::$trait for BitRow<W>
+		{
+			type Output = BitRow<W>;
+
+			#[inline]
+			fn $method(mut self, rhs: BitRow<W>) -> BitRow<W>
+			{
+				std::ops::$assign_trait::$assign(&mut self, &rhs);
+				self
+			}
+		}
+
+		impl<W: Words> std::ops::$trait<BitRow<W>> for &BitRow<W>
+		{
+			type Output = BitRow<W>;
+
+			#[inline]
+			fn $method(self, mut rhs: BitRow<W>) -> BitRow<W>
+			{
+				std::ops::$assign_trait::$assign(&mut rhs, self);
+				rhs
+			}
+		}
+
+		impl<W: Words> std::ops::$trait for &BitRow<W>
+		{
+			type Output = BitRow<W>;
+
+			#[inline]
+			fn $method(self, rhs: &BitRow<W>) -> BitRow<W>
+			{
+				std::ops::$trait::$method(self.clone(), rhs)
+			}
+		}
+	};
+}
+
+bitwise!(BitOr, bitor, BitOrAssign, bitor_assign);
+bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl<W: Words> std::ops::ShlAssign<usize> for BitRow<W>
+{
+	#[inline]
+	fn shl_assign(&mut self, n: usize)
+	{
+		shift_words_up(self.words_mut(), n);
+		self.mask();
+	}
+}
+
+impl<W: Words> std::ops::ShrAssign<usize> for BitRow<W>
+{
+	#[inline]
+	fn shr_assign(&mut self, n: usize)
+	{
+		shift_words_down(self.words_mut(), n);
+	}
+}
+
+// Shifts towards the more significant bits, carrying bits between words.
+#[inline]
+fn shift_words_up(words: &mut [u64], n: usize)
+{
+	let len = words.len();
+	if len == 0
+	{
+		return;
+	}
+	let k = (n / WIDTH_OF_WORD).min(len);
+	if k > 0
+	{
+		words.copy_within(0..(len - k), k);
+		words[0..k].fill(0);
+	}
+	let n = n % WIDTH_OF_WORD;
+	if n > 0
+	{
+		for i in (1..len).rev()
+		{
+			words[i] = (words[i] << n) | (words[i - 1] >> (WIDTH_OF_WORD - n));
+		}
+		words[0] <<= n;
+	}
+}
+
+// Shifts towards the less significant bits, carrying bits between words.
+#[inline]
+fn shift_words_down(words: &mut [u64], n: usize)
+{
+	let len = words.len();
+	if len == 0
+	{
+		return;
+	}
+	let k = (n / WIDTH_OF_WORD).min(len);
+	if k > 0
+	{
+		words.copy_within(k..len, 0);
+		words[(len - k)..len].fill(0);
+	}
+	let n = n % WIDTH_OF_WORD;
+	if n > 0
+	{
+		for i in 0..(len - 1)
+		{
+			words[i] = (words[i] >> n) | (words[i + 1] << (WIDTH_OF_WORD - n));
+		}
+		words[len - 1] >>= n;
+	}
+}
+
+impl<W: Words> std::ops::Shl<usize> for BitRow<W>
+{
+	type Output = BitRow<W>;
+
+	#[inline]
+	fn shl(mut self, n: usize) -> BitRow<W>
+	{
+		self <<= n;
+		self
+	}
+}
+
+impl<W: Words> std::ops::Shl<usize> for &BitRow<W>
+{
+	type Output = BitRow<W>;
+
+	#[inline]
+	fn shl(self, n: usize) -> BitRow<W>
+	{
+		self.clone() << n
+	}
+}
+
+impl<W: Words> std::ops::Shr<usize> for BitRow<W>
+{
+	type Output = BitRow<W>;
+
+	#[inline]
+	fn shr(mut self, n: usize) -> BitRow<W>
+	{
+		self >>= n;
+		self
+	}
+}
+
+impl<W: Words> std::ops::Shr<usize> for &BitRow<W>
+{
+	type Output = BitRow<W>;
+
+	#[inline]
+	fn shr(self, n: usize) -> BitRow<W>
+	{
+		self.clone() >> n
+	}
+}
+
+/// A two-dimensional grid of bits, with one BitRow per row so that whole
+/// rows can be combined at once.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BitGrid<W: Words = Vec<u64>>
+{
+	rows: Vec<BitRow<W>>,
+	width: usize,
+}
+
+impl<W: Words> BitGrid<W>
+{
+	pub fn new(width: usize, height: usize) -> BitGrid<W>
+	{
+		BitGrid {
+			rows: vec![BitRow::new(width); height],
+			width,
+		}
+	}
+
+	/// Copies the grid into rows of other words, which must fit its width.
+	pub fn repack<V: Words>(&self) -> BitGrid<V>
+	{
+		BitGrid {
+			rows: self.rows.iter().map(|row| row.repack()).collect(),
+			width: self.width,
+		}
+	}
+
+	pub fn width(&self) -> usize
+	{
+		self.width
+	}
+
+	pub fn height(&self) -> usize
+	{
+		self.rows.len()
+	}
+
+	pub fn contains(&self, (r, c): Position) -> bool
+	{
+		r < self.rows.len() && c < self.width
+	}
+
+	pub fn get_bit(&self, (r, c): Position) -> bool
+	{
+		self.rows[r].get_bit(c)
+	}
+
+	pub fn set_bit(&mut self, (r, c): Position)
+	{
+		self.rows[r].set_bit(c);
+	}
+
+	pub fn clear_bit(&mut self, (r, c): Position)
+	{
+		self.rows[r].clear_bit(c);
+	}
+
+	pub fn toggle_bit(&mut self, (r, c): Position)
+	{
+		self.rows[r].toggle_bit(c);
+	}
+
+	pub fn clear(&mut self)
+	{
+		for row in &mut self.rows
+		{
+			row.clear();
+		}
+	}
+
+	pub fn rows(&self) -> &[BitRow<W>]
+	{
+		&self.rows
+	}
+
+	pub fn rows_mut(&mut self) -> &mut [BitRow<W>]
+	{
+		&mut self.rows
+	}
+
+	pub fn count_ones(&self) -> u32
+	{
+		self.rows.iter().map(|row| row.count_ones()).sum()
+	}
+
+	/// Renders one line per row, with every row ending in a newline.
+	pub fn render(&self, one: char, zero: char) -> String
+	{
+		let mut text = String::with_capacity(self.height() * (self.width + 1));
+		for row in &self.rows
+		{
+			text.push_str(&row.render(one, zero));
+			text.push('\n');
+		}
+		text
+	}
+}
+
+impl<W: Words> std::ops::Index<usize> for BitGrid<W>
+{
+	type Output = BitRow<W>;
+
+	fn index(&self, r: usize) -> &BitRow<W>
+	{
+		&self.rows[r]
+	}
+}
+
+impl<W: Words> std::ops::IndexMut<usize> for BitGrid<W>
+{
+	fn index_mut(&mut self, r: usize) -> &mut BitRow<W>
+	{
+		&mut self.rows[r]
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn row_assertions()
+	{
+		check_row::<Vec<u64>>();
+		check_row::<[u64; 6]>();
+	}
+
+	fn check_row<W: Words>()
+	{
+		let mut row: BitRow<W> = BitRow::new(384);
+		let max = 384;
+		assert_eq!(row.count_ones(), 0);
+		assert_eq!(row.leading_zeros(), max);
+		assert_eq!(row.trailing_zeros(), max);
+		row.set_bit(4);
+		assert_eq!(row.count_ones(), 1);
+		assert_eq!(row.leading_zeros(), max - 4 - 1);
+		assert_eq!(row.trailing_zeros(), 4);
+		row.set_bit(200);
+		assert_eq!(row.count_ones(), 2);
+		assert_eq!(row.leading_zeros(), max - 200 - 1);
+		assert_eq!(row.trailing_zeros(), 4);
+		row.set_bit(126);
+		row.set_bit(127);
+		row.set_bit(128);
+		row.set_bit(129);
+		assert_eq!(row.count_ones(), 6);
+		assert_eq!(row.leading_zeros(), max - 200 - 1);
+		assert_eq!(row.trailing_zeros(), 4);
+		row >>= 1;
+		assert_eq!(row.count_ones(), 6);
+		assert_eq!(row.leading_zeros(), max - 200);
+		assert_eq!(row.trailing_zeros(), 4 - 1);
+		row <<= 2;
+		assert_eq!(row.count_ones(), 6);
+		assert_eq!(row.leading_zeros(), max - 200 - 2);
+		assert_eq!(row.trailing_zeros(), 4 + 1);
+		row <<= 128;
+		assert_eq!(row.count_ones(), 6);
+		assert_eq!(row.leading_zeros(), max - 200 - 2 - 128);
+		assert_eq!(row.trailing_zeros(), 4 + 1 + 128);
+		row >>= 128 + 1;
+		assert_eq!(row.count_ones(), 6);
+		assert_eq!(row.leading_zeros(), max - 200 - 1);
+		assert_eq!(row.trailing_zeros(), 4);
+	}
+
+	#[test]
+	fn bits_beyond_width_stay_zero()
+	{
+		let mut row: BitRow<[u64; 2]> = BitRow::new(70);
+		assert_eq!((!&row).count_ones(), 70);
+		assert_eq!((!&row).leading_zeros(), 0);
+		row.set_bit(69);
+		row.set_bit(3);
+		assert_eq!((&row << 1).ones().collect::<Vec<usize>>(), vec![4]);
+		assert_eq!((&row >> 4).ones().collect::<Vec<usize>>(), vec![65]);
+		row.resize(10);
+		assert_eq!(row.render('#', '.'), "...#......");
+		row.resize(100);
+		assert_eq!(row.count_ones(), 1);
+		let wide: BitRow = row.repack();
+		assert_eq!(wide.width(), 100);
+		assert_eq!(wide.ones().collect::<Vec<usize>>(), vec![3]);
+	}
+
+	#[test]
+	fn combine_rows()
+	{
+		let mut a: BitRow = BitRow::new(5);
+		let mut b = BitRow::new(5);
+		a.set_bit(0);
+		a.set_bit(1);
+		b.set_bit(1);
+		b.set_bit(4);
+		assert_eq!((&a | &b).render('#', '.'), "##..#");
+		assert_eq!((&a & &b).render('#', '.'), ".#...");
+		assert_eq!((&a ^ &b).render('#', '.'), "#...#");
+		let c: BitRow<[u64; 1]> = a.repack();
+		let d = b.repack();
+		assert_eq!((a & !b).render('#', '.'), "#....");
+		assert_eq!((c & !d).render('#', '.'), "#....");
+		assert_eq!((c | d).render('#', '.'), "##..#");
+	}
+
+	#[test]
+	fn grid_of_bits()
+	{
+		let mut grid: BitGrid = BitGrid::new(3, 2);
+		grid.set_bit((0, 2));
+		grid.toggle_bit((1, 0));
+		grid.toggle_bit((1, 1));
+		grid.clear_bit((1, 0));
+		assert_eq!(grid.count_ones(), 2);
+		assert!(grid.get_bit((1, 1)));
+		assert_eq!(grid.render('#', '.'), "..#\n.#.\n");
+		grid[1] <<= 1;
+		assert_eq!(grid[1].trailing_zeros(), 2);
+	}
+}
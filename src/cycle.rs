@@ -0,0 +1,150 @@
+/**/
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating itself, counted in steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle
+{
+	pub start: usize,
+	pub length: usize,
+}
+
+/// Steps until the key of a state repeats, giving up after `limit` steps.
+/// Two states with the same key must have the same future.
+pub fn find_cycle<S, K>(
+	state: &mut S,
+	limit: usize,
+	mut step: impl FnMut(&mut S),
+	mut key: impl FnMut(&S) -> K,
+) -> Option<Cycle>
+where
+	K: Eq + Hash,
+{
+	let mut seen: HashMap<K, usize> = HashMap::new();
+	for i in 0..limit
+	{
+		if let Some(start) = seen.insert(key(state), i)
+		{
+			return Some(Cycle {
+				start,
+				length: i - start,
+			});
+		}
+		step(state);
+	}
+	None
+}
+
+/// Measures the state after `target` steps, without taking all of them. Once
+/// the key repeats, whole cycles are skipped on the assumption that the
+/// measure grows by the same amount every time around.
+pub fn extrapolate<S, K>(
+	mut state: S,
+	target: usize,
+	mut step: impl FnMut(&mut S),
+	key: impl FnMut(&S) -> K,
+	mut measure: impl FnMut(&S) -> usize,
+) -> usize
+where
+	K: Eq + Hash,
+{
+	// Remember what the measure was at every step, to know how much it grew
+	// in the cycle once we find one.
+	let mut history = Vec::new();
+	let cycle = find_cycle(
+		&mut state,
+		target,
+		|state| {
+			history.push(measure(state));
+			step(state)
+		},
+		key,
+	);
+	let Some(cycle) = cycle
+	else
+	{
+		return measure(&state);
+	};
+	crate::debug!(
+		"cycle of {} steps after {} steps",
+		cycle.length,
+		cycle.start
+	);
+	let now = cycle.start + cycle.length;
+	let growth = measure(&state) - history[cycle.start];
+	let num_skipped = (target - now) / cycle.length;
+	for _ in 0..((target - now) % cycle.length)
+	{
+		step(&mut state);
+	}
+	measure(&state) + num_skipped * growth
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	// Counts how often a pseudo-random sequence has landed on an even number.
+	#[derive(Debug, Clone, Copy)]
+	struct Counter
+	{
+		x: u32,
+		num_even: usize,
+	}
+
+	fn next(counter: &mut Counter)
+	{
+		counter.x = (counter.x * counter.x + 1) % 23;
+		if counter.x.is_multiple_of(2)
+		{
+			counter.num_even += 1;
+		}
+	}
+
+	fn brute_force(target: usize) -> usize
+	{
+		let mut counter = Counter { x: 3, num_even: 0 };
+		for _ in 0..target
+		{
+			next(&mut counter);
+		}
+		counter.num_even
+	}
+
+	#[test]
+	fn find_cycle_after_a_tail()
+	{
+		let mut counter = Counter { x: 3, num_even: 0 };
+		let cycle = find_cycle(&mut counter, 100, next, |counter| counter.x);
+		// 3, 10, 9, 13, 9, ...
+		let expected = Cycle {
+			start: 2,
+			length: 2,
+		};
+		assert_eq!(cycle, Some(expected));
+		let mut counter = Counter { x: 3, num_even: 0 };
+		let cycle = find_cycle(&mut counter, 3, next, |counter| counter.x);
+		assert_eq!(cycle, None);
+	}
+
+	#[test]
+	fn extrapolate_matches_brute_force()
+	{
+		for target in [0, 1, 2, 3, 4, 5, 17, 100, 1001]
+		{
+			let counter = Counter { x: 3, num_even: 0 };
+			let count = extrapolate(
+				counter,
+				target,
+				next,
+				|counter| counter.x,
+				|counter| counter.num_even,
+			);
+			assert_eq!(count, brute_force(target), "after {} steps", target);
+		}
+	}
+}
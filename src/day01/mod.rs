@@ -0,0 +1,655 @@
+/**/
+
+use crate::generate::Rng;
+use crate::parse::parse_line;
+use crate::Answer;
+use crate::ParseError;
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+pub fn one(input: &str) -> i64
+{
+	most_calories(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> i64
+{
+	top_three_calories(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Elf>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Elf>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, elves: &Vec<Elf>) -> Option<Answer>
+	{
+		Some(most_calories(elves).into())
+	}
+
+	fn two(&self, elves: &Vec<Elf>) -> Option<Answer>
+	{
+		Some(top_three_calories(elves).into())
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf
+{
+	// Counting from 1, in the order of the input.
+	pub number: usize,
+	pub num_items: usize,
+	pub total: i64,
+}
+
+#[derive(Debug)]
+pub enum ReadError
+{
+	Io(std::io::Error),
+	Parse(ParseError),
+}
+
+impl std::fmt::Display for ReadError
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		match self
+		{
+			ReadError::Io(error) => write!(f, "{}", error),
+			ReadError::Parse(error) => write!(f, "{}", error),
+		}
+	}
+}
+
+impl std::error::Error for ReadError {}
+
+fn parse(input: &str) -> Result<Vec<Elf>, ParseError>
+{
+	let elves = read_elves(input.as_bytes())
+		.map(|elf| {
+			elf.map_err(|error| match error
+			{
+				ReadError::Parse(error) => error,
+				ReadError::Io(error) => unreachable!("{}", error),
+			})
+		})
+		.collect::<Result<Vec<Elf>, ParseError>>()?;
+	// Part two adds up the three largest totals, which must fit as well.
+	if top_calories(&elves, 3).is_none()
+	{
+		return Err(ParseError::at_end(input, "too many calories in total"));
+	}
+	Ok(elves)
+}
+
+/// Reads the elves one at a time, each with the calories of the items they
+/// carry on consecutive lines, separated by blank lines.
+pub fn read_elves<R: BufRead>(reader: R) -> Elves<R>
+{
+	Elves {
+		lines: reader.lines().enumerate(),
+		number: 0,
+		is_done: false,
+	}
+}
+
+pub struct Elves<R>
+{
+	lines: std::iter::Enumerate<std::io::Lines<R>>,
+	number: usize,
+	is_done: bool,
+}
+
+impl<R: BufRead> Iterator for Elves<R>
+{
+	type Item = Result<Elf, ReadError>;
+
+	fn next(&mut self) -> Option<Result<Elf, ReadError>>
+	{
+		if self.is_done
+		{
+			return None;
+		}
+		let mut elf = Elf {
+			number: self.number + 1,
+			num_items: 0,
+			total: 0,
+		};
+		loop
+		{
+			let (i, line) = match self.lines.next()
+			{
+				Some((i, Ok(line))) => (i, line),
+				Some((_, Err(error))) =>
+				{
+					self.is_done = true;
+					return Some(Err(ReadError::Io(error)));
+				}
+				None =>
+				{
+					self.is_done = true;
+					break;
+				}
+			};
+			if line.is_empty()
+			{
+				// Extra blank lines do not make an elf without any items.
+				if elf.num_items == 0
+				{
+					continue;
+				}
+				break;
+			}
+			let total = parse_line(i, &line).and_then(|calories: i64| {
+				elf.total.checked_add(calories).ok_or_else(|| {
+					ParseError::at_line(i, &line, "too many calories")
+				})
+			});
+			match total
+			{
+				Ok(total) =>
+				{
+					elf.total = total;
+					elf.num_items += 1;
+				}
+				Err(error) =>
+				{
+					self.is_done = true;
+					return Some(Err(ReadError::Parse(error)));
+				}
+			}
+		}
+		if elf.num_items == 0
+		{
+			return None;
+		}
+		self.number = elf.number;
+		Some(Ok(elf))
+	}
+}
+
+pub fn generate(rng: &mut Rng, num_elves: usize) -> String
+{
+	let elves: Vec<String> = (0..num_elves)
+		.map(|_| {
+			(0..rng.range(1..=15))
+				.map(|_| format!("{}\n", rng.range(1000..=60000)))
+				.collect()
+		})
+		.collect();
+	elves.join("\n")
+}
+
+/// Keeps the `k` elves that carry the most calories out of a stream. Of two
+/// elves with the same total, the one that came first ranks higher.
+pub struct TopK
+{
+	k: usize,
+	// The worst of the elves kept so far is on top.
+	heap: BinaryHeap<(Reverse<i64>, usize, usize)>,
+}
+
+impl TopK
+{
+	pub fn new(k: usize) -> TopK
+	{
+		TopK {
+			k,
+			heap: BinaryHeap::with_capacity(k.min(1024) + 1),
+		}
+	}
+
+	pub fn push(&mut self, elf: Elf)
+	{
+		let key = (Reverse(elf.total), elf.number, elf.num_items);
+		if self.heap.len() < self.k
+		{
+			self.heap.push(key);
+		}
+		else if let Some(mut worst) = self.heap.peek_mut()
+		{
+			if key < *worst
+			{
+				*worst = key;
+			}
+		}
+	}
+
+	/// The elves kept along with their rank, the one with the most calories
+	/// first. Elves with the same total share a rank, so that the ranks can
+	/// skip a number.
+	pub fn into_ranked(self) -> Vec<Ranked>
+	{
+		let mut ranked: Vec<Ranked> = Vec::with_capacity(self.heap.len());
+		for (i, elf) in self.into_sorted().into_iter().enumerate()
+		{
+			let rank = match ranked.last()
+			{
+				Some(previous) if previous.elf.total == elf.total =>
+				{
+					previous.rank
+				}
+				_ => i + 1,
+			};
+			ranked.push(Ranked { rank, elf });
+		}
+		ranked
+	}
+
+	/// The elves kept, the one with the most calories first.
+	pub fn into_sorted(self) -> Vec<Elf>
+	{
+		self.heap
+			.into_sorted_vec()
+			.into_iter()
+			.map(|(Reverse(total), number, num_items)| Elf {
+				number,
+				num_items,
+				total,
+			})
+			.collect()
+	}
+}
+
+impl Extend<Elf> for TopK
+{
+	fn extend<I: IntoIterator<Item = Elf>>(&mut self, elves: I)
+	{
+		for elf in elves
+		{
+			self.push(elf);
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranked
+{
+	pub rank: usize,
+	pub elf: Elf,
+}
+
+/// The distribution of the calories carried by a group of elves.
+#[derive(Debug, Clone)]
+pub struct Distribution
+{
+	// Sorted by total, then by number.
+	elves: Vec<Elf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket
+{
+	// Both inclusive.
+	pub low: i64,
+	pub high: i64,
+	pub count: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fences
+{
+	pub low: i64,
+	pub high: i64,
+}
+
+impl Distribution
+{
+	/// Returns None if there are no elves at all.
+	pub fn new(elves: impl IntoIterator<Item = Elf>) -> Option<Distribution>
+	{
+		let mut elves: Vec<Elf> = elves.into_iter().collect();
+		if elves.is_empty()
+		{
+			return None;
+		}
+		elves.sort_by_key(|elf| (elf.total, elf.number));
+		Some(Distribution { elves })
+	}
+
+	pub fn count(&self) -> usize
+	{
+		self.elves.len()
+	}
+
+	pub fn min(&self) -> i64
+	{
+		self.elves[0].total
+	}
+
+	pub fn max(&self) -> i64
+	{
+		self.elves[self.elves.len() - 1].total
+	}
+
+	pub fn mean(&self) -> f64
+	{
+		// The sum of many i64 totals does not always fit in an i64.
+		let sum: i128 = self.elves.iter().map(|elf| elf.total as i128).sum();
+		sum as f64 / self.count() as f64
+	}
+
+	/// The population standard deviation.
+	pub fn std_dev(&self) -> f64
+	{
+		let mean = self.mean();
+		let sum_of_squares: f64 = self
+			.elves
+			.iter()
+			.map(|elf| (elf.total as f64 - mean).powi(2))
+			.sum();
+		(sum_of_squares / self.count() as f64).sqrt()
+	}
+
+	pub fn median(&self) -> f64
+	{
+		let n = self.count();
+		if n % 2 == 1
+		{
+			self.elves[n / 2].total as f64
+		}
+		else
+		{
+			let low = self.elves[n / 2 - 1].total as f64;
+			let high = self.elves[n / 2].total as f64;
+			(low + high) / 2.0
+		}
+	}
+
+	/// The nearest-rank percentile, for `p` between 0 (exclusive) and 100.
+	pub fn percentile(&self, p: f64) -> i64
+	{
+		assert!(p > 0.0 && p <= 100.0, "invalid percentile {}", p);
+		let rank = (p / 100.0 * self.count() as f64).ceil() as usize;
+		self.elves[rank.clamp(1, self.count()) - 1].total
+	}
+
+	/// Splits the range from the least to the most calories into at most
+	/// `num_buckets` buckets of equal width, and counts the elves in each.
+	pub fn histogram(&self, num_buckets: usize) -> Vec<Bucket>
+	{
+		assert!(num_buckets > 0);
+		let min = self.min() as i128;
+		let range = self.max() as i128 - min + 1;
+		let width = (range + num_buckets as i128 - 1) / num_buckets as i128;
+		let num_buckets = (range + width - 1) / width;
+		let mut buckets: Vec<Bucket> = (0..num_buckets)
+			.map(|i| Bucket {
+				low: (min + i * width) as i64,
+				high: (min + (i + 1) * width - 1).min(self.max() as i128)
+					as i64,
+				count: 0,
+			})
+			.collect();
+		for elf in &self.elves
+		{
+			let i = (elf.total as i128 - min) / width;
+			buckets[i as usize].count += 1;
+		}
+		buckets
+	}
+
+	/// Tukey's fences: one and a half times the interquartile range below
+	/// the first quartile and above the third.
+	pub fn fences(&self) -> Fences
+	{
+		let q1 = self.percentile(25.0) as i128;
+		let q3 = self.percentile(75.0) as i128;
+		let margin = (q3 - q1) * 3 / 2;
+		let clamp = |x: i128| x.clamp(i64::MIN as i128, i64::MAX as i128);
+		Fences {
+			low: clamp(q1 - margin) as i64,
+			high: clamp(q3 + margin) as i64,
+		}
+	}
+
+	/// The elves that carry fewer calories than the low fence or more than
+	/// the high one, in the order of the input.
+	pub fn outliers(&self) -> Vec<Elf>
+	{
+		let fences = self.fences();
+		let mut outliers: Vec<Elf> = self
+			.elves
+			.iter()
+			.filter(|elf| elf.total < fences.low || elf.total > fences.high)
+			.copied()
+			.collect();
+		outliers.sort_by_key(|elf| elf.number);
+		outliers
+	}
+}
+
+// The total of a single elf always fits.
+fn most_calories(elves: &[Elf]) -> i64
+{
+	elves.iter().map(|elf| elf.total).max().unwrap_or(0)
+}
+
+fn top_three_calories(elves: &[Elf]) -> i64
+{
+	top_calories(elves, 3).expect("the total was checked when parsing")
+}
+
+// Returns None if the total does not fit.
+fn top_calories(elves: &[Elf], k: usize) -> Option<i64>
+{
+	let mut top = TopK::new(k);
+	top.extend(elves.iter().copied());
+	top.into_sorted()
+		.iter()
+		.try_fold(0i64, |sum, elf| sum.checked_add(elf.total))
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+	const TEST: &str = include_str!("test.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 24000);
+	}
+
+	#[test]
+	fn one_test()
+	{
+		assert_eq!(one(TEST), 24001);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 45000);
+	}
+
+	#[test]
+	fn two_test()
+	{
+		assert_eq!(two(TEST), 59001);
+	}
+
+	fn elf(number: usize, num_items: usize, total: i64) -> Elf
+	{
+		Elf {
+			number,
+			num_items,
+			total,
+		}
+	}
+
+	#[test]
+	fn rank_top_elves()
+	{
+		let mut top = TopK::new(3);
+		for elf in read_elves(PROVIDED.as_bytes())
+		{
+			top.push(elf.unwrap());
+		}
+		let ranked = top.into_ranked();
+		let expected = [
+			Ranked {
+				rank: 1,
+				elf: elf(4, 3, 24000),
+			},
+			Ranked {
+				rank: 2,
+				elf: elf(3, 2, 11000),
+			},
+			Ranked {
+				rank: 3,
+				elf: elf(5, 1, 10000),
+			},
+		];
+		assert_eq!(ranked, expected);
+	}
+
+	#[test]
+	fn share_ranks_between_equal_totals()
+	{
+		let mut top = TopK::new(4);
+		top.extend(
+			[5, 9, 5, 7, 9, 5]
+				.iter()
+				.enumerate()
+				.map(|(i, &total)| elf(i + 1, 1, total)),
+		);
+		let ranks: Vec<(usize, usize)> = top
+			.into_ranked()
+			.iter()
+			.map(|ranked| (ranked.rank, ranked.elf.number))
+			.collect();
+		assert_eq!(ranks, [(1, 2), (1, 5), (3, 4), (4, 1)]);
+	}
+
+	#[test]
+	fn stop_at_overflow()
+	{
+		let input = "1\n\n9223372036854775807\n1\n\n2\n";
+		let mut elves = read_elves(input.as_bytes());
+		assert_eq!(elves.next().unwrap().unwrap(), elf(1, 1, 1));
+		match elves.next()
+		{
+			Some(Err(ReadError::Parse(error))) =>
+			{
+				assert_eq!(
+					error,
+					ParseError::new(4, 1, "1", "too many calories")
+				);
+			}
+			other => panic!("expected an overflow, got {:?}", other),
+		}
+		assert!(elves.next().is_none());
+	}
+
+	#[test]
+	fn skip_blank_lines_between_elves()
+	{
+		let expected = vec![elf(1, 2, 3), elf(2, 1, 3)];
+		assert_eq!(parse("1\n2\n\n3\n\n"), Ok(expected.clone()));
+		assert_eq!(parse("1\n2\n\n\n3\n"), Ok(expected.clone()));
+		assert_eq!(parse("\n1\n2\n\n3\n"), Ok(expected));
+		assert_eq!(parse(""), Ok(Vec::new()));
+		assert_eq!(parse("\n\n"), Ok(Vec::new()));
+	}
+
+	#[test]
+	fn reject_overflow_in_total()
+	{
+		let input = "9223372036854775807\n\n1\n";
+		let error = ParseError::new(4, 1, "", "too many calories in total");
+		assert_eq!(parse(input), Err(error));
+		assert_eq!(
+			parse("9223372036854775807\n\n-1\n"),
+			Ok(vec![elf(1, 1, i64::MAX), elf(2, 1, -1)])
+		);
+	}
+
+	fn distribution(totals: &[i64]) -> Distribution
+	{
+		let elves = totals
+			.iter()
+			.enumerate()
+			.map(|(i, &total)| elf(i + 1, 1, total));
+		Distribution::new(elves).unwrap()
+	}
+
+	#[test]
+	fn describe_provided()
+	{
+		let elves = parse(PROVIDED).unwrap();
+		let distribution = Distribution::new(elves).unwrap();
+		assert_eq!(distribution.count(), 5);
+		assert_eq!(distribution.min(), 4000);
+		assert_eq!(distribution.max(), 24000);
+		assert_eq!(distribution.mean(), 11000.0);
+		assert_eq!(distribution.median(), 10000.0);
+		assert_eq!(distribution.std_dev(), 48_800_000f64.sqrt());
+		assert_eq!(distribution.percentile(20.0), 4000);
+		assert_eq!(distribution.percentile(50.0), 10000);
+		assert_eq!(distribution.percentile(100.0), 24000);
+	}
+
+	#[test]
+	fn median_of_even_count()
+	{
+		assert_eq!(distribution(&[4, 1, 3, 2]).median(), 2.5);
+		assert!(Distribution::new(Vec::new()).is_none());
+	}
+
+	#[test]
+	fn count_elves_per_bucket()
+	{
+		let histogram = distribution(&[10, 19, 11, 30, 25, 10]).histogram(3);
+		let expected = [
+			Bucket {
+				low: 10,
+				high: 16,
+				count: 3,
+			},
+			Bucket {
+				low: 17,
+				high: 23,
+				count: 1,
+			},
+			Bucket {
+				low: 24,
+				high: 30,
+				count: 2,
+			},
+		];
+		assert_eq!(histogram, expected);
+		let histogram = distribution(&[7, 7]).histogram(10);
+		let expected = [Bucket {
+			low: 7,
+			high: 7,
+			count: 2,
+		}];
+		assert_eq!(histogram, expected);
+	}
+
+	#[test]
+	fn find_outliers()
+	{
+		let distribution = distribution(&[50, 52, 49, 51, 120, 48, 50, 3]);
+		assert_eq!(distribution.fences(), Fences { low: 44, high: 55 });
+		let numbers: Vec<usize> = distribution
+			.outliers()
+			.iter()
+			.map(|elf| elf.number)
+			.collect();
+		assert_eq!(numbers, [5, 8]);
+	}
+}
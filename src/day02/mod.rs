@@ -0,0 +1,1053 @@
+/**/
+
+use crate::generate::Rng;
+use crate::Answer;
+use crate::ParseError;
+
+use std::collections::HashMap;
+
+pub fn one(input: &str) -> i32
+{
+	parse(input).unwrap().total_score(Interpretation::Response)
+}
+
+pub fn two(input: &str) -> i32
+{
+	parse(input).unwrap().total_score(Interpretation::Outcome)
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Guide;
+
+	fn parse(&self, input: &str) -> Result<Guide, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, guide: &Guide) -> Option<Answer>
+	{
+		Some(guide.total_score(Interpretation::Response).into())
+	}
+
+	fn two(&self, guide: &Guide) -> Option<Answer>
+	{
+		Some(guide.total_score(Interpretation::Outcome).into())
+	}
+}
+
+fn parse(input: &str) -> Result<Guide, ParseError>
+{
+	Guide::parse(Rules::standard(), input)
+}
+
+/// The rules of the puzzle itself, in the format of `Rules::from_str`.
+pub const STANDARD_RULES: &str = "moves Rock Paper Scissors
+opponent A B C
+second X Y Z
+responses Rock Paper Scissors
+outcomes loss draw win
+move-scores 1 2 3
+outcome-scores 0 3 6
+";
+
+/// One of the moves of a game, by its position in the cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Move(usize);
+
+impl Move
+{
+	pub fn index(self) -> usize
+	{
+		self.0
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Opponent(pub Move);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Response(pub Move);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome
+{
+	Loss,
+	Draw,
+	Win,
+}
+
+impl Outcome
+{
+	pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+	fn index(self) -> usize
+	{
+		self as usize
+	}
+
+	pub fn name(self) -> &'static str
+	{
+		match self
+		{
+			Outcome::Loss => "loss",
+			Outcome::Draw => "draw",
+			Outcome::Win => "win",
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round
+{
+	pub opponent: Opponent,
+	pub response: Response,
+}
+
+/// What the second column of the strategy guide means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation
+{
+	// The move to play.
+	Response,
+	// How the round should end.
+	Outcome,
+}
+
+impl Interpretation
+{
+	pub fn name(self) -> &'static str
+	{
+		match self
+		{
+			Interpretation::Response => "response",
+			Interpretation::Outcome => "outcome",
+		}
+	}
+}
+
+/// A line of the strategy guide, with the second column as the position of
+/// its symbol, because what it means depends on the interpretation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line
+{
+	pub opponent: Opponent,
+	pub second: usize,
+}
+
+/// A cyclic game with an odd number of moves, in which each move beats the
+/// half of the other moves that come right before it in the cycle, along
+/// with the symbols of the strategy guide and how rounds are scored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules
+{
+	moves: Vec<String>,
+	opponent_symbols: Vec<String>,
+	second_symbols: Vec<String>,
+	// What each symbol of the second column means, in either interpretation.
+	responses: Vec<Move>,
+	outcomes: Vec<Outcome>,
+	move_scores: Vec<i32>,
+	outcome_scores: [i32; 3],
+	// The outcome of each response against each move of the opponent.
+	table: Vec<Vec<Outcome>>,
+}
+
+impl Rules
+{
+	pub fn standard() -> Rules
+	{
+		STANDARD_RULES.parse().unwrap()
+	}
+
+	pub fn num_moves(&self) -> usize
+	{
+		self.moves.len()
+	}
+
+	pub fn move_name(&self, x: Move) -> &str
+	{
+		&self.moves[x.0]
+	}
+
+	pub fn outcome(&self, round: Round) -> Outcome
+	{
+		self.table[round.response.0.index()][round.opponent.0.index()]
+	}
+
+	pub fn score(&self, round: Round) -> i32
+	{
+		let outcome = self.outcome(round);
+		self.outcome_scores[outcome.index()]
+			+ self.move_scores[round.response.0.index()]
+	}
+
+	/// The response that gives the desired outcome. If there are several,
+	/// the one that scores the most.
+	pub fn response_for(&self, opponent: Opponent, outcome: Outcome)
+		-> Response
+	{
+		(0..self.num_moves())
+			.map(|i| Response(Move(i)))
+			.filter(|&response| {
+				self.outcome(Round { opponent, response }) == outcome
+			})
+			.max_by_key(|response| {
+				(
+					self.move_scores[response.0.index()],
+					std::cmp::Reverse(response.0.index()),
+				)
+			})
+			.expect("every outcome is possible in a cyclic game")
+	}
+
+	pub fn round(&self, line: Line, interpretation: Interpretation) -> Round
+	{
+		let opponent = line.opponent;
+		let response = match interpretation
+		{
+			Interpretation::Response => Response(self.responses[line.second]),
+			Interpretation::Outcome =>
+			{
+				self.response_for(opponent, self.outcomes[line.second])
+			}
+		};
+		Round { opponent, response }
+	}
+
+	fn parse_line(&self, i: usize, line: &str) -> Result<Line, ParseError>
+	{
+		let (first, second) = line.split_once(' ').ok_or_else(|| {
+			ParseError::at_line(i, line, "expected two columns")
+		})?;
+		let opponent = position(&self.opponent_symbols, first)
+			.ok_or_else(|| ParseError::at_byte(i, 0, line))?;
+		let second = position(&self.second_symbols, second)
+			.ok_or_else(|| ParseError::at_byte(i, first.len() + 1, line))?;
+		Ok(Line {
+			opponent: Opponent(Move(opponent)),
+			second,
+		})
+	}
+}
+
+fn position(symbols: &[String], symbol: &str) -> Option<usize>
+{
+	symbols.iter().position(|x| x == symbol)
+}
+
+const RULE_KEYS: [&str; 7] = [
+	"moves",
+	"opponent",
+	"second",
+	"responses",
+	"outcomes",
+	"move-scores",
+	"outcome-scores",
+];
+
+// Each line has a key followed by a list of values, separated by spaces.
+// Blank lines and lines starting with "#" are ignored.
+impl std::str::FromStr for Rules
+{
+	type Err = ParseError;
+
+	fn from_str(input: &str) -> Result<Rules, ParseError>
+	{
+		let mut entries: HashMap<&str, (usize, &str, Vec<&str>)> =
+			HashMap::new();
+		for (i, line) in input.lines().enumerate()
+		{
+			if line.trim().is_empty() || line.starts_with('#')
+			{
+				continue;
+			}
+			let mut words = line.split_whitespace();
+			let key = words.next().unwrap();
+			if !RULE_KEYS.contains(&key)
+			{
+				return Err(ParseError::at_line(i, line, "unknown key"));
+			}
+			if entries.insert(key, (i, line, words.collect())).is_some()
+			{
+				return Err(ParseError::at_line(i, line, "duplicate key"));
+			}
+		}
+		let entry = |key: &str| {
+			entries.get(key).ok_or_else(|| {
+				ParseError::at_end(input, format!("missing {}", key))
+			})
+		};
+		let sized = |key: &str, len: usize| {
+			let (i, line, values) = entry(key)?;
+			if values.len() != len
+			{
+				let reason = format!("expected {} values", len);
+				return Err(ParseError::at_line(*i, line, reason));
+			}
+			Ok((*i, *line, values))
+		};
+		let unique = |key: &str| {
+			let (i, line, values) = entry(key)?;
+			let mut sorted = values.clone();
+			sorted.sort();
+			sorted.dedup();
+			if sorted.len() != values.len()
+			{
+				return Err(ParseError::at_line(*i, line, "duplicate value"));
+			}
+			let values: Vec<String> =
+				values.iter().map(|x| x.to_string()).collect();
+			Ok(values)
+		};
+
+		let moves = unique("moves")?;
+		let n = moves.len();
+		if n < 3 || n % 2 == 0
+		{
+			let (i, line, _) = entry("moves")?;
+			let reason = "expected an odd number of moves, at least 3";
+			return Err(ParseError::at_line(*i, line, reason));
+		}
+		sized("opponent", n)?;
+		let opponent_symbols = unique("opponent")?;
+		let second_symbols = unique("second")?;
+		let num_second = second_symbols.len();
+		let (i, line, values) = sized("responses", num_second)?;
+		let responses = values
+			.iter()
+			.map(|name| {
+				position(&moves, name)
+					.map(Move)
+					.ok_or_else(|| ParseError::at_line(i, line, "unknown move"))
+			})
+			.collect::<Result<Vec<Move>, ParseError>>()?;
+		let (i, line, values) = sized("outcomes", num_second)?;
+		let outcomes = values
+			.iter()
+			.map(|name| {
+				Outcome::ALL
+					.into_iter()
+					.find(|outcome| outcome.name() == *name)
+					.ok_or_else(|| {
+						ParseError::at_line(i, line, "unknown outcome")
+					})
+			})
+			.collect::<Result<Vec<Outcome>, ParseError>>()?;
+		let scores = |key: &str, len: usize| {
+			let (i, line, values) = sized(key, len)?;
+			values
+				.iter()
+				.map(|x| {
+					x.parse().map_err(|_| {
+						ParseError::at_line(i, line, "invalid score")
+					})
+				})
+				.collect::<Result<Vec<i32>, ParseError>>()
+		};
+		let move_scores = scores("move-scores", n)?;
+		let outcome_scores = scores("outcome-scores", 3)?;
+		let outcome_scores =
+			[outcome_scores[0], outcome_scores[1], outcome_scores[2]];
+
+		let table = (0..n)
+			.map(|response| {
+				(0..n)
+					.map(|opponent| match (response + n - opponent) % n
+					{
+						0 => Outcome::Draw,
+						d if d <= n / 2 => Outcome::Win,
+						_ => Outcome::Loss,
+					})
+					.collect()
+			})
+			.collect();
+		Ok(Rules {
+			moves,
+			opponent_symbols,
+			second_symbols,
+			responses,
+			outcomes,
+			move_scores,
+			outcome_scores,
+			table,
+		})
+	}
+}
+
+/// A strategy guide along with the rules of the game that it is for.
+#[derive(Debug, Clone)]
+pub struct Guide
+{
+	pub rules: Rules,
+	pub lines: Vec<Line>,
+}
+
+impl Guide
+{
+	pub fn parse(rules: Rules, input: &str) -> Result<Guide, ParseError>
+	{
+		let lines = input
+			.lines()
+			.enumerate()
+			.map(|(i, line)| rules.parse_line(i, line))
+			.collect::<Result<Vec<Line>, ParseError>>()?;
+		Ok(Guide { rules, lines })
+	}
+
+	pub fn rounds(
+		&self,
+		interpretation: Interpretation,
+	) -> impl Iterator<Item = Round> + '_
+	{
+		self.lines
+			.iter()
+			.map(move |&line| self.rules.round(line, interpretation))
+	}
+
+	pub fn total_score(&self, interpretation: Interpretation) -> i32
+	{
+		self.rounds(interpretation)
+			.map(|round| self.rules.score(round))
+			.sum()
+	}
+
+	/// Scores every way to read the second column as distinct moves, in
+	/// lexicographic order, followed by reading it as the desired outcomes.
+	pub fn mappings(&self) -> Vec<Mapping>
+	{
+		let rules = &self.rules;
+		let n = rules.num_moves();
+		let k = rules.second_symbols.len();
+		// The score only depends on how often each line occurs.
+		let mut counts = vec![vec![0i64; k]; n];
+		for line in &self.lines
+		{
+			counts[line.opponent.0.index()][line.second] += 1;
+		}
+		let score_of = |responses: &[Move]| -> i64 {
+			let mut score = 0;
+			for (opponent, row) in counts.iter().enumerate()
+			{
+				for (second, &count) in row.iter().enumerate()
+				{
+					let round = Round {
+						opponent: Opponent(Move(opponent)),
+						response: Response(responses[second]),
+					};
+					score += count * i64::from(rules.score(round));
+				}
+			}
+			score
+		};
+		let mut mappings: Vec<Mapping> = permutations(n, k)
+			.into_iter()
+			.map(|indices| {
+				let responses: Vec<Move> =
+					indices.into_iter().map(Move).collect();
+				Mapping {
+					score: score_of(&responses),
+					meaning: Meaning::Responses(responses),
+				}
+			})
+			.collect();
+		let score = self
+			.rounds(Interpretation::Outcome)
+			.map(|round| i64::from(rules.score(round)))
+			.sum();
+		mappings.push(Mapping {
+			meaning: Meaning::Outcomes(rules.outcomes.clone()),
+			score,
+		});
+		mappings
+	}
+
+	/// Describes what each symbol of the second column means, such as
+	/// "X=Rock Y=Paper Z=Scissors".
+	pub fn describe(&self, meaning: &Meaning) -> String
+	{
+		let symbols = &self.rules.second_symbols;
+		let names: Vec<&str> = match meaning
+		{
+			Meaning::Responses(responses) =>
+			{
+				responses.iter().map(|&x| self.rules.move_name(x)).collect()
+			}
+			Meaning::Outcomes(outcomes) =>
+			{
+				outcomes.iter().map(|outcome| outcome.name()).collect()
+			}
+		};
+		let pairs: Vec<String> = symbols
+			.iter()
+			.zip(names)
+			.map(|(symbol, name)| format!("{}={}", symbol, name))
+			.collect();
+		pairs.join(" ")
+	}
+}
+
+/// What each symbol of the second column of a guide means.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Meaning
+{
+	Responses(Vec<Move>),
+	Outcomes(Vec<Outcome>),
+}
+
+impl Meaning
+{
+	pub fn interpretation(&self) -> Interpretation
+	{
+		match self
+		{
+			Meaning::Responses(_) => Interpretation::Response,
+			Meaning::Outcomes(_) => Interpretation::Outcome,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping
+{
+	pub meaning: Meaning,
+	pub score: i64,
+}
+
+/// The mapping with the highest score, the first one if there are several.
+pub fn best_mapping(mappings: &[Mapping]) -> Option<&Mapping>
+{
+	mappings.iter().rev().max_by_key(|mapping| mapping.score)
+}
+
+/// All ordered selections of `k` distinct numbers below `n`.
+fn permutations(n: usize, k: usize) -> Vec<Vec<usize>>
+{
+	let mut permutations = Vec::new();
+	let mut current = Vec::with_capacity(k);
+	let mut is_used = vec![false; n];
+	extend_permutations(&mut current, &mut is_used, k, &mut permutations);
+	permutations
+}
+
+fn extend_permutations(
+	current: &mut Vec<usize>,
+	is_used: &mut [bool],
+	k: usize,
+	permutations: &mut Vec<Vec<usize>>,
+)
+{
+	if current.len() == k
+	{
+		permutations.push(current.clone());
+		return;
+	}
+	for i in 0..is_used.len()
+	{
+		if !is_used[i]
+		{
+			is_used[i] = true;
+			current.push(i);
+			extend_permutations(current, is_used, k, permutations);
+			current.pop();
+			is_used[i] = false;
+		}
+	}
+}
+
+/// A player in a tournament, which can learn from the rounds played so far.
+pub trait Strategy
+{
+	fn name(&self) -> &str;
+
+	/// Forgets what was learned, before the start of a match.
+	fn reset(&mut self) {}
+
+	/// Picks a move, given the rounds of the match so far from the point of
+	/// view of this player, so that the opponent is the other one.
+	fn play(&mut self, rules: &Rules, history: &[Round]) -> Response;
+}
+
+/// Plays the moves of a strategy guide in order, starting over at the end.
+pub struct FixedGuide
+{
+	responses: Vec<Response>,
+}
+
+impl FixedGuide
+{
+	pub fn new(guide: &Guide) -> FixedGuide
+	{
+		let responses: Vec<Response> = guide
+			.rounds(Interpretation::Response)
+			.map(|round| round.response)
+			.collect();
+		assert!(!responses.is_empty(), "the strategy guide is empty");
+		FixedGuide { responses }
+	}
+}
+
+impl Strategy for FixedGuide
+{
+	fn name(&self) -> &str
+	{
+		"guide"
+	}
+
+	fn play(&mut self, _rules: &Rules, history: &[Round]) -> Response
+	{
+		self.responses[history.len() % self.responses.len()]
+	}
+}
+
+/// Expects the move that the opponent has played most often, and plays
+/// whatever beats it.
+#[derive(Default)]
+pub struct Frequency
+{
+	counts: Vec<usize>,
+	num_seen: usize,
+}
+
+impl Frequency
+{
+	fn learn(&mut self, rules: &Rules, history: &[Round])
+	{
+		self.counts.resize(rules.num_moves(), 0);
+		for round in &history[self.num_seen..]
+		{
+			self.counts[round.opponent.0.index()] += 1;
+		}
+		self.num_seen = history.len();
+	}
+
+	// The first of the most common moves, or the first move of all.
+	fn predict(&self) -> Opponent
+	{
+		let mut best = 0;
+		for (i, &count) in self.counts.iter().enumerate()
+		{
+			if count > self.counts[best]
+			{
+				best = i;
+			}
+		}
+		Opponent(Move(best))
+	}
+}
+
+impl Strategy for Frequency
+{
+	fn name(&self) -> &str
+	{
+		"frequency"
+	}
+
+	fn reset(&mut self)
+	{
+		*self = Frequency::default();
+	}
+
+	fn play(&mut self, rules: &Rules, history: &[Round]) -> Response
+	{
+		self.learn(rules, history);
+		rules.response_for(self.predict(), Outcome::Win)
+	}
+}
+
+/// Expects the move that most often followed the last move of the opponent,
+/// and plays whatever beats it. Until that move has been followed by
+/// anything, it counts moves like Frequency.
+#[derive(Default)]
+pub struct Markov
+{
+	// How often each move of the opponent followed each other move.
+	transitions: Vec<Vec<usize>>,
+	frequency: Frequency,
+}
+
+impl Strategy for Markov
+{
+	fn name(&self) -> &str
+	{
+		"markov"
+	}
+
+	fn reset(&mut self)
+	{
+		*self = Markov::default();
+	}
+
+	fn play(&mut self, rules: &Rules, history: &[Round]) -> Response
+	{
+		let n = rules.num_moves();
+		self.transitions.resize(n, vec![0; n]);
+		let start = self.frequency.num_seen.max(1);
+		for i in start..history.len()
+		{
+			let previous = history[i - 1].opponent.0.index();
+			let next = history[i].opponent.0.index();
+			self.transitions[previous][next] += 1;
+		}
+		self.frequency.learn(rules, history);
+		let Some(last) = history.last()
+		else
+		{
+			return rules.response_for(self.frequency.predict(), Outcome::Win);
+		};
+		let row = &self.transitions[last.opponent.0.index()];
+		let mut best = None;
+		for (i, &count) in row.iter().enumerate()
+		{
+			if count > 0 && best.is_none_or(|j: usize| count > row[j])
+			{
+				best = Some(i);
+			}
+		}
+		let prediction = match best
+		{
+			Some(i) => Opponent(Move(i)),
+			None => self.frequency.predict(),
+		};
+		rules.response_for(prediction, Outcome::Win)
+	}
+}
+
+/// Plays any move with equal probability, the same ones for the same seed.
+pub struct Random
+{
+	seed: u64,
+	rng: Rng,
+}
+
+impl Random
+{
+	pub fn new(seed: u64) -> Random
+	{
+		Random {
+			seed,
+			rng: Rng::new(seed),
+		}
+	}
+}
+
+impl Strategy for Random
+{
+	fn name(&self) -> &str
+	{
+		"random"
+	}
+
+	fn reset(&mut self)
+	{
+		self.rng = Rng::new(self.seed);
+	}
+
+	fn play(&mut self, rules: &Rules, _history: &[Round]) -> Response
+	{
+		Response(Move(self.rng.below(rules.num_moves())))
+	}
+}
+
+/// How one strategy did over all of its matches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Standing
+{
+	pub name: String,
+	pub rounds: usize,
+	pub wins: usize,
+	pub draws: usize,
+	pub losses: usize,
+	pub score: i64,
+}
+
+impl Standing
+{
+	pub fn win_rate(&self) -> f64
+	{
+		self.wins as f64 / self.rounds.max(1) as f64
+	}
+
+	/// The average score per round.
+	pub fn expected_score(&self) -> f64
+	{
+		self.score as f64 / self.rounds.max(1) as f64
+	}
+
+	fn count(&mut self, rules: &Rules, round: Round)
+	{
+		self.rounds += 1;
+		self.score += i64::from(rules.score(round));
+		match rules.outcome(round)
+		{
+			Outcome::Win => self.wins += 1,
+			Outcome::Draw => self.draws += 1,
+			Outcome::Loss => self.losses += 1,
+		}
+	}
+}
+
+/// Lets every strategy play a match of `num_rounds` rounds against every
+/// other one, and returns their standings in the same order.
+pub fn tournament(
+	rules: &Rules,
+	strategies: &mut [Box<dyn Strategy>],
+	num_rounds: usize,
+) -> Vec<Standing>
+{
+	let mut standings: Vec<Standing> = strategies
+		.iter()
+		.map(|strategy| Standing {
+			name: strategy.name().to_string(),
+			..Default::default()
+		})
+		.collect();
+	for j in 1..strategies.len()
+	{
+		let (left, right) = strategies.split_at_mut(j);
+		for i in 0..j
+		{
+			let (a, b) =
+				play_match(rules, &mut *left[i], &mut *right[0], num_rounds);
+			for round in a
+			{
+				standings[i].count(rules, round);
+			}
+			for round in b
+			{
+				standings[j].count(rules, round);
+			}
+		}
+	}
+	standings
+}
+
+/// Plays a match, and returns the rounds from the point of view of either
+/// player.
+pub fn play_match(
+	rules: &Rules,
+	a: &mut dyn Strategy,
+	b: &mut dyn Strategy,
+	num_rounds: usize,
+) -> (Vec<Round>, Vec<Round>)
+{
+	a.reset();
+	b.reset();
+	let mut history_a = Vec::with_capacity(num_rounds);
+	let mut history_b = Vec::with_capacity(num_rounds);
+	for _ in 0..num_rounds
+	{
+		let move_a = a.play(rules, &history_a).0;
+		let move_b = b.play(rules, &history_b).0;
+		history_a.push(Round {
+			opponent: Opponent(move_b),
+			response: Response(move_a),
+		});
+		history_b.push(Round {
+			opponent: Opponent(move_a),
+			response: Response(move_b),
+		});
+	}
+	(history_a, history_b)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+	const TEST: &str = include_str!("test.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 15);
+	}
+
+	#[test]
+	fn one_test()
+	{
+		assert_eq!(one(TEST), 18 + 9 + 3 + 6 + 9);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 12);
+	}
+
+	#[test]
+	fn two_test()
+	{
+		assert_eq!(two(TEST), 18 + 9 + 3 + 6 + 9);
+	}
+
+	const RPSLS: &str = include_str!("rpsls.txt");
+
+	#[test]
+	fn play_rock_paper_scissors_lizard_spock()
+	{
+		let rules: Rules = RPSLS.parse().unwrap();
+		let named = |name: &str| {
+			Move(rules.moves.iter().position(|x| x == name).unwrap())
+		};
+		let outcome = |response: &str, opponent: &str| {
+			rules.outcome(Round {
+				opponent: Opponent(named(opponent)),
+				response: Response(named(response)),
+			})
+		};
+		assert_eq!(outcome("Paper", "Rock"), Outcome::Win);
+		assert_eq!(outcome("Spock", "Scissors"), Outcome::Win);
+		assert_eq!(outcome("Lizard", "Spock"), Outcome::Win);
+		assert_eq!(outcome("Lizard", "Scissors"), Outcome::Loss);
+		assert_eq!(outcome("Rock", "Spock"), Outcome::Loss);
+		assert_eq!(outcome("Spock", "Spock"), Outcome::Draw);
+
+		let guide = Guide::parse(rules, "A V\nC Z\nE X\n").unwrap();
+		assert_eq!(guide.total_score(Interpretation::Response), 4 + 11 + 3);
+		assert_eq!(guide.total_score(Interpretation::Outcome), 5 + 11 + 8);
+	}
+
+	#[test]
+	fn score_every_mapping()
+	{
+		let guide = parse(PROVIDED).unwrap();
+		let mappings = guide.mappings();
+		let scores: Vec<(String, i64)> = mappings
+			.iter()
+			.map(|mapping| (guide.describe(&mapping.meaning), mapping.score))
+			.collect();
+		let expected = [
+			("X=Rock Y=Paper Z=Scissors", 15),
+			("X=Rock Y=Scissors Z=Paper", 6),
+			("X=Paper Y=Rock Z=Scissors", 15),
+			("X=Paper Y=Scissors Z=Rock", 15),
+			("X=Scissors Y=Rock Z=Paper", 15),
+			("X=Scissors Y=Paper Z=Rock", 24),
+			("X=loss Y=draw Z=win", 12),
+		];
+		let expected: Vec<(String, i64)> = expected
+			.iter()
+			.map(|&(mapping, score)| (mapping.to_string(), score))
+			.collect();
+		assert_eq!(scores, expected);
+		let best = best_mapping(&mappings).unwrap();
+		assert_eq!(best, &mappings[5]);
+		assert_eq!(best.meaning.interpretation(), Interpretation::Response);
+	}
+
+	fn wins(rounds: &[Round], rules: &Rules) -> usize
+	{
+		rounds
+			.iter()
+			.filter(|&&round| rules.outcome(round) == Outcome::Win)
+			.count()
+	}
+
+	#[test]
+	fn learn_from_history()
+	{
+		let rules = Rules::standard();
+		let constant = Guide::parse(rules.clone(), "A X\n").unwrap();
+		let cycle = Guide::parse(rules.clone(), "A X\nA Y\nA Z\n").unwrap();
+		let (rounds, _) = play_match(
+			&rules,
+			&mut Frequency::default(),
+			&mut FixedGuide::new(&constant),
+			100,
+		);
+		assert_eq!(wins(&rounds, &rules), 100);
+		let (rounds, _) = play_match(
+			&rules,
+			&mut Markov::default(),
+			&mut FixedGuide::new(&cycle),
+			100,
+		);
+		assert_eq!(wins(&rounds, &rules), 98);
+		let (rounds, _) = play_match(
+			&rules,
+			&mut Frequency::default(),
+			&mut FixedGuide::new(&cycle),
+			99,
+		);
+		assert_eq!(wins(&rounds, &rules), 33);
+	}
+
+	#[test]
+	fn keep_standings_consistent()
+	{
+		let rules: Rules = RPSLS.parse().unwrap();
+		let guide = Guide::parse(rules.clone(), "A V\nC Z\nE X\n").unwrap();
+		let mut strategies: Vec<Box<dyn Strategy>> = vec![
+			Box::new(FixedGuide::new(&guide)),
+			Box::new(Frequency::default()),
+			Box::new(Markov::default()),
+			Box::new(Random::new(7)),
+		];
+		let standings = tournament(&rules, &mut strategies, 50);
+		let names: Vec<&str> = standings
+			.iter()
+			.map(|standing| standing.name.as_str())
+			.collect();
+		assert_eq!(names, ["guide", "frequency", "markov", "random"]);
+		for standing in &standings
+		{
+			assert_eq!(standing.rounds, 3 * 50);
+			assert_eq!(
+				standing.wins + standing.draws + standing.losses,
+				standing.rounds
+			);
+		}
+		let wins: usize = standings.iter().map(|standing| standing.wins).sum();
+		let losses: usize =
+			standings.iter().map(|standing| standing.losses).sum();
+		assert_eq!(wins, losses);
+		assert_eq!(tournament(&rules, &mut strategies, 50), standings);
+	}
+
+	#[test]
+	fn count_permutations()
+	{
+		assert_eq!(permutations(3, 2).len(), 6);
+		assert_eq!(permutations(5, 5).len(), 120);
+		assert_eq!(permutations(2, 3), Vec::<Vec<usize>>::new());
+	}
+
+	#[test]
+	fn reject_invalid_rules()
+	{
+		let invalid = |rules: &str| rules.parse::<Rules>().unwrap_err();
+		let four_moves =
+			STANDARD_RULES.replace("Scissors\n", "Scissors Well\n");
+		assert_eq!(
+			invalid(&four_moves),
+			ParseError::at_line(
+				0,
+				"moves Rock Paper Scissors Well",
+				"expected an odd number of moves, at least 3"
+			)
+		);
+		let no_scores = STANDARD_RULES.replace("move-scores 1 2 3\n", "");
+		assert_eq!(
+			invalid(&no_scores),
+			ParseError::new(7, 1, "", "missing move-scores")
+		);
+		let unknown = STANDARD_RULES.replace("draw", "tie");
+		assert_eq!(
+			invalid(&unknown),
+			ParseError::at_line(4, "outcomes loss tie win", "unknown outcome")
+		);
+	}
+
+	#[test]
+	fn reject_unknown_symbols()
+	{
+		let error = Guide::parse(Rules::standard(), "A X\nD Y\n").unwrap_err();
+		assert_eq!(error, ParseError::new(2, 1, "D Y", "unexpected 'D'"));
+	}
+}
@@ -0,0 +1,144 @@
+/**/
+
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> i32
+{
+	sum_of_shared_items(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> i32
+{
+	sum_of_badges(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Rucksack>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Rucksack>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, rucksacks: &Vec<Rucksack>) -> Option<Answer>
+	{
+		Some(sum_of_shared_items(rucksacks).into())
+	}
+
+	fn two(&self, rucksacks: &Vec<Rucksack>) -> Option<Answer>
+	{
+		Some(sum_of_badges(rucksacks).into())
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rucksack
+{
+	left: u64,
+	right: u64,
+}
+
+fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError>
+{
+	input
+		.lines()
+		.enumerate()
+		.map(|(i, line)| {
+			if line.len() % 2 != 0
+			{
+				return Err(ParseError::at_line(
+					i,
+					line,
+					"odd number of items",
+				));
+			}
+			let n = line.len() / 2;
+			let left = item_set_bitmask(&line[0..n])
+				.map_err(|j| ParseError::at_byte(i, j, line))?;
+			let right = item_set_bitmask(&line[n..])
+				.map_err(|j| ParseError::at_byte(i, n + j, line))?;
+			Ok(Rucksack { left, right })
+		})
+		.collect()
+}
+
+fn sum_of_shared_items(rucksacks: &[Rucksack]) -> i32
+{
+	rucksacks.iter().map(priority_of_shared_item).sum()
+}
+
+fn priority_of_shared_item(rucksack: &Rucksack) -> i32
+{
+	let shared = rucksack.left & rucksack.right;
+	let offset = shared.trailing_zeros();
+	1 + offset as i32
+}
+
+// On failure, returns the index of the item that is not a letter.
+fn item_set_bitmask(compartment: &str) -> Result<u64, usize>
+{
+	let mut bitmask = 0u64;
+	for (j, item) in compartment.bytes().enumerate()
+	{
+		let offset = match item
+		{
+			b'a'..=b'z' => item - b'a',
+			b'A'..=b'Z' => 26 + item - b'A',
+			_ => return Err(j),
+		};
+		bitmask |= 1 << offset;
+	}
+	Ok(bitmask)
+}
+
+fn sum_of_badges(rucksacks: &[Rucksack]) -> i32
+{
+	rucksacks
+		.chunks(3)
+		.map(|group| {
+			group.iter().fold(!0u64, |bitmask, rucksack| {
+				bitmask & (rucksack.left | rucksack.right)
+			})
+		})
+		.map(|badge| 1 + badge.trailing_zeros() as i32)
+		.sum()
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+	const TEST: &str = include_str!("test.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 157);
+	}
+
+	#[test]
+	fn one_test()
+	{
+		assert_eq!(one(TEST), 136);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 70);
+	}
+
+	#[test]
+	fn parse_invalid_item()
+	{
+		let error = parse("vJrwpWtwJgWrhcsFMMfFFhFp\nabc!ef\n").unwrap_err();
+		assert_eq!(error, ParseError::new(2, 4, "abc!ef", "unexpected '!'"));
+	}
+}
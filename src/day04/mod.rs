@@ -1,34 +1,65 @@
 /**/
 
-const INPUT: &str = include_str!("input.txt");
+use crate::parse::parse_lines;
+use crate::Answer;
+use crate::ParseError;
 
-pub fn main()
+pub fn one(input: &str) -> usize
 {
-	println!("Part One: {}", one(INPUT));
-	println!("Part Two: {}", two(INPUT));
+	count_fully_contained(&parse(input).unwrap())
 }
 
-fn one(input: &str) -> usize
+pub fn two(input: &str) -> usize
 {
-	input
-		.lines()
-		.map(|line| line.parse().unwrap())
+	count_overlapping(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Pairing>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Pairing>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, pairings: &Vec<Pairing>) -> Option<Answer>
+	{
+		Some(count_fully_contained(pairings).into())
+	}
+
+	fn two(&self, pairings: &Vec<Pairing>) -> Option<Answer>
+	{
+		Some(count_overlapping(pairings).into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Pairing>, ParseError>
+{
+	parse_lines(input)
+}
+
+fn count_fully_contained(pairings: &[Pairing]) -> usize
+{
+	pairings
+		.iter()
 		.filter(|pairing| fully_contained_either_way(pairing))
 		.count()
 }
 
-fn two(input: &str) -> usize
+fn count_overlapping(pairings: &[Pairing]) -> usize
 {
-	input
-		.lines()
-		.map(|line| line.parse().unwrap())
+	pairings
+		.iter()
 		.filter(|pairing| overlap_either_way(pairing))
 		.count()
 }
 
 #[derive(parse_display::Display, parse_display::FromStr)]
 #[display("{a},{b}")]
-struct Pairing
+pub struct Pairing
 {
 	a: Range,
 	b: Range,
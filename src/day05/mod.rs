@@ -0,0 +1,348 @@
+/**/
+
+use crate::parse::parse_line;
+use crate::parse::split_at_blank_line;
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> String
+{
+	rearrange_9000(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> String
+{
+	rearrange_9001(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Procedure;
+
+	fn parse(&self, input: &str) -> Result<Procedure, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, procedure: &Procedure) -> Option<Answer>
+	{
+		Some(rearrange_9000(procedure).into())
+	}
+
+	fn two(&self, procedure: &Procedure) -> Option<Answer>
+	{
+		Some(rearrange_9001(procedure).into())
+	}
+}
+
+pub struct Procedure
+{
+	ship: Ship,
+	instructions: Vec<Instruction>,
+}
+
+fn parse(input: &str) -> Result<Procedure, ParseError>
+{
+	let (diagram, rest_of_input) = split_at_blank_line(input)?;
+	let mut ship = Ship::new();
+	ship.fill(diagram)?;
+	let offset = diagram.lines().count() + 1;
+	// Both cranes leave stacks of the same height, so one of them is enough
+	// to check that every move can be made.
+	let mut simulation = ship.clone();
+	let instructions = rest_of_input
+		.lines()
+		.enumerate()
+		.map(|(i, line)| {
+			let instruction: Instruction = parse_line(i, line)?;
+			simulation
+				.check(&instruction)
+				.map_err(|reason| ParseError::at_line(i, line, reason))?;
+			simulation.follow_9001(instruction);
+			Ok(instruction)
+		})
+		.collect::<Result<Vec<Instruction>, ParseError>>()
+		.map_err(|error| error.below(offset))?;
+	Ok(Procedure { ship, instructions })
+}
+
+fn rearrange_9000(procedure: &Procedure) -> String
+{
+	let mut ship = procedure.ship.clone();
+	for instruction in procedure.instructions.iter()
+	{
+		ship.follow_9000(*instruction);
+	}
+	ship.get_message()
+}
+
+fn rearrange_9001(procedure: &Procedure) -> String
+{
+	let mut ship = procedure.ship.clone();
+	for instruction in procedure.instructions.iter()
+	{
+		ship.follow_9001(*instruction);
+	}
+	ship.get_message()
+}
+
+const MAX_WIDTH: usize = 9;
+const MAX_HEIGHT: usize = 100;
+
+#[derive(Debug, Clone)]
+struct Ship
+{
+	crates: [[u8; MAX_HEIGHT]; MAX_WIDTH],
+	heights: [usize; MAX_WIDTH],
+	width: usize,
+}
+
+impl Ship
+{
+	fn new() -> Ship
+	{
+		Ship {
+			crates: [[b' '; MAX_HEIGHT]; MAX_WIDTH],
+			heights: [0; MAX_WIDTH],
+			width: 0,
+		}
+	}
+
+	fn fill(&mut self, input: &str) -> Result<(), ParseError>
+	{
+		let mut h = input.lines().count();
+		if h < 2
+		{
+			return Err(ParseError::at_end(input, "missing stack numbers"));
+		}
+		h -= 1;
+		let mut lines = input.lines();
+		let first_line = lines.next().unwrap_or_default();
+		if h >= MAX_HEIGHT
+		{
+			return Err(ParseError::at_line(0, first_line, "too many crates"));
+		}
+		if first_line.len() % 4 != 3 || first_line.len() > 4 * MAX_WIDTH
+		{
+			return Err(ParseError::at_line(0, first_line, "invalid width"));
+		}
+		if let Some((i, line)) = input
+			.lines()
+			.enumerate()
+			.find(|(_i, line)| line.len() != first_line.len())
+		{
+			return Err(ParseError::at_line(i, line, "uneven width"));
+		}
+		self.width = (first_line.len() + 1) / 4;
+		for i in 0..self.width
+		{
+			self.crates[i][h] = first_line.as_bytes()[4 * i + 1];
+		}
+		for line in lines.take(h)
+		{
+			h -= 1;
+			for i in 0..self.width
+			{
+				self.crates[i][h] = line.as_bytes()[4 * i + 1];
+			}
+		}
+		for i in 0..self.width
+		{
+			self.heights[i] =
+				self.crates[i].iter().position(|x| *x == b' ').unwrap();
+		}
+		Ok(())
+	}
+
+	fn check(&self, instruction: &Instruction) -> Result<(), &'static str>
+	{
+		let is_stack = |num: i32| num >= 1 && num as usize <= self.width;
+		if !is_stack(instruction.from_num) || !is_stack(instruction.to_num)
+		{
+			Err("no such stack")
+		}
+		else if instruction.from_num == instruction.to_num
+		{
+			Err("cannot move crates onto the same stack")
+		}
+		else if instruction.amount < 1
+		{
+			Err("must move at least one crate")
+		}
+		else if instruction.amount as usize
+			> self.heights[instruction.from_num as usize - 1]
+		{
+			Err("not enough crates on the stack")
+		}
+		else if instruction.amount as usize
+			+ self.heights[instruction.to_num as usize - 1]
+			> MAX_HEIGHT
+		{
+			Err("too many crates on the stack")
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+
+	fn follow_9000(&mut self, instruction: Instruction)
+	{
+		let Instruction {
+			from_num,
+			to_num,
+			amount,
+		} = instruction;
+		assert!(from_num >= 1 && from_num as usize <= self.width);
+		assert!(to_num >= 1 && to_num as usize <= self.width);
+		assert_ne!(from_num, to_num);
+		let from = from_num as usize - 1;
+		let to = to_num as usize - 1;
+		for _step in 0..amount
+		{
+			assert!(self.heights[from] > 0);
+			self.crates[to][self.heights[to]] =
+				self.crates[from][self.heights[from] - 1];
+			self.heights[to] += 1;
+			self.crates[from][self.heights[from] - 1] = b' ';
+			self.heights[from] -= 1;
+		}
+	}
+
+	fn follow_9001(&mut self, instruction: Instruction)
+	{
+		let Instruction {
+			from_num,
+			to_num,
+			amount,
+		} = instruction;
+		assert!(from_num >= 1 && from_num as usize <= self.width);
+		assert!(to_num >= 1 && to_num as usize <= self.width);
+		assert_ne!(from_num, to_num);
+		let from = from_num as usize - 1;
+		let to = to_num as usize - 1;
+		let n = amount as usize;
+		assert!(n >= 1);
+		let (left, right) = self.crates.split_at_mut(std::cmp::max(from, to));
+		let (from_stack, to_stack) = if from < to
+		{
+			(&mut left[from], &mut right[0])
+		}
+		else
+		{
+			(&mut right[0], &mut left[to])
+		};
+		let height_of_from = self.heights[from];
+		let height_of_to = self.heights[to];
+		assert!(height_of_from >= n);
+		let from_slice = &mut from_stack[(height_of_from - n)..height_of_from];
+		let to_slice = &mut to_stack[height_of_to..(height_of_to + n)];
+		from_slice.swap_with_slice(to_slice);
+		self.heights[from] -= n;
+		self.heights[to] += n;
+	}
+
+	fn get_message(&self) -> String
+	{
+		let top_crates: Vec<u8> = self.heights[0..self.width]
+			.iter()
+			.enumerate()
+			.filter(|(_i, h)| **h > 0)
+			.map(|(i, h)| self.crates[i][h - 1])
+			.collect();
+		String::from_utf8(top_crates).unwrap()
+	}
+}
+
+#[derive(
+	Debug, Clone, Copy, parse_display::Display, parse_display::FromStr,
+)]
+#[display("move {amount} from {from_num} to {to_num}")]
+struct Instruction
+{
+	from_num: i32,
+	to_num: i32,
+	amount: i32,
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+	const AS_IS: &str = include_str!("as_is.txt");
+	const ONCE: &str = include_str!("once.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(&one(PROVIDED), "CMZ");
+	}
+
+	#[test]
+	fn one_as_is()
+	{
+		assert_eq!(&one(AS_IS), "NDP");
+	}
+
+	#[test]
+	fn one_once()
+	{
+		assert_eq!(&one(ONCE), "DCP");
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(&two(PROVIDED), "MCD");
+	}
+
+	#[test]
+	fn two_as_is()
+	{
+		assert_eq!(&two(AS_IS), "NDP");
+	}
+
+	#[test]
+	fn two_once()
+	{
+		assert_eq!(&two(ONCE), "DCP");
+	}
+
+	#[test]
+	fn parse_no_such_stack()
+	{
+		let input =
+			PROVIDED.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+		let error =
+			ParseError::new(9, 1, "move 1 from 1 to 4", "no such stack");
+		assert_eq!(parse(&input).err(), Some(error));
+	}
+
+	#[test]
+	fn parse_not_enough_crates()
+	{
+		let input =
+			PROVIDED.replace("move 1 from 2 to 1", "move 9 from 2 to 1");
+		let error = ParseError::new(
+			6,
+			1,
+			"move 9 from 2 to 1",
+			"not enough crates on the stack",
+		);
+		assert_eq!(parse(&input).err(), Some(error));
+		let input =
+			PROVIDED.replace("move 1 from 2 to 1", "move 0 from 2 to 1");
+		let error = ParseError::new(
+			6,
+			1,
+			"move 0 from 2 to 1",
+			"must move at least one crate",
+		);
+		assert_eq!(parse(&input).err(), Some(error));
+	}
+}
@@ -0,0 +1,110 @@
+/**/
+
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> usize
+{
+	find_consecutive_unique(&parse(input).unwrap(), 4).unwrap()
+}
+
+pub fn two(input: &str) -> usize
+{
+	find_consecutive_unique(&parse(input).unwrap(), 14).unwrap()
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<u8>;
+
+	fn parse(&self, input: &str) -> Result<Vec<u8>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, signal: &Vec<u8>) -> Option<Answer>
+	{
+		Some(find_consecutive_unique(signal, 4)?.into())
+	}
+
+	fn two(&self, signal: &Vec<u8>) -> Option<Answer>
+	{
+		Some(find_consecutive_unique(signal, 14)?.into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<u8>, ParseError>
+{
+	let line = input.trim_end();
+	match line.bytes().position(|x| !x.is_ascii_lowercase())
+	{
+		Some(j) => Err(ParseError::at_byte(0, j, line)),
+		None => Ok(line.as_bytes().to_vec()),
+	}
+}
+
+// The signal can be too short, or repeat itself too much, to have a marker.
+fn find_consecutive_unique(signal: &[u8], num: usize) -> Option<usize>
+{
+	let (i, _window) = signal
+		.windows(num)
+		.enumerate()
+		.find(|(_i, window)| all_unique(window))?;
+	Some(num + i)
+}
+
+fn all_unique(code: &[u8]) -> bool
+{
+	let mut bitmask = 0u32;
+	for letter in code
+	{
+		let offset = letter - b'a';
+		let bit = 1 << offset;
+		if bitmask & bit != 0
+		{
+			return false;
+		}
+		bitmask |= bit;
+	}
+	true
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
+		assert_eq!(one("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
+		assert_eq!(one("nppdvjthqldpwncqszvftbrmjlhg"), 6);
+		assert_eq!(one("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
+		assert_eq!(one("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
+		assert_eq!(two("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
+		assert_eq!(two("nppdvjthqldpwncqszvftbrmjlhg"), 23);
+		assert_eq!(two("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+		assert_eq!(two("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
+	}
+
+	#[test]
+	fn without_marker()
+	{
+		for input in ["", "x", "\n\n\n", "abababababab"]
+		{
+			let signal = parse(input).unwrap();
+			assert_eq!(find_consecutive_unique(&signal, 4), None);
+			assert_eq!(find_consecutive_unique(&signal, 14), None);
+		}
+	}
+}
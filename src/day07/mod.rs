@@ -0,0 +1,246 @@
+/**/
+
+use crate::parse::parse_line;
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> usize
+{
+	sum_of_small_directories(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> usize
+{
+	size_of_directory_to_delete(&parse(input).unwrap()).unwrap()
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Filesystem;
+
+	fn parse(&self, input: &str) -> Result<Filesystem, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, filesystem: &Filesystem) -> Option<Answer>
+	{
+		Some(sum_of_small_directories(filesystem).into())
+	}
+
+	fn two(&self, filesystem: &Filesystem) -> Option<Answer>
+	{
+		Some(size_of_directory_to_delete(filesystem)?.into())
+	}
+}
+
+fn parse(input: &str) -> Result<Filesystem, ParseError>
+{
+	let mut discovery = Discovery::new();
+	for (i, line) in input.lines().enumerate()
+	{
+		discovery
+			.discover(parse_line(i, line)?)
+			.map_err(|reason| ParseError::at_line(i, line, reason))?;
+	}
+	Ok(discovery.filesystem)
+}
+
+fn sum_of_small_directories(filesystem: &Filesystem) -> usize
+{
+	filesystem
+		.directory_sizes
+		.values()
+		.filter(|size| **size <= 100000)
+		.sum()
+}
+
+// There is nothing to delete if the files do not fit on the disk, or if
+// there is already enough free space.
+fn size_of_directory_to_delete(filesystem: &Filesystem) -> Option<usize>
+{
+	let current_free_space =
+		TOTAL_DISK_SPACE.checked_sub(filesystem.total_size())?;
+	let extra_size_needed = NEEDED_FREE_DISK_SPACE
+		.checked_sub(current_free_space)
+		.filter(|size| *size > 0)?;
+	filesystem
+		.directory_sizes
+		.values()
+		.copied()
+		.filter(|size| *size >= extra_size_needed)
+		.min()
+}
+
+const TOTAL_DISK_SPACE: usize = 70000000;
+const NEEDED_FREE_DISK_SPACE: usize = 30000000;
+
+#[derive(Debug)]
+pub struct Filesystem
+{
+	directory_sizes: std::collections::HashMap<std::path::PathBuf, usize>,
+}
+
+impl Filesystem
+{
+	fn new() -> Filesystem
+	{
+		let mut filesystem = Filesystem {
+			directory_sizes: std::collections::HashMap::new(),
+		};
+		filesystem.create_directory("/".parse().unwrap());
+		filesystem
+	}
+
+	fn create_directory(&mut self, dir_path: std::path::PathBuf)
+	{
+		self.directory_sizes.insert(dir_path, 0);
+	}
+
+	fn add_to_directory(&mut self, dir_path: &std::path::Path, size: usize)
+	{
+		for path in dir_path.ancestors()
+		{
+			let dsize = self.directory_sizes.get_mut(path).unwrap();
+			*dsize += size;
+		}
+	}
+
+	fn has_directory(&self, dir_path: &std::path::Path) -> bool
+	{
+		self.directory_sizes.contains_key(dir_path)
+	}
+
+	fn total_size(&self) -> usize
+	{
+		let root: std::path::PathBuf = "/".parse().unwrap();
+		self.directory_sizes.get(&root).unwrap().to_owned()
+	}
+}
+
+#[derive(Debug)]
+struct Discovery
+{
+	filesystem: Filesystem,
+	current_path: std::path::PathBuf,
+}
+
+impl Discovery
+{
+	fn new() -> Self
+	{
+		Self {
+			filesystem: Filesystem::new(),
+			current_path: std::path::PathBuf::new(),
+		}
+	}
+
+	// The terminal output only goes into directories that it has listed.
+	fn discover(&mut self, line: Line) -> Result<(), &'static str>
+	{
+		match line
+		{
+			Line::CdRoot =>
+			{
+				self.current_path.push("/");
+			}
+			Line::CdUp =>
+			{
+				self.current_path.pop();
+			}
+			Line::CdDown { name } =>
+			{
+				self.current_path.push(name);
+				if !self.filesystem.has_directory(&self.current_path)
+				{
+					return Err("no such directory");
+				}
+			}
+			Line::Ls => (),
+			Line::Dir { name } =>
+			{
+				let mut dir_path = self.current_path.clone();
+				dir_path.push(name);
+				self.filesystem.create_directory(dir_path);
+			}
+			Line::File { name: _, size } =>
+			{
+				if !self.filesystem.has_directory(&self.current_path)
+				{
+					return Err("not in a directory");
+				}
+				self.filesystem.add_to_directory(&self.current_path, size);
+			}
+		}
+		Ok(())
+	}
+}
+
+#[derive(Debug, parse_display::Display, parse_display::FromStr)]
+enum Line
+{
+	#[display("$ cd /")]
+	CdRoot,
+	#[display("$ cd ..")]
+	CdUp,
+	#[display("$ cd {name}")]
+	CdDown
+	{
+		name: String
+	},
+	#[display("$ ls")]
+	Ls,
+	#[display("dir {name}")]
+	Dir
+	{
+		name: String
+	},
+	#[display("{size} {name}")]
+	File
+	{
+		name: String, size: usize
+	},
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 95437);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 24933642);
+	}
+
+	#[test]
+	fn parse_unknown_directory()
+	{
+		let input = PROVIDED.replace("dir d\n", "");
+		let error = ParseError::new(17, 1, "$ cd d", "no such directory");
+		assert_eq!(parse(&input).err(), Some(error));
+	}
+
+	#[test]
+	fn two_enough_free_space()
+	{
+		let input = PROVIDED.replace("14848514 b.txt", "14 b.txt");
+		let filesystem = parse(&input).unwrap();
+		assert_eq!(sum_of_small_directories(&filesystem), 95437);
+		assert_eq!(size_of_directory_to_delete(&filesystem), None);
+		let input = PROVIDED.replace("14848514 b.txt", "74848514 b.txt");
+		let filesystem = parse(&input).unwrap();
+		assert_eq!(size_of_directory_to_delete(&filesystem), None);
+	}
+}
@@ -0,0 +1,154 @@
+/**/
+
+use crate::grid::Grid;
+use crate::grid::Position;
+use crate::grid::ORTHOGONAL;
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> usize
+{
+	count_visible_trees(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> usize
+{
+	highest_scenic_score(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Forest;
+
+	fn parse(&self, input: &str) -> Result<Forest, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, forest: &Forest) -> Option<Answer>
+	{
+		Some(count_visible_trees(forest).into())
+	}
+
+	fn two(&self, forest: &Forest) -> Option<Answer>
+	{
+		Some(highest_scenic_score(forest).into())
+	}
+}
+
+// The heights of the trees as ASCII digits.
+pub type Forest = Grid<u8>;
+
+fn parse(input: &str) -> Result<Forest, ParseError>
+{
+	Grid::parse_with(input, |x| Some(x).filter(u8::is_ascii_digit))
+}
+
+fn count_visible_trees(forest: &Forest) -> usize
+{
+	let width = forest.width();
+	let height = forest.height();
+	let mut visible = Grid::new(width, height, false);
+	let mut view = vec![0u8; width];
+	for (row, line) in forest.rows().enumerate()
+	{
+		let mut max = 0u8;
+		for (col, x) in line.iter().enumerate()
+		{
+			let h = *x;
+			if h > max
+			{
+				visible[(row, col)] = true;
+				max = h;
+			}
+			if h > view[col]
+			{
+				visible[(row, col)] = true;
+				view[col] = h;
+			}
+		}
+	}
+	view.fill(0u8);
+	for (rev_row, line) in forest.rows().rev().enumerate()
+	{
+		let row = height - 1 - rev_row;
+		let mut max = 0u8;
+		for (rev_col, x) in line.iter().rev().enumerate()
+		{
+			let col = width - 1 - rev_col;
+			let h = *x;
+			if h > max
+			{
+				visible[(row, col)] = true;
+				max = h;
+			}
+			if h > view[col]
+			{
+				visible[(row, col)] = true;
+				view[col] = h;
+			}
+		}
+	}
+	visible.iter().filter(|x| **x).count()
+}
+
+fn highest_scenic_score(forest: &Forest) -> usize
+{
+	let mut max = 0;
+	for (row, col) in forest.positions()
+	{
+		let x = forest[(row, col)];
+		let score = ORTHOGONAL
+			.iter()
+			.map(|&offset| viewing_distance(forest, (row, col), offset, x))
+			.product();
+		if score > max
+		{
+			max = score;
+		}
+	}
+	max
+}
+
+fn viewing_distance(
+	forest: &Forest,
+	mut position: Position,
+	offset: (isize, isize),
+	x: u8,
+) -> usize
+{
+	let mut distance = 0;
+	while let Some(next) = forest.offset(position, offset)
+	{
+		distance += 1;
+		if forest[next] >= x
+		{
+			break;
+		}
+		position = next;
+	}
+	distance
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 21);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 8);
+	}
+}
@@ -0,0 +1,191 @@
+/**/
+
+use crate::generate::Rng;
+use crate::parse::parse_lines;
+use crate::vector::Direction;
+use crate::vector::Vec2;
+use crate::Answer;
+use crate::ParseError;
+
+use std::collections::HashSet;
+
+pub fn one(input: &str) -> usize
+{
+	count_positions_visited_by_tail::<2>(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> usize
+{
+	count_positions_visited_by_tail::<10>(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Instruction>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Instruction>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, instructions: &Vec<Instruction>) -> Option<Answer>
+	{
+		Some(count_positions_visited_by_tail::<2>(instructions).into())
+	}
+
+	fn two(&self, instructions: &Vec<Instruction>) -> Option<Answer>
+	{
+		Some(count_positions_visited_by_tail::<10>(instructions).into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError>
+{
+	parse_lines(input)
+}
+
+pub fn generate(rng: &mut Rng, num_moves: usize) -> String
+{
+	(0..num_moves)
+		.map(|_| {
+			let instruction = Instruction {
+				direction: *rng.pick(&Direction::ALL),
+				amount: rng.range(1..=19) as usize,
+			};
+			format!("{}\n", instruction)
+		})
+		.collect()
+}
+
+fn count_positions_visited_by_tail<const N: usize>(
+	instructions: &[Instruction],
+) -> usize
+{
+	let mut simulation: Simulation<N> = Simulation::new();
+	let area = area_covered_by_head(instructions);
+	for instruction in instructions
+	{
+		simulation.follow(instruction, area);
+	}
+	simulation.positions_visited_by_tail.len()
+}
+
+#[derive(Debug, parse_display::Display, parse_display::FromStr)]
+#[display("{direction} {amount}")]
+pub struct Instruction
+{
+	direction: Direction,
+	amount: usize,
+}
+
+struct Simulation<const N: usize>
+{
+	knots: [Vec2; N],
+	positions_visited_by_tail: HashSet<Vec2>,
+}
+
+impl<const N: usize> Simulation<N>
+{
+	fn new() -> Simulation<N>
+	{
+		Simulation {
+			knots: [Vec2::ZERO; N],
+			positions_visited_by_tail: HashSet::from([Vec2::ZERO]),
+		}
+	}
+
+	fn follow(&mut self, instruction: &Instruction, area: (Vec2, Vec2))
+	{
+		for _t in 0..instruction.amount
+		{
+			self.knots[0] += instruction.direction.offset();
+			for i in 1..N
+			{
+				// A knot that is no longer touching the one in front of it
+				// moves one step towards it, diagonally if need be.
+				let pull = self.knots[i - 1] - self.knots[i];
+				if pull.chebyshev_distance(Vec2::ZERO) > 1
+				{
+					self.knots[i] += pull.signum();
+				}
+			}
+			self.positions_visited_by_tail.insert(self.knots[N - 1]);
+			crate::frames::record(|| self.render(area));
+		}
+	}
+
+	// Like the pictures in the puzzle, with the head as H, the other knots
+	// numbered (or T if there is only one) and where the tail has been as #.
+	fn render(&self, (min, max): (Vec2, Vec2)) -> String
+	{
+		let mut text = String::new();
+		for y in min.y..=max.y
+		{
+			for x in min.x..=max.x
+			{
+				let pos = Vec2::new(x, y);
+				let knot = self.knots.iter().position(|&knot| knot == pos);
+				let symbol = match knot
+				{
+					Some(0) => 'H',
+					Some(_) if N == 2 => 'T',
+					Some(i) => char::from(b'0' + i as u8),
+					None if pos == Vec2::ZERO => 's',
+					None if self.positions_visited_by_tail.contains(&pos) =>
+					{
+						'#'
+					}
+					None => '.',
+				};
+				text.push(symbol);
+			}
+			text.push('\n');
+		}
+		text
+	}
+}
+
+// The knots never leave the rectangle that the head moves around in.
+fn area_covered_by_head(instructions: &[Instruction]) -> (Vec2, Vec2)
+{
+	let mut head = Vec2::ZERO;
+	let mut min = head;
+	let mut max = head;
+	for instruction in instructions
+	{
+		head += instruction.direction.offset() * instruction.amount as i32;
+		min = Vec2::new(min.x.min(head.x), min.y.min(head.y));
+		max = Vec2::new(max.x.max(head.x), max.y.max(head.y));
+	}
+	(min, max)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+	const LARGER: &str = include_str!("larger.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 13);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 1);
+	}
+
+	#[test]
+	fn two_larger()
+	{
+		assert_eq!(two(LARGER), 36);
+	}
+}
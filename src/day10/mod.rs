@@ -1,20 +1,54 @@
 /**/
 
-const INPUT: &str = include_str!("input.txt");
+use crate::grid::Grid;
+use crate::parse::parse_lines;
+use crate::Answer;
+use crate::ParseError;
 
-pub fn main()
+pub fn one(input: &str) -> i32
 {
-	println!("Part One: {}", one(INPUT));
-	println!("Part Two: \n{}", two(INPUT));
+	sum_of_signal_strengths(&parse(input).unwrap())
 }
 
-fn one(input: &str) -> i32
+pub fn two(input: &str) -> String
+{
+	render_screen(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Instruction>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Instruction>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, instructions: &Vec<Instruction>) -> Option<Answer>
+	{
+		Some(sum_of_signal_strengths(instructions).into())
+	}
+
+	fn two(&self, instructions: &Vec<Instruction>) -> Option<Answer>
+	{
+		Some(Answer::from_art(render_screen(instructions), '#'))
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError>
+{
+	parse_lines(input)
+}
+
+fn sum_of_signal_strengths(instructions: &[Instruction]) -> i32
 {
 	let mut total_signal_strength = 0;
 	let mut register = 1;
 	let mut cycle = 1;
 	let mut next_important_cycle = 20;
-	for instruction in input.lines().map(|line| line.parse().unwrap())
+	for &instruction in instructions
 	{
 		let (new_register, new_cycle) = match instruction
 		{
@@ -34,18 +68,13 @@ fn one(input: &str) -> i32
 
 const SCREEN_HEIGHT: usize = 6;
 const SCREEN_WIDTH: usize = 40;
-const SCREEN_STRIDE: usize = SCREEN_WIDTH + 1;
 
-fn two(input: &str) -> String
+fn render_screen(instructions: &[Instruction]) -> String
 {
-	let mut screen = [b'.'; SCREEN_HEIGHT * SCREEN_STRIDE];
-	for r in 0..SCREEN_HEIGHT
-	{
-		screen[r * SCREEN_STRIDE + SCREEN_WIDTH] = b'\n';
-	}
+	let mut screen = Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, b'.');
 	let mut register = 1;
 	let mut cycle = 1;
-	for instruction in input.lines().map(|line| line.parse().unwrap())
+	for &instruction in instructions
 	{
 		let (new_register, new_cycle) = match instruction
 		{
@@ -59,19 +88,22 @@ fn two(input: &str) -> String
 			let draw_col = c as i32;
 			if draw_col >= register - 1 && draw_col <= register + 1
 			{
-				screen[r * SCREEN_STRIDE + c] = b'#';
+				if let Some(pixel) = screen.get_mut((r, c))
+				{
+					*pixel = b'#';
+				}
 			}
 		}
 		register = new_register;
 		cycle = new_cycle;
 	}
-	std::str::from_utf8(&screen).unwrap().to_string()
+	screen.to_string()
 }
 
 #[derive(
 	Debug, Clone, Copy, parse_display::Display, parse_display::FromStr,
 )]
-enum Instruction
+pub enum Instruction
 {
 	#[display("noop")]
 	Noop,
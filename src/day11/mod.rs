@@ -1,16 +1,43 @@
 /**/
 
-const INPUT: &str = include_str!("input.txt");
+use crate::Answer;
+use crate::ParseError;
 
-pub fn main()
+pub fn one(input: &str) -> usize
 {
-	println!("Part One: {}", one(INPUT));
-	println!("Part Two: {}", two(INPUT));
+	play_manageable_game(&input.parse().unwrap())
 }
 
-fn one(input: &str) -> usize
+pub fn two(input: &str) -> usize
 {
-	let mut game: KeepAway = input.parse().unwrap();
+	play_worrying_game(&input.parse().unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = KeepAway;
+
+	fn parse(&self, input: &str) -> Result<KeepAway, ParseError>
+	{
+		input.parse()
+	}
+
+	fn one(&self, game: &KeepAway) -> Option<Answer>
+	{
+		Some(play_manageable_game(game).into())
+	}
+
+	fn two(&self, game: &KeepAway) -> Option<Answer>
+	{
+		Some(play_worrying_game(game).into())
+	}
+}
+
+fn play_manageable_game(game: &KeepAway) -> usize
+{
+	let mut game = game.clone();
 	game.is_manageable = true;
 	for _i in 0..20
 	{
@@ -19,9 +46,9 @@ fn one(input: &str) -> usize
 	game.level_of_monkey_business()
 }
 
-fn two(input: &str) -> usize
+fn play_worrying_game(game: &KeepAway) -> usize
 {
-	let mut game: KeepAway = input.parse().unwrap();
+	let mut game = game.clone();
 	for _i in 0..10000
 	{
 		game.play_round();
@@ -29,8 +56,8 @@ fn two(input: &str) -> usize
 	game.level_of_monkey_business()
 }
 
-#[derive(Debug)]
-struct KeepAway
+#[derive(Debug, Clone)]
+pub struct KeepAway
 {
 	monkeys: Vec<Monkey>,
 	is_manageable: bool,
@@ -43,36 +70,62 @@ const MONKEY_REGEX: &str = "Monkey [0-9]+:
 
 impl std::str::FromStr for KeepAway
 {
-	type Err = std::io::Error;
+	type Err = ParseError;
 
 	fn from_str(input: &str) -> Result<Self, Self::Err>
 	{
 		let monkey_regex = regex::Regex::new(MONKEY_REGEX).unwrap();
 		let items_regex = regex::Regex::new("[0-9]+").unwrap();
-		let monkeys: Vec<Monkey> = input
-			.split("\n\n")
-			.map(|segment| {
-				let captures = monkey_regex.captures(segment).unwrap();
-				let items_str = captures.name("items").unwrap();
-				let items: Vec<i64> = items_regex
-					.captures_iter(items_str.as_str())
-					.map(|x| x.get(0).unwrap().as_str().parse().unwrap())
-					.collect();
-				let spec = captures.name("spec").unwrap();
-				let spec: Specification = spec.as_str().parse().unwrap();
-				Monkey {
-					items,
-					spec,
-					number_of_inspections: 0,
-				}
-			})
-			.collect();
+		let mut monkeys: Vec<Monkey> = Vec::new();
+		let mut offsets: Vec<usize> = Vec::new();
+		let mut offset = 0;
+		for segment in input.split("\n\n")
+		{
+			let line_at = |i: usize| segment.lines().nth(i).unwrap_or_default();
+			let captures = monkey_regex.captures(segment).ok_or_else(|| {
+				ParseError::at_line(offset, line_at(0), "invalid Monkey")
+			})?;
+			let items_str = captures.name("items").unwrap();
+			let items: Vec<i64> = items_regex
+				.captures_iter(items_str.as_str())
+				.map(|x| x.get(0).unwrap().as_str().parse())
+				.collect::<Result<Vec<i64>, _>>()
+				.map_err(|_| {
+					ParseError::at_line(offset + 1, line_at(1), "invalid item")
+				})?;
+			let spec = captures.name("spec").unwrap();
+			let spec: Specification = spec.as_str().parse().map_err(|_| {
+				ParseError::at_line(
+					offset + 2,
+					line_at(2),
+					"invalid Specification",
+				)
+			})?;
+			monkeys.push(Monkey {
+				items,
+				spec,
+				number_of_inspections: 0,
+			});
+			offsets.push(offset);
+			offset += segment.lines().count() + 1;
+		}
 		// Do not allow monkeys to throw to themselves to avoid infinite loops.
 		let mut base = 1;
 		for (i, monkey) in monkeys.iter().enumerate()
 		{
-			assert_ne!(monkey.spec.true_friend, i);
-			assert_ne!(monkey.spec.false_friend, i);
+			let spec = &monkey.spec;
+			for (friend, j) in [(spec.true_friend, 4), (spec.false_friend, 5)]
+			{
+				if friend == i || friend >= monkeys.len()
+				{
+					let line = input.lines().nth(offsets[i] + j);
+					return Err(ParseError::at_line(
+						offsets[i] + j,
+						line.unwrap_or_default(),
+						"invalid friend",
+					));
+				}
+			}
 			base *= monkey.spec.test_divisor;
 		}
 		Ok(Self {
@@ -138,7 +191,7 @@ impl KeepAway
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Monkey
 {
 	items: Vec<i64>,
@@ -147,7 +200,7 @@ struct Monkey
 	number_of_inspections: usize,
 }
 
-#[derive(Debug, parse_display::Display, parse_display::FromStr)]
+#[derive(Debug, Clone, parse_display::Display, parse_display::FromStr)]
 #[display(
 	"  Operation: new = {operation}
   Test: divisible by {test_divisor}
@@ -162,7 +215,7 @@ struct Specification
 	false_friend: usize,
 }
 
-#[derive(Debug, parse_display::Display, parse_display::FromStr)]
+#[derive(Debug, Clone, parse_display::Display, parse_display::FromStr)]
 #[display("{left} {op} {right}")]
 struct Operation
 {
@@ -193,7 +246,7 @@ impl Operation
 	}
 }
 
-#[derive(Debug, parse_display::Display, parse_display::FromStr)]
+#[derive(Debug, Clone, parse_display::Display, parse_display::FromStr)]
 enum Operand
 {
 	#[display("old")]
@@ -202,7 +255,7 @@ enum Operand
 	Value(i64),
 }
 
-#[derive(Debug, parse_display::Display, parse_display::FromStr)]
+#[derive(Debug, Clone, parse_display::Display, parse_display::FromStr)]
 enum Operator
 {
 	#[display("+")]
@@ -0,0 +1,153 @@
+/**/
+
+use crate::grid::Grid;
+use crate::grid::Position;
+use crate::search;
+use crate::search::Search;
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> usize
+{
+	solve(&parse(input).unwrap(), false).unwrap()
+}
+
+pub fn two(input: &str) -> usize
+{
+	solve(&parse(input).unwrap(), true).unwrap()
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Heightmap;
+
+	fn parse(&self, input: &str) -> Result<Heightmap, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, heightmap: &Heightmap) -> Option<Answer>
+	{
+		Some(solve(heightmap, false)?.into())
+	}
+
+	fn two(&self, heightmap: &Heightmap) -> Option<Answer>
+	{
+		Some(solve(heightmap, true)?.into())
+	}
+}
+
+// The raw input, one row per line.
+pub type Heightmap = Grid<u8>;
+
+fn parse(input: &str) -> Result<Heightmap, ParseError>
+{
+	let mut num_starts = 0;
+	let mut num_ends = 0;
+	let heightmap = Grid::parse_with(input, |x| match x
+	{
+		b'a'..=b'z' => Some(x),
+		b'S' if num_starts == 0 =>
+		{
+			num_starts += 1;
+			Some(x)
+		}
+		b'E' if num_ends == 0 =>
+		{
+			num_ends += 1;
+			Some(x)
+		}
+		_ => None,
+	})?;
+	if num_starts == 0 || num_ends == 0
+	{
+		return Err(ParseError::at_end(input, "missing S or E"));
+	}
+	Ok(heightmap)
+}
+
+// The hill can be too steep to climb, and then there is no path.
+fn solve(heightmap: &Heightmap, is_part_two: bool) -> Option<usize>
+{
+	// Search backwards from the end, because in part two any 'a' will do.
+	let climb = Climb {
+		heightmap,
+		is_part_two,
+	};
+	let end = heightmap.position(|x| *x == b'E').unwrap();
+	let path = search::bfs(&climb, end)?;
+	Some(path.cost as usize)
+}
+
+struct Climb<'a>
+{
+	heightmap: &'a Heightmap,
+	is_part_two: bool,
+}
+
+impl Search for Climb<'_>
+{
+	type State = Position;
+
+	fn is_goal(&self, &position: &Position) -> bool
+	{
+		match self.heightmap[position]
+		{
+			b'S' => true,
+			b'a' => self.is_part_two,
+			_ => false,
+		}
+	}
+
+	// Going backwards, we can descend at most one but climb any distance.
+	fn successors(&self, &from: &Position, next: &mut Vec<Position>)
+	{
+		let current_height = elevation(self.heightmap[from]);
+		let reachable = |&to: &Position| {
+			elevation(self.heightmap[to]) + 1 >= current_height
+		};
+		next.extend(self.heightmap.neighbours4(from).filter(reachable));
+	}
+}
+
+fn elevation(x: u8) -> u8
+{
+	match x
+	{
+		b'S' => b'a',
+		b'E' => b'z',
+		h => h,
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 31);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 29);
+	}
+
+	#[test]
+	fn too_steep()
+	{
+		let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\n";
+		let heightmap = parse(input).unwrap();
+		assert_eq!(solve(&heightmap, false), None);
+		assert_eq!(solve(&heightmap, true), None);
+	}
+}
@@ -0,0 +1,623 @@
+/**/
+
+use crate::generate::Rng;
+use crate::Answer;
+use crate::ParseError;
+
+use std::cmp::Ordering;
+
+pub fn one(input: &str) -> usize
+{
+	sum_of_ordered_pair_indices(&parse(input).unwrap()).unwrap()
+}
+
+pub fn two(input: &str) -> usize
+{
+	decoder_key(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Vec<Token>>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Vec<Token>>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, packets: &Vec<Vec<Token>>) -> Option<Answer>
+	{
+		Some(sum_of_ordered_pair_indices(packets)?.into())
+	}
+
+	fn two(&self, packets: &Vec<Vec<Token>>) -> Option<Answer>
+	{
+		Some(decoder_key(packets).into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<Token>>, ParseError>
+{
+	let mut packets: Vec<Vec<Token>> = Vec::new();
+	for (i, line) in input.lines().enumerate()
+	{
+		if i % 3 == 2
+		{
+			if !line.is_empty()
+			{
+				return Err(ParseError::at_line(
+					i,
+					line,
+					"expected blank line",
+				));
+			}
+		}
+		else
+		{
+			packets.push(parse_packet(i, line)?);
+		}
+	}
+	if !packets.len().is_multiple_of(2)
+	{
+		return Err(ParseError::at_end(input, "missing second packet"));
+	}
+	Ok(packets)
+}
+
+pub fn generate(rng: &mut Rng, num_pairs: usize) -> String
+{
+	let pairs: Vec<String> = (0..num_pairs)
+		.map(|_| {
+			let left = random_packet(rng, 0);
+			// The packets in a pair are never equal.
+			let right = loop
+			{
+				let right = random_packet(rng, 0);
+				let tokens = |packet| parse_packet(0, packet).unwrap();
+				if compare(&tokens(&left), &tokens(&right)) != Ordering::Equal
+				{
+					break right;
+				}
+			};
+			format!("{}\n{}\n", left, right)
+		})
+		.collect();
+	pairs.join("\n")
+}
+
+fn random_packet(rng: &mut Rng, depth: usize) -> String
+{
+	let items: Vec<String> = (0..rng.below(6))
+		.map(|_| {
+			if depth < 4 && rng.chance(0.3)
+			{
+				random_packet(rng, depth + 1)
+			}
+			else
+			{
+				rng.range(0..=10).to_string()
+			}
+		})
+		.collect();
+	format!("[{}]", items.join(","))
+}
+
+fn sum_of_ordered_pair_indices(packets: &[Vec<Token>]) -> Option<usize>
+{
+	let mut sum = 0;
+	for (i, pair) in packets.chunks(2).enumerate()
+	{
+		if are_in_right_order(&pair[0], &pair[1])?
+		{
+			sum += 1 + i;
+		}
+	}
+	Some(sum)
+}
+
+fn decoder_key(packets: &[Vec<Token>]) -> usize
+{
+	// We can cheat a litte: we only need to know the indices of the dividers,
+	// so we can instead just calculate the number of lines that are earlier.
+	let mut num_less_than_2 = 0;
+	let mut num_between = 0;
+	for packet in packets
+	{
+		match compare_to_range(packet, 2, 6)
+		{
+			Ordering::Less => num_less_than_2 += 1,
+			Ordering::Equal => num_between += 1,
+			Ordering::Greater => (),
+		}
+	}
+	let offset_of_2 = 1 + num_less_than_2;
+	let offset_of_6 = offset_of_2 + num_between + 1;
+	offset_of_2 * offset_of_6
+}
+
+// Either packet in a pair has to come first.
+fn are_in_right_order(left: &[Token], right: &[Token]) -> Option<bool>
+{
+	match compare(left, right)
+	{
+		Ordering::Less => Some(true),
+		Ordering::Greater => Some(false),
+		Ordering::Equal => None,
+	}
+}
+
+fn compare_to_range(packet: &[Token], lower: i32, upper: i32) -> Ordering
+{
+	match compare_1(lower, &mut packet.iter().copied().skip(1)).reverse()
+	{
+		Ordering::Less => Ordering::Less,
+		Ordering::Greater =>
+		{
+			match compare_1(upper, &mut packet.iter().copied().skip(1))
+				.reverse()
+			{
+				Ordering::Less => Ordering::Equal,
+				Ordering::Greater => Ordering::Greater,
+				Ordering::Equal => Ordering::Equal,
+			}
+		}
+		Ordering::Equal => Ordering::Equal,
+	}
+}
+
+fn compare(left: &[Token], right: &[Token]) -> Ordering
+{
+	let mut left = left.iter().copied();
+	let mut right = right.iter().copied();
+	// Both run out at the same time only if the packets are equal.
+	while let (Some(a), Some(b)) = (left.next(), right.next())
+	{
+		let order = match (a, b)
+		{
+			(Token::Num(a), Token::Num(b)) => a.cmp(&b),
+			(Token::Num(a), Token::Open) => compare_1(a, &mut right),
+			(Token::Open, Token::Num(b)) => compare_1(b, &mut left).reverse(),
+			(Token::Open, Token::Open) => Ordering::Equal,
+			(Token::Close, Token::Close) => Ordering::Equal,
+			(Token::Close, _) => Ordering::Less,
+			(_, Token::Close) => Ordering::Greater,
+		};
+		match order
+		{
+			Ordering::Equal => (),
+			Ordering::Less => return Ordering::Less,
+			Ordering::Greater => return Ordering::Greater,
+		}
+	}
+	Ordering::Equal
+}
+
+fn compare_1(a: i32, right: &mut impl Iterator<Item = Token>) -> Ordering
+{
+	let mut inner_depth = 1;
+	while inner_depth > 0
+	{
+		match right.next().unwrap()
+		{
+			Token::Open => inner_depth += 1,
+			Token::Close => return Ordering::Greater,
+			Token::Num(b) if a < b => return Ordering::Less,
+			Token::Num(b) if a > b => return Ordering::Greater,
+			Token::Num(_) => break,
+		}
+	}
+	// We have found a == b within some number of nested brackets.
+	// We need to return to the surface, unless one of the inner
+	// lists contains a second item, in which case a is first.
+	while inner_depth > 0
+	{
+		match right.next().unwrap()
+		{
+			Token::Close => inner_depth -= 1,
+			Token::Open | Token::Num(_) => return Ordering::Less,
+		}
+	}
+	Ordering::Equal
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token
+{
+	Open,
+	Close,
+	Num(i32),
+}
+
+fn parse_packet(i: usize, line: &str) -> Result<Vec<Token>, ParseError>
+{
+	if !line.starts_with('[') || !line.ends_with(']')
+	{
+		return Err(ParseError::at_line(i, line, "invalid packet"));
+	}
+	// The only multi-byte token is Num, so we can parse with a running value.
+	// The last byte (']') is discarded, so we append it at the end.
+	let mut state = None;
+	let mut depth = 0;
+	let mut tokens = Vec::new();
+	for (j, window) in line.as_bytes().windows(2).enumerate()
+	{
+		let token =
+			scan_token(&mut state, window[0], window[1]).map_err(|reason| {
+				match reason
+				{
+					Some(reason) => ParseError::new(i + 1, j + 1, line, reason),
+					None => ParseError::at_byte(i, j, line),
+				}
+			})?;
+		match token
+		{
+			Some(Token::Open) => depth += 1,
+			Some(Token::Close) => depth -= 1,
+			_ => (),
+		}
+		if depth <= 0
+		{
+			return Err(ParseError::at_byte(i, j, line));
+		}
+		tokens.extend(token);
+	}
+	if depth != 1
+	{
+		return Err(ParseError::at_byte(i, line.len(), line));
+	}
+	tokens.push(Token::Close);
+	Ok(tokens)
+}
+
+// Fails with a reason, unless the symbol is simply unexpected.
+fn scan_token(
+	accumulator: &mut Option<i32>,
+	current_symbol: u8,
+	next_symbol: u8,
+) -> Result<Option<Token>, Option<&'static str>>
+{
+	let acc = accumulator.take();
+	match current_symbol
+	{
+		b'[' => Ok(Some(Token::Open)),
+		b']' => Ok(Some(Token::Close)),
+		b',' => Ok(None),
+		b' ' => Ok(None),
+		x if x.is_ascii_digit() =>
+		{
+			let digit_value: i32 = (x - b'0').into();
+			let value = acc
+				.unwrap_or(0)
+				.checked_mul(10)
+				.and_then(|value| value.checked_add(digit_value))
+				.ok_or(Some("number too large"))?;
+			if next_symbol.is_ascii_digit()
+			{
+				*accumulator = Some(value);
+				Ok(None)
+			}
+			else
+			{
+				Ok(Some(Token::Num(value)))
+			}
+		}
+		_ => Err(None),
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::property::agree;
+	use crate::property::check;
+	use crate::property::shrink_integer;
+	use crate::property::shrink_vec;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn scan_sample()
+	{
+		assert_eq!(
+			parse_packet(0, "[[1],[2,3,4]]").unwrap(),
+			[
+				Token::Open,
+				Token::Open,
+				Token::Num(1),
+				Token::Close,
+				Token::Open,
+				Token::Num(2),
+				Token::Num(3),
+				Token::Num(4),
+				Token::Close,
+				Token::Close
+			]
+		);
+	}
+
+	#[test]
+	fn right_order()
+	{
+		let left = parse_packet(0, "[1]").unwrap();
+		let right = parse_packet(1, "[[[[[2]]]]]").unwrap();
+		assert_eq!(are_in_right_order(&left, &right), Some(true));
+	}
+
+	#[test]
+	fn list_after_equal_number()
+	{
+		let left = parse_packet(0, "[[6]]").unwrap();
+		let right = parse_packet(1, "[6,[]]").unwrap();
+		assert_eq!(are_in_right_order(&left, &right), Some(true));
+		assert_eq!(compare_to_range(&right, 2, 6), Ordering::Greater);
+	}
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 13);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 140);
+	}
+
+	#[test]
+	fn parse_unbalanced_packet()
+	{
+		let error = parse("[1,[2]\n[3]\n").unwrap_err();
+		assert_eq!(
+			error,
+			ParseError::new(1, 7, "[1,[2]", "unexpected end of line")
+		);
+	}
+
+	#[test]
+	fn parse_number_too_large()
+	{
+		let error = parse("[99999999999]\n[1]\n").unwrap_err();
+		let reason = "number too large";
+		assert_eq!(error, ParseError::new(1, 11, "[99999999999]", reason));
+	}
+
+	#[test]
+	fn one_equal_packets()
+	{
+		let packets = parse("[1,[2]]\n[1,2]\n").unwrap();
+		assert_eq!(sum_of_ordered_pair_indices(&packets), None);
+		assert_eq!(decoder_key(&packets), 12);
+	}
+
+	#[derive(Debug, Clone, PartialEq)]
+	enum Packet
+	{
+		Num(i32),
+		List(Vec<Packet>),
+	}
+
+	impl std::fmt::Display for Packet
+	{
+		fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+		{
+			match self
+			{
+				Packet::Num(x) => write!(f, "{}", x),
+				Packet::List(items) =>
+				{
+					let items: Vec<String> =
+						items.iter().map(|item| item.to_string()).collect();
+					write!(f, "[{}]", items.join(","))
+				}
+			}
+		}
+	}
+
+	// Compares the packets as trees, following the rules in the puzzle.
+	fn reference_compare(left: &Packet, right: &Packet) -> Ordering
+	{
+		match (left, right)
+		{
+			(Packet::Num(a), Packet::Num(b)) => a.cmp(b),
+			(Packet::Num(_), Packet::List(_)) =>
+			{
+				reference_compare(&Packet::List(vec![left.clone()]), right)
+			}
+			(Packet::List(_), Packet::Num(_)) =>
+			{
+				reference_compare(left, &Packet::List(vec![right.clone()]))
+			}
+			(Packet::List(a), Packet::List(b)) => a
+				.iter()
+				.zip(b)
+				.map(|(a, b)| reference_compare(a, b))
+				.find(|&order| order != Ordering::Equal)
+				.unwrap_or_else(|| a.len().cmp(&b.len())),
+		}
+	}
+
+	fn divider(x: i32) -> Packet
+	{
+		Packet::List(vec![Packet::List(vec![Packet::Num(x)])])
+	}
+
+	fn reference_solve(pairs: &[(Packet, Packet)]) -> (usize, usize)
+	{
+		let sum = pairs
+			.iter()
+			.enumerate()
+			.filter(|(_, (left, right))| {
+				reference_compare(left, right) == Ordering::Less
+			})
+			.map(|(i, _)| i + 1)
+			.sum();
+		let mut packets: Vec<Packet> = pairs
+			.iter()
+			.flat_map(|(left, right)| [left.clone(), right.clone()])
+			.chain([divider(2), divider(6)])
+			.collect();
+		packets.sort_by(reference_compare);
+		let key = [divider(2), divider(6)]
+			.iter()
+			.map(|x| 1 + packets.iter().position(|packet| packet == x).unwrap())
+			.product();
+		(sum, key)
+	}
+
+	fn random_list(rng: &mut Rng, depth: usize) -> Packet
+	{
+		let items = (0..rng.below(5))
+			.map(|_| {
+				if depth < 3 && rng.chance(0.3)
+				{
+					random_list(rng, depth + 1)
+				}
+				else
+				{
+					Packet::Num(rng.range(0..=10) as i32)
+				}
+			})
+			.collect();
+		Packet::List(items)
+	}
+
+	// Often the right packet is a slightly changed copy of the left one,
+	// which makes ties and equal prefixes much more likely.
+	fn random_pairs(rng: &mut Rng) -> Vec<(Packet, Packet)>
+	{
+		(0..rng.range(1..=8))
+			.map(|_| {
+				let left = random_list(rng, 0);
+				let right = if rng.chance(0.5)
+				{
+					let changes = shrink_packet(&left);
+					if changes.is_empty()
+					{
+						left.clone()
+					}
+					else
+					{
+						rng.pick(&changes).clone()
+					}
+				}
+				else
+				{
+					random_list(rng, 0)
+				};
+				(left, right)
+			})
+			.collect()
+	}
+
+	fn shrink_packet(packet: &Packet) -> Vec<Packet>
+	{
+		match packet
+		{
+			Packet::Num(x) => shrink_integer(i64::from(*x))
+				.into_iter()
+				.map(|x| Packet::Num(x as i32))
+				.collect(),
+			Packet::List(items) =>
+			{
+				let mut candidates: Vec<Packet> = items
+					.iter()
+					.filter(|item| matches!(item, Packet::List(_)))
+					.cloned()
+					.collect();
+				candidates.extend(
+					shrink_vec(items, shrink_item)
+						.into_iter()
+						.map(Packet::List),
+				);
+				candidates
+			}
+		}
+	}
+
+	// The items of a list can also be numbers.
+	fn shrink_item(item: &Packet) -> Vec<Packet>
+	{
+		let mut candidates = shrink_packet(item);
+		if let Packet::List(items) = item
+		{
+			candidates.extend(items.iter().cloned());
+		}
+		candidates
+	}
+
+	fn shrink_pairs(pairs: &[(Packet, Packet)]) -> Vec<Vec<(Packet, Packet)>>
+	{
+		shrink_vec(pairs, |(left, right)| {
+			let lefts = shrink_packet(left)
+				.into_iter()
+				.map(|left| (left, right.clone()));
+			let rights = shrink_packet(right)
+				.into_iter()
+				.map(|right| (left.clone(), right));
+			lefts.chain(rights).collect()
+		})
+	}
+
+	#[test]
+	fn comparison_matches_reference()
+	{
+		check(
+			1000,
+			random_pairs,
+			|pairs| shrink_pairs(pairs),
+			|pairs| {
+				let input: Vec<String> = pairs
+					.iter()
+					.map(|(left, right)| format!("{}\n{}\n", left, right))
+					.collect();
+				let packets = parse(&input.join("\n")).unwrap();
+				for (i, (left, right)) in pairs.iter().enumerate()
+				{
+					agree(
+						&format!("comparing pair {}", i + 1),
+						compare(&packets[2 * i], &packets[2 * i + 1]),
+						reference_compare(left, right),
+					)?;
+				}
+				// The puzzle only has unequal pairs and no copies of the dividers.
+				let are_unequal = pairs.iter().all(|(left, right)| {
+					reference_compare(left, right) != Ordering::Equal
+				});
+				if !are_unequal
+				{
+					agree(
+						"part one",
+						sum_of_ordered_pair_indices(&packets),
+						None,
+					)?;
+				}
+				let is_valid = are_unequal
+					&& pairs.iter().all(|(left, right)| {
+						[left, right].iter().all(|&packet| {
+							reference_compare(packet, &divider(2))
+								!= Ordering::Equal && reference_compare(
+								packet,
+								&divider(6),
+							) != Ordering::Equal
+						})
+					});
+				if is_valid
+				{
+					let (sum, key) = reference_solve(pairs);
+					agree(
+						"part one",
+						sum_of_ordered_pair_indices(&packets),
+						Some(sum),
+					)?;
+					agree("part two", decoder_key(&packets), key)?;
+				}
+				Ok(())
+			},
+		);
+	}
+}
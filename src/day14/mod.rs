@@ -0,0 +1,282 @@
+/**/
+
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::vector::Vec2;
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> usize
+{
+	count_sand_until_abyss(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> usize
+{
+	count_sand_until_blocked(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Cave;
+
+	fn parse(&self, input: &str) -> Result<Cave, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, cave: &Cave) -> Option<Answer>
+	{
+		Some(count_sand_until_abyss(cave).into())
+	}
+
+	fn two(&self, cave: &Cave) -> Option<Answer>
+	{
+		Some(count_sand_until_blocked(cave).into())
+	}
+}
+
+fn parse(input: &str) -> Result<Cave, ParseError>
+{
+	let mut paths = Vec::new();
+	for (i, line) in input.lines().enumerate()
+	{
+		let mut path: Vec<Vec2> = Vec::new();
+		let mut j = 0;
+		for pos in line.split(" -> ")
+		{
+			let to: Vec2 =
+				pos.parse().map_err(|_| ParseError::at_byte(i, j, line))?;
+			if to.to_position().is_none()
+			{
+				return Err(ParseError::new(
+					i + 1,
+					j + 1,
+					line,
+					"negative coordinate",
+				));
+			}
+			if let Some(from) = path.last()
+			{
+				if from.x != to.x && from.y != to.y
+				{
+					return Err(ParseError::new(
+						i + 1,
+						j + 1,
+						line,
+						"diagonal",
+					));
+				}
+			}
+			path.push(to);
+			j += pos.len() + " -> ".len();
+		}
+		paths.push(path);
+	}
+	Ok(Cave::build(&paths))
+}
+
+// Paths of rock below the source, with between one and four walls each.
+// They stay within a cone no wider than a pile of sand, so that the sand runs
+// off its sides into the abyss instead of filling a bowl below the source,
+// however many paths there are.
+pub fn generate(rng: &mut Rng, num_paths: usize) -> String
+{
+	let half_width = |y: i32| std::cmp::min(y - CONE_DEPTH, 40);
+	(0..num_paths)
+		.map(|_| {
+			let y = rng.range(CONE_DEPTH as i64..=165) as i32;
+			let w = half_width(y) as i64;
+			let x = SOURCE.x + rng.range(-w..=w) as i32;
+			let mut pos = Vec2::new(x, y);
+			let mut path = vec![pos.to_string()];
+			let mut is_horizontal = rng.chance(0.5);
+			for _ in 0..rng.range(1..=4)
+			{
+				let length = rng.range(-12..=12) as i32;
+				if is_horizontal
+				{
+					let w = half_width(pos.y);
+					pos.x = (pos.x + length).clamp(SOURCE.x - w, SOURCE.x + w);
+				}
+				else
+				{
+					let depth = CONE_DEPTH + (pos.x - SOURCE.x).abs();
+					pos.y = std::cmp::max(pos.y + length, depth);
+				}
+				path.push(pos.to_string());
+				is_horizontal = !is_horizontal;
+			}
+			format!("{}\n", path.join(" -> "))
+		})
+		.collect()
+}
+
+// How far below the source the cone of rock starts.
+const CONE_DEPTH: i32 = 13;
+
+fn count_sand_until_abyss(cave: &Cave) -> usize
+{
+	let mut cave = cave.clone();
+	cave.fill();
+	cave.count_sand()
+}
+
+fn count_sand_until_blocked(cave: &Cave) -> usize
+{
+	let mut cave = cave.clone();
+	cave.build_floor();
+	cave.fill();
+	cave.count_sand()
+}
+
+const SOURCE: Vec2 = Vec2::new(500, 0);
+
+#[derive(Debug, Clone)]
+pub struct Cave
+{
+	// Indexed by y and then x.
+	grid: Grid<u8>,
+	// Nothing happens to the left of this column.
+	left: usize,
+}
+
+impl Cave
+{
+	fn build(paths: &[Vec<Vec2>]) -> Cave
+	{
+		let rocks = paths.iter().flatten();
+		let max_y_of_rock = rocks.clone().map(|pos| pos.y).max().unwrap_or(0);
+		let max_x_of_rock = rocks.clone().map(|pos| pos.x).max().unwrap_or(0);
+		let min_x_of_rock = rocks.map(|pos| pos.x).min().unwrap_or(SOURCE.x);
+		// Leave room for the floor below the lowest rock, and for the sand
+		// that piles up on the floor to the right of the source.
+		// Parsing made sure that no coordinate is negative.
+		let height = max_y_of_rock as usize + 3;
+		let width =
+			std::cmp::max(max_x_of_rock as usize, SOURCE.x as usize + height)
+				+ 2;
+		let left = std::cmp::min(
+			min_x_of_rock as usize,
+			(SOURCE.x as usize).saturating_sub(height),
+		);
+		let mut cave = Cave {
+			grid: Grid::new(width, height, b' '),
+			left: left.saturating_sub(1),
+		};
+		for path in paths
+		{
+			for wall in path.windows(2)
+			{
+				cave.build_wall(wall[0], wall[1]);
+			}
+		}
+		cave
+	}
+
+	fn build_wall(&mut self, from: Vec2, to: Vec2)
+	{
+		let step = (to - from).signum();
+		let mut pos = from;
+		loop
+		{
+			self.grid[pos.to_position().unwrap()] = b'#';
+			if pos == to
+			{
+				break;
+			}
+			pos += step;
+		}
+	}
+
+	fn build_floor(&mut self)
+	{
+		let y = self.grid.height() - 1;
+		self.grid.fill_row(y, b'=');
+	}
+
+	fn fill(&mut self)
+	{
+		while let Some(final_pos) = self.drop_grain()
+		{
+			self.grid[final_pos] = b'o';
+			crate::frames::record(|| self.render());
+		}
+	}
+
+	fn render(&self) -> String
+	{
+		let mut text = String::new();
+		for row in self.grid.rows()
+		{
+			text.extend(row[self.left..].iter().map(|&x| char::from(x)));
+			text.push('\n');
+		}
+		text
+	}
+
+	// Sand that leaves the grid falls into the abyss.
+	fn drop_grain(&mut self) -> Option<(usize, usize)>
+	{
+		let mut pos = SOURCE.to_position().unwrap();
+		if self.grid[pos] != b' '
+		{
+			return None;
+		}
+		'falling: loop
+		{
+			for offset in [(1, 0), (1, -1), (1, 1)]
+			{
+				let next = self.grid.offset(pos, offset)?;
+				if self.grid[next] == b' '
+				{
+					pos = next;
+					continue 'falling;
+				}
+			}
+			return Some(pos);
+		}
+	}
+
+	fn count_sand(&self) -> usize
+	{
+		self.grid.iter().filter(|a| **a == b'o').count()
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 24);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 93);
+	}
+
+	#[test]
+	fn generate_room_for_sand()
+	{
+		for seed in 0..3
+		{
+			let input = generate(&mut Rng::new(seed), 2000);
+			let cave = parse(&input).unwrap();
+			let until_abyss = count_sand_until_abyss(&cave);
+			let until_blocked = count_sand_until_blocked(&cave);
+			assert!(until_abyss > 4, "seed {}", seed);
+			assert!(until_blocked > 10000, "seed {}", seed);
+		}
+	}
+}
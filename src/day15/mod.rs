@@ -0,0 +1,483 @@
+/**/
+
+use crate::parse::parse_lines;
+use crate::vector::Vec2;
+use crate::Answer;
+use crate::ParseError;
+
+pub const REAL_SCALE: i32 = 4000000;
+
+pub fn one(input: &str, height: i32) -> usize
+{
+	count_positions_without_beacon(&parse(input).unwrap(), height)
+}
+
+pub fn two(input: &str, height: i32) -> u64
+{
+	tuning_frequency(&parse(input).unwrap(), height).unwrap()
+}
+
+pub struct Solver
+{
+	pub scale: i32,
+}
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Reading>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Reading>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, readings: &Vec<Reading>) -> Option<Answer>
+	{
+		Some(count_positions_without_beacon(readings, self.scale).into())
+	}
+
+	fn two(&self, readings: &Vec<Reading>) -> Option<Answer>
+	{
+		Some(tuning_frequency(readings, self.scale)?.into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Reading>, ParseError>
+{
+	parse_lines(input)
+}
+
+fn count_positions_without_beacon(readings: &[Reading], height: i32) -> usize
+{
+	let scanline_y = height / 2;
+	// Collect ranges where the sensor range overlaps the scan line.
+	let mut scan_ranges: Vec<Range> = readings
+		.iter()
+		.flat_map(|reading| get_scan_intersection(reading, scanline_y))
+		.flatten()
+		.collect();
+	// Sort the ranges so that overlapping ranges are adjacent.
+	scan_ranges.sort_by_key(|range| *range.start());
+	// Count the number of unoccupied spaces after merging overlapping ranges.
+	count_deduplicated_ranges(scan_ranges)
+}
+
+// There has to be exactly one place for the distress beacon, next to the
+// edges of the ranges of several sensors.
+fn tuning_frequency(readings: &[Reading], height: i32) -> Option<u64>
+{
+	let sensors: Vec<Diamond> =
+		readings.iter().map(|reading| (*reading).into()).collect();
+	let signal = do_line_search(height, &sensors)?;
+	crate::info!("distress signal at {}", signal);
+	Some((signal.x as u64) * (REAL_SCALE as u64) + (signal.y as u64))
+}
+
+fn do_line_search(scope: i32, sensors: &[Diamond]) -> Option<Vec2>
+{
+	// We know that there is exactly one solution, so it must be just outside
+	// the range of the sensors that cover its neighbors. Because the sensor
+	// ranges are diamonds, their edges are diagonal lines, and at least two
+	// of those sensors have an edge on the same diagonal through the signal.
+	// Other sensors can cover the rest of that diagonal without having an
+	// edge on the other diagonal through the signal, so instead of
+	// intersecting diagonals we look for a gap along each of them.
+	// In rotated coordinates a = x - y and b = x + y, the diagonals are lines
+	// where a or b is constant and the sensor ranges are squares.
+	let mut ascenders: Vec<i32> = sensors
+		.iter()
+		.flat_map(|sensor| {
+			let a = sensor.center.x - sensor.center.y;
+			[a - sensor.range - 1, a + sensor.range + 1]
+		})
+		.collect();
+	ascenders.sort();
+	let mut descenders: Vec<i32> = sensors
+		.iter()
+		.flat_map(|sensor| {
+			let b = sensor.center.x + sensor.center.y;
+			[b - sensor.range - 1, b + sensor.range + 1]
+		})
+		.collect();
+	descenders.sort();
+	// Filter out non-duplicate entries and keep only the duplicates.
+	let mut ascenders: Vec<i32> = ascenders
+		.windows(2)
+		.filter_map(|window| match window
+		{
+			[a, b] if a == b => Some(*a),
+			[_, _] => None,
+			_ => unreachable!(),
+		})
+		.collect();
+	let mut descenders: Vec<i32> = descenders
+		.windows(2)
+		.filter_map(|window| match window
+		{
+			[a, b] if a == b => Some(*a),
+			[_, _] => None,
+			_ => unreachable!(),
+		})
+		.collect();
+	ascenders.dedup();
+	descenders.dedup();
+	for a in ascenders
+	{
+		// Both x = (a + b) / 2 and y = (b - a) / 2 are within the scope.
+		let low = std::cmp::max(a, -a);
+		let high = std::cmp::min(2 * scope - a, 2 * scope + a);
+		let spans = sensors
+			.iter()
+			.map(|sensor| sensor.rotated())
+			.filter(|&(sa, _, range)| (a - sa).abs() <= range)
+			.map(|(_, sb, range)| (sb - range, sb + range))
+			.collect();
+		if let Some(b) = find_gap(a, low, high, spans)
+		{
+			return Some(Vec2::new((a + b) / 2, (b - a) / 2));
+		}
+	}
+	for b in descenders
+	{
+		let low = std::cmp::max(-b, b - 2 * scope);
+		let high = std::cmp::min(b, 2 * scope - b);
+		let spans = sensors
+			.iter()
+			.map(|sensor| sensor.rotated())
+			.filter(|&(_, sb, range)| (b - sb).abs() <= range)
+			.map(|(sa, _, range)| (sa - range, sa + range))
+			.collect();
+		if let Some(a) = find_gap(b, low, high, spans)
+		{
+			return Some(Vec2::new((a + b) / 2, (b - a) / 2));
+		}
+	}
+	None
+}
+
+// Finds the first value between low and high that is not in any of the
+// spans, skipping values that do not have the same parity as the line
+// because they do not correspond to a position on the grid.
+fn find_gap(
+	line: i32,
+	low: i32,
+	high: i32,
+	mut spans: Vec<(i32, i32)>,
+) -> Option<i32>
+{
+	let align = |value: i32| value + (value - line).rem_euclid(2);
+	spans.sort();
+	let mut value = align(low);
+	for (start, end) in spans
+	{
+		if value < start
+		{
+			break;
+		}
+		if value <= end
+		{
+			value = align(end + 1);
+		}
+	}
+	(value <= high).then_some(value)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Diamond
+{
+	center: Vec2,
+	range: i32,
+}
+
+impl Diamond
+{
+	// The range becomes a square around (x - y, x + y).
+	fn rotated(&self) -> (i32, i32, i32)
+	{
+		let Vec2 { x, y } = self.center;
+		(x - y, x + y, self.range)
+	}
+}
+
+impl From<Reading> for Diamond
+{
+	fn from(reading: Reading) -> Diamond
+	{
+		let range = reading.sensor.manhattan_distance(reading.beacon);
+		Diamond {
+			center: reading.sensor,
+			range,
+		}
+	}
+}
+
+#[derive(
+	Debug, Clone, Copy, parse_display::Display, parse_display::FromStr,
+)]
+#[display(
+	"Sensor at x={sensor.x}, y={sensor.y}: closest beacon is at x={beacon.x}, \
+	 y={beacon.y}"
+)]
+pub struct Reading
+{
+	#[from_str(default)]
+	sensor: Vec2,
+	#[from_str(default)]
+	beacon: Vec2,
+}
+
+type Range = std::ops::RangeInclusive<i32>;
+
+fn get_scan_intersection(
+	reading: &Reading,
+	scanline_y: i32,
+) -> [Option<Range>; 2]
+{
+	let dis = reading.sensor.manhattan_distance(reading.beacon);
+	let dy = scanline_y - reading.sensor.y;
+	let ady = dy.abs();
+	if ady <= dis
+	{
+		// There are two points where the "circle" with radius dis intersects
+		// the line at scanline_y: x0 and x1, possibly equal.
+		// They satisfy (x - s.x).abs() + (scanline_y - s.y).abs() == dis,
+		// hence (x - s.x).abs() == dis - ady,
+		// hence x = s.x +- (dis - ady)
+		let adx = dis - ady;
+		let x0 = reading.sensor.x - adx;
+		let x1 = reading.sensor.x + adx;
+		assert!(x0 <= x1);
+		// The beacon itself is not one of the beacon-free spaces.
+		if scanline_y == reading.beacon.y
+		{
+			let bx = reading.beacon.x;
+			if x0 == bx
+			{
+				[Some((bx + 1)..=x1), None]
+			}
+			else if x1 == bx
+			{
+				[Some(x0..=(bx - 1)), None]
+			}
+			else
+			{
+				[Some(x0..=(bx - 1)), Some((bx + 1)..=x1)]
+			}
+		}
+		else
+		{
+			[Some(x0..=x1), None]
+		}
+	}
+	else
+	{
+		[None, None]
+	}
+}
+
+fn count_deduplicated_ranges(ranges: Vec<Range>) -> usize
+{
+	let mut ranges = ranges.into_iter().peekable();
+	let mut num_free_spaces = 0;
+	let mut merging_range: Option<Range> = None;
+	while let Some(range) = ranges.next()
+	{
+		let range = match merging_range.take()
+		{
+			Some(other) =>
+			{
+				let start: i32 = std::cmp::min(*range.start(), *other.start());
+				let end: i32 = std::cmp::max(*range.end(), *other.end());
+				start..=end
+			}
+			None => range,
+		};
+		match ranges.peek()
+		{
+			Some(other) if overlap(&range, other) =>
+			{
+				merging_range = Some(range)
+			}
+			Some(_) | None =>
+			{
+				num_free_spaces += range.count();
+			}
+		}
+	}
+	num_free_spaces
+}
+
+// From day04.
+fn overlap(a: &Range, b: &Range) -> bool
+{
+	// Edge containment is NOT symmetric (e.g. 4-4 does not contain 1 or 9).
+	contains_edge(a, b) || contains_edge(b, a)
+}
+
+// From day04.
+fn contains_edge(a: &Range, b: &Range) -> bool
+{
+	a.contains(b.start()) || a.contains(b.end())
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::generate::Rng;
+	use crate::property::agree;
+	use crate::property::check;
+	use crate::property::shrink_vec;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED, 20), 26);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED, 20), 56000011);
+	}
+
+	#[test]
+	fn two_without_place_for_beacon()
+	{
+		let input: String = PROVIDED
+			.lines()
+			.take(4)
+			.map(|line| line.to_owned() + "\n")
+			.collect();
+		let readings = parse(&input).unwrap();
+		let count = count_positions_without_beacon(&readings, SCOPE);
+		assert_eq!(count, reference_count(&readings, SCOPE));
+		assert_eq!(tuning_frequency(&readings, SCOPE), None);
+	}
+
+	const SCOPE: i32 = 20;
+
+	fn range(reading: &Reading) -> i32
+	{
+		reading.sensor.manhattan_distance(reading.beacon)
+	}
+
+	fn is_covered(readings: &[Reading], pos: Vec2) -> bool
+	{
+		readings.iter().any(|reading| {
+			reading.sensor.manhattan_distance(pos) <= range(reading)
+		})
+	}
+
+	fn uncovered(readings: &[Reading]) -> Vec<Vec2>
+	{
+		(0..=SCOPE)
+			.flat_map(|y| (0..=SCOPE).map(move |x| Vec2::new(x, y)))
+			.filter(|&pos| !is_covered(readings, pos))
+			.collect()
+	}
+
+	// Checks every position on the line, one by one.
+	fn reference_count(readings: &[Reading], height: i32) -> usize
+	{
+		let y = height / 2;
+		let left = readings.iter().map(|r| r.sensor.x - range(r)).min();
+		let right = readings.iter().map(|r| r.sensor.x + range(r)).max();
+		(left.unwrap()..=right.unwrap())
+			.map(|x| Vec2::new(x, y))
+			.filter(|&pos| is_covered(readings, pos))
+			.filter(|&pos| readings.iter().all(|r| r.beacon != pos))
+			.count()
+	}
+
+	fn reference_frequency(readings: &[Reading]) -> u64
+	{
+		let signal = uncovered(readings)[0];
+		(signal.x as u64) * (REAL_SCALE as u64) + (signal.y as u64)
+	}
+
+	// Keeps adding sensors that cover some uncovered position, while making
+	// sure that every sensor has a single closest beacon, until only the
+	// distress signal is left. Like in the puzzle, it is not at the edge.
+	fn random_readings(rng: &mut Rng) -> Vec<Reading>
+	{
+		loop
+		{
+			let signal = Vec2::new(
+				rng.range(1..=(SCOPE - 1) as i64) as i32,
+				rng.range(1..=(SCOPE - 1) as i64) as i32,
+			);
+			let mut readings: Vec<Reading> = Vec::new();
+			let mut targets: Vec<Vec2> = uncovered(&readings);
+			targets.retain(|&pos| pos != signal);
+			for _ in 0..100
+			{
+				if targets.is_empty()
+				{
+					return readings;
+				}
+				let target = *rng.pick(&targets);
+				let offset = Vec2::new(
+					rng.range(-6..=6) as i32,
+					rng.range(-6..=6) as i32,
+				);
+				let sensor = target + offset;
+				let max_range = readings
+					.iter()
+					.map(|r| sensor.manhattan_distance(r.beacon))
+					.chain([sensor.manhattan_distance(signal)])
+					.min()
+					.unwrap() - 1;
+				let min_range = sensor.manhattan_distance(target);
+				if min_range == 0 || max_range < min_range
+				{
+					continue;
+				}
+				let range =
+					rng.range(min_range as i64..=max_range as i64) as i32;
+				let dx = rng.range(-range as i64..=range as i64) as i32;
+				let dy = (range - dx.abs()) * rng.pick(&[-1, 1]);
+				let beacon = sensor + Vec2::new(dx, dy);
+				if is_covered(&readings, beacon)
+				{
+					continue;
+				}
+				let reading = Reading { sensor, beacon };
+				targets.retain(|&pos| !is_covered(&[reading], pos));
+				readings.push(reading);
+			}
+		}
+	}
+
+	fn shrink_readings(readings: &[Reading]) -> Vec<Vec<Reading>>
+	{
+		let mut candidates = shrink_vec(readings, |_| Vec::new());
+		candidates.retain(|readings| uncovered(readings).len() == 1);
+		candidates
+	}
+
+	#[test]
+	fn line_search_matches_reference()
+	{
+		check(
+			200,
+			random_readings,
+			|readings| shrink_readings(readings),
+			|readings| {
+				agree(
+					"part one",
+					count_positions_without_beacon(readings, SCOPE),
+					reference_count(readings, SCOPE),
+				)?;
+				agree(
+					"part two",
+					tuning_frequency(readings, SCOPE),
+					Some(reference_frequency(readings)),
+				)
+			},
+		);
+	}
+}
@@ -1,17 +1,154 @@
 /**/
 
-const INPUT: &str = include_str!("input.txt");
+use crate::generate::Rng;
+use crate::search;
+use crate::search::BranchAndBound;
+use crate::solution::Variant;
+use crate::Answer;
+use crate::ParseError;
+use crate::Part;
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+pub fn one(input: &str) -> i32
+{
+	release_pressure_alone(&parse(input).unwrap(), Strategy::BestFirst)
+}
 
-pub fn main()
+pub fn two(input: &str) -> i32
 {
-	println!("Part One: {}", one(INPUT));
-	println!("Part Two: {}", two(INPUT));
+	release_pressure_with_elephant(&parse(input).unwrap(), Strategy::BestFirst)
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Cave;
+
+	fn parse(&self, input: &str) -> Result<Cave, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, cave: &Cave) -> Option<Answer>
+	{
+		Some(release_pressure_alone(cave, Strategy::BestFirst).into())
+	}
+
+	fn two(&self, cave: &Cave) -> Option<Answer>
+	{
+		Some(release_pressure_with_elephant(cave, Strategy::BestFirst).into())
+	}
+
+	fn variants(&self) -> Vec<Variant<Cave>>
+	{
+		vec![
+			Variant {
+				name: "depth-first",
+				part: Part::One,
+				solve: |cave| {
+					let pressure =
+						release_pressure_alone(cave, Strategy::DepthFirst);
+					Some(pressure.into())
+				},
+			},
+			Variant {
+				name: "depth-first",
+				part: Part::Two,
+				solve: |cave| {
+					let pressure = release_pressure_with_elephant(
+						cave,
+						Strategy::DepthFirst,
+					);
+					Some(pressure.into())
+				},
+			},
+		]
+	}
 }
 
-fn one(input: &str) -> i32
+fn parse(input: &str) -> Result<Cave, ParseError>
 {
-	let mut cave = parse_input(input);
+	let mut cave = parse_input(input)?;
 	sort_and_filter_valves(&mut cave);
+	Ok(cave)
+}
+
+// The valves are connected by a random tree with a few extra tunnels, and
+// about a quarter of them have a flow rate, like in the actual input.
+pub fn generate(rng: &mut Rng, num_valves: usize) -> String
+{
+	let mut labels: Vec<String> = (b'A'..=b'Z')
+		.flat_map(|a| {
+			(b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char))
+		})
+		.collect();
+	let num_valves = num_valves.clamp(2, labels.len());
+	// Keep AA, where we start.
+	rng.shuffle(&mut labels[1..]);
+	labels.truncate(num_valves);
+	let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); num_valves];
+	for i in 1..num_valves
+	{
+		let j = rng.below(i);
+		tunnels[i].push(j);
+		tunnels[j].push(i);
+	}
+	for _ in 0..num_valves / 4
+	{
+		let i = rng.below(num_valves);
+		let j = rng.below(num_valves);
+		if i != j && !tunnels[i].contains(&j)
+		{
+			tunnels[i].push(j);
+			tunnels[j].push(i);
+		}
+	}
+	let mut order: Vec<usize> = (0..num_valves).collect();
+	rng.shuffle(&mut order);
+	order
+		.into_iter()
+		.map(|i| {
+			let flow_rate = if i > 0 && rng.chance(0.25)
+			{
+				rng.range(1..=25)
+			}
+			else
+			{
+				0
+			};
+			let exits: Vec<&str> =
+				tunnels[i].iter().map(|&j| labels[j].as_str()).collect();
+			let tunnels_lead = match exits.len()
+			{
+				1 => "tunnel leads to valve",
+				_ => "tunnels lead to valves",
+			};
+			format!(
+				"Valve {} has flow rate={}; {} {}\n",
+				labels[i],
+				flow_rate,
+				tunnels_lead,
+				exits.join(", ")
+			)
+		})
+		.collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Strategy
+{
+	// Explore the states with the best greedy solution first.
+	BestFirst,
+	// Explore the most recent states first, which takes longer to find a good
+	// solution but uses far less memory.
+	DepthFirst,
+}
+
+fn release_pressure_alone(cave: &Cave, strategy: Strategy) -> i32
+{
 	let starting_position = cave.starting_position() as u8;
 	let initial_state = State {
 		is_open: 0,
@@ -23,13 +160,11 @@ fn one(input: &str) -> i32
 		greedy_lower_bound: 0,
 		loose_upper_bound: 0,
 	};
-	calculate_max_total_pressure(&cave, initial_state)
+	calculate_max_total_pressure(cave, initial_state, strategy)
 }
 
-fn two(input: &str) -> i32
+fn release_pressure_with_elephant(cave: &Cave, strategy: Strategy) -> i32
 {
-	let mut cave = parse_input(input);
-	sort_and_filter_valves(&mut cave);
 	let starting_position = cave.starting_position() as u8;
 	let initial_state = State {
 		is_open: 0,
@@ -41,14 +176,14 @@ fn two(input: &str) -> i32
 		greedy_lower_bound: 0,
 		loose_upper_bound: 0,
 	};
-	calculate_max_total_pressure(&cave, initial_state)
+	calculate_max_total_pressure(cave, initial_state, strategy)
 }
 
 const MAX_TIME: i8 = 30;
 const MAX_VALVES: usize = 128;
 
 #[derive(Debug)]
-struct Cave
+pub struct Cave
 {
 	num_valves: usize,
 	valve_labels: [[u8; 2]; MAX_VALVES],
@@ -62,152 +197,167 @@ impl Cave
 	{
 		self.valve_labels
 			.iter()
-			.position(|label| label == &[b'A', b'A'])
+			.position(|label| label == b"AA")
 			.unwrap()
 	}
 }
 
-fn calculate_max_total_pressure(cave: &Cave, mut initial_state: State) -> i32
+fn calculate_max_total_pressure(
+	cave: &Cave,
+	mut initial_state: State,
+	strategy: Strategy,
+) -> i32
 {
 	initial_state.perform_heuristics(cave);
-	let mut queue: Vec<State> = Vec::new();
-	queue.push(initial_state);
-	let mut max_total_pressure = 0;
-	while let Some(i_of_max) = queue
-		.iter()
-		.enumerate()
-		.max_by_key(|(_i, state)| state.greedy_lower_bound)
-		.map(|(i, _)| i)
-	{
-		let current = queue.swap_remove(i_of_max);
-		//println!("current = {current:?}");
+	let mut valves = Valves {
+		cave,
+		best_seen: HashMap::new(),
+	};
+	let best = match strategy
+	{
+		Strategy::BestFirst =>
+		{
+			search::best_first(&mut valves, initial_state, |state| {
+				state.greedy_lower_bound
+			})
+		}
+		Strategy::DepthFirst => search::depth_first(&mut valves, initial_state),
+	};
+	std::cmp::max(best, 0)
+}
 
+struct Valves<'a>
+{
+	cave: &'a Cave,
+	// If we are in the same position and have opened the same valves, the
+	// only thing that's changed is the order in which we opened them. So only
+	// the best total pressure for each of those is worth exploring.
+	best_seen: HashMap<(u128, i8, i8, u8, u8), i32>,
+}
+
+impl BranchAndBound for Valves<'_>
+{
+	type Node = State;
+
+	fn bound(&self, state: &State) -> i32
+	{
+		state.loose_upper_bound
+	}
+
+	fn branch(
+		&mut self,
+		current: State,
+		_best: i32,
+		children: &mut Vec<State>,
+	) -> i32
+	{
+		let cave = self.cave;
+		let mut max_total_pressure = current.total_pressure_added;
+
+		// Whoever has the most time remaining acts first, so that we do not
+		// explore every possible interleaving of the same two routes.
+		let traveler_acts = current.time_remaining_for_traveler
+			>= current.time_remaining_for_elephant;
+		let (position, time_remaining) = if traveler_acts
+		{
+			(
+				current.traveler_position,
+				current.time_remaining_for_traveler,
+			)
+		}
+		else
+		{
+			(
+				current.elephant_position,
+				current.time_remaining_for_elephant,
+			)
+		};
 		for i in 0..cave.num_valves
 		{
-			if current.time_remaining_for_traveler == 0
-			{
-				break;
-			}
-			let distance = cave.distance[current.traveler_position as usize][i];
+			let distance = cave.distance[position as usize][i];
 			let time_needed = distance as i8 + 1;
 			if distance > 0
-				&& current.time_remaining_for_traveler >= time_needed
+				&& time_remaining >= time_needed
 				&& !current.has_been_opened(i as u8)
 			{
 				let mut next: State = current;
-				next.travel(i as u8, cave);
-				next.open(cave);
-
-				decide_what_to_do(
-					next,
-					&cave,
-					&mut queue,
-					&mut max_total_pressure,
+				if traveler_acts
+				{
+					next.travel(i as u8, cave);
+					next.open(cave);
+				}
+				else
+				{
+					next.lumber(i as u8, cave);
+					next.break_open(cave);
+				}
+				max_total_pressure = std::cmp::max(
+					max_total_pressure,
+					next.total_pressure_added,
 				);
+				self.consider(next, children);
 			}
 		}
 
-		for i in 0..cave.num_valves
+		// Either of us can also decide to stop and leave the remaining
+		// valves to the other.
+		let mut next: State = current;
+		if traveler_acts
 		{
-			if current.time_remaining_for_elephant == 0
-			{
-				break;
-			}
-			let distance = cave.distance[current.elephant_position as usize][i];
-			let time_needed = distance as i8 + 1;
-			if distance > 0
-				&& current.time_remaining_for_elephant >= time_needed
-				&& !current.has_been_opened(i as u8)
-			{
-				let mut next: State = current;
-				next.lumber(i as u8, cave);
-				next.break_open(cave);
-
-				decide_what_to_do(
-					next,
-					&cave,
-					&mut queue,
-					&mut max_total_pressure,
-				);
-			}
+			next.time_remaining_for_traveler = 0;
 		}
+		else
+		{
+			next.time_remaining_for_elephant = 0;
+		}
+		self.consider(next, children);
 
-		queue.retain(|state| state.loose_upper_bound > max_total_pressure);
-		//println!(
-		//	"len = {}, min = {}, max = {}, best = {}",
-		//	queue.len(),
-		//	queue
-		//		.iter()
-		//		.map(|state| state.loose_upper_bound)
-		//		.min()
-		//		.unwrap_or(0),
-		//	queue
-		//		.iter()
-		//		.map(|state| state.loose_upper_bound)
-		//		.max()
-		//		.unwrap_or(0),
-		//	max_total_pressure
-		//);
-	}
-	max_total_pressure
+		max_total_pressure
+	}
 }
 
-fn decide_what_to_do(
-	mut next: State,
-	cave: &Cave,
-	queue: &mut Vec<State>,
-	max_total_pressure: &mut i32,
-)
+impl Valves<'_>
 {
-	if next.total_pressure_added > *max_total_pressure
-	{
-		*max_total_pressure = next.total_pressure_added;
-		dbg!(*max_total_pressure);
-		//dbg!(&next);
-	}
-
-	assert!(next.time_remaining_for_traveler >= 0);
-	assert!(next.time_remaining_for_elephant >= 0);
-	if next.time_remaining_for_traveler == 0
-		&& next.time_remaining_for_elephant == 0
+	fn consider(&mut self, mut next: State, children: &mut Vec<State>)
 	{
-		//dbg!(&next);
-		return;
-	}
-
-	next.perform_heuristics(cave);
-	if next.loose_upper_bound <= *max_total_pressure
-	{
-		//dbg!(&next);
-		return;
-	}
+		assert!(next.time_remaining_for_traveler >= 0);
+		assert!(next.time_remaining_for_elephant >= 0);
+		if next.time_remaining_for_traveler == 0
+			&& next.time_remaining_for_elephant == 0
+		{
+			return;
+		}
 
-	// If we are in the same position and have opened the same
-	// valves, the only thing that's changed is the order in which
-	// we opened them. So keep the one that is better.
-	if let Some(other) = queue.iter_mut().find(|other| {
-		other.is_open == next.is_open
-			&& other.traveler_position == next.traveler_position
-			&& other.elephant_position == next.elephant_position
-	})
-	{
-		if next.total_pressure_added > other.total_pressure_added
+		let key = (
+			next.is_open,
+			next.time_remaining_for_traveler,
+			next.time_remaining_for_elephant,
+			next.traveler_position,
+			next.elephant_position,
+		);
+		match self.best_seen.entry(key)
 		{
-			*other = next;
+			Entry::Occupied(entry)
+				if *entry.get() >= next.total_pressure_added =>
+			{
+				return;
+			}
+			entry =>
+			{
+				entry.insert_entry(next.total_pressure_added);
+			}
 		}
-		//dbg!(other);
-		return;
-	}
 
-	//dbg!(&next);
-	queue.push(next);
+		next.perform_heuristics(self.cave);
+		children.push(next);
+	}
 }
 
 const READING_REGEX: &str = "Valve (?P<label>[A-Z][A-Z]) has flow \
                              rate=(?P<flowrate>[0-9]+); tunnels? leads? to \
                              valves? (?P<exits>[A-Z]+(, [A-Z]+)*)";
 
-fn parse_input(input: &str) -> Cave
+fn parse_input(input: &str) -> Result<Cave, ParseError>
 {
 	let reading_regex = regex::Regex::new(READING_REGEX).unwrap();
 	let mut cave = Cave {
@@ -216,16 +366,24 @@ fn parse_input(input: &str) -> Cave
 		flow_rate: [0; MAX_VALVES],
 		distance: [[0; MAX_VALVES]; MAX_VALVES],
 	};
-	for line in input.lines()
+	for (i, line) in input.lines().enumerate()
 	{
-		let captures = reading_regex.captures(line).unwrap();
-		let i = cave.num_valves;
-		assert!(i < MAX_VALVES);
+		let captures = reading_regex
+			.captures(line)
+			.ok_or_else(|| ParseError::at_line(i, line, "invalid valve"))?;
+		// Leave room for at least one valve with no flow rate.
+		if i + 1 >= MAX_VALVES
+		{
+			return Err(ParseError::at_line(i, line, "too many valves"));
+		}
 		cave.num_valves += 1;
 		let label = captures.name("label").unwrap().as_str();
 		cave.valve_labels[i] = label.as_bytes().try_into().unwrap();
-		let flowrate_str = captures.name("flowrate").unwrap().as_str();
-		cave.flow_rate[i] = flowrate_str.parse().unwrap();
+		let flowrate = captures.name("flowrate").unwrap();
+		cave.flow_rate[i] = flowrate
+			.as_str()
+			.parse()
+			.map_err(|_| ParseError::at_byte(i, flowrate.start(), line))?;
 		let exits_str = captures.name("exits").unwrap().as_str();
 		let exits = exits_str.split(", ");
 		for exit in exits
@@ -255,15 +413,22 @@ fn parse_input(input: &str) -> Cave
 			}
 		}
 	}
-	for i in 0..cave.num_valves
+	if !cave.valve_labels.contains(b"AA")
+	{
+		return Err(ParseError::at_end(input, "missing valve AA"));
+	}
+	for (i, line) in input.lines().enumerate()
 	{
 		for j in 0..i
 		{
 			assert_eq!(cave.distance[i][j], cave.distance[j][i]);
-			assert!(cave.distance[i][j] > 0);
+			if cave.distance[i][j] == 0
+			{
+				return Err(ParseError::at_line(i, line, "unreachable valve"));
+			}
 		}
 	}
-	cave
+	Ok(cave)
 }
 
 fn fix_distances(cave: &mut Cave, i: usize, j: usize, k: usize)
@@ -290,7 +455,7 @@ fn sort_and_filter_valves(cave: &mut Cave)
 {
 	// Sort the valves from high flow rate to low, in particular such that
 	// the valves with positive flow rate are all at the start.
-	let mut perm = permutation::sort_by_key(&cave.flow_rate, |i| -(*i as i32));
+	let mut perm = permutation::sort_by_key(cave.flow_rate, |i| -*i);
 	perm.apply_slice_in_place(&mut cave.valve_labels);
 	perm.apply_slice_in_place(&mut cave.flow_rate);
 	perm.apply_slice_in_place(&mut cave.distance);
@@ -487,13 +652,13 @@ mod tests
 	#[test]
 	fn two_testcase1()
 	{
-		assert_eq!(two(TESTCASE1), 2652);
+		assert_eq!(two(TESTCASE1), 2670);
 	}
 
 	#[test]
 	fn two_testcase2()
 	{
-		assert_eq!(two(TESTCASE2), 12355);
+		assert_eq!(two(TESTCASE2), 12887);
 	}
 
 	#[test]
@@ -507,4 +672,21 @@ mod tests
 	{
 		assert_eq!(two(TESTCASE4), 3680);
 	}
+
+	#[test]
+	fn depth_first_testcases()
+	{
+		let cases = [
+			(PROVIDED, 1651, 1707),
+			(TESTCASE3, 1288, 1484),
+		];
+		for (input, expected_one, expected_two) in cases
+		{
+			let cave = parse(input).unwrap();
+			let alone = release_pressure_alone(&cave, Strategy::DepthFirst);
+			let with_elephant =
+				release_pressure_with_elephant(&cave, Strategy::DepthFirst);
+			assert_eq!((alone, with_elephant), (expected_one, expected_two));
+		}
+	}
 }
@@ -0,0 +1,376 @@
+/**/
+
+use crate::cycle;
+use crate::generate::Rng;
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> usize
+{
+	tower_height(input.as_bytes(), 2022).unwrap()
+}
+
+pub fn two(input: &str) -> usize
+{
+	tower_height(input.as_bytes(), 1000000000000).unwrap()
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<u8>;
+
+	fn parse(&self, input: &str) -> Result<Vec<u8>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, wind_data: &Vec<u8>) -> Option<Answer>
+	{
+		Some(tower_height(wind_data, 2022)?.into())
+	}
+
+	fn two(&self, wind_data: &Vec<u8>) -> Option<Answer>
+	{
+		Some(tower_height(wind_data, 1000000000000)?.into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<u8>, ParseError>
+{
+	for (i, line) in input.lines().enumerate()
+	{
+		let invalid = line.bytes().position(|x| !b"<>=".contains(&x));
+		if let Some(j) = invalid
+		{
+			return Err(ParseError::at_byte(i, j, line));
+		}
+	}
+	if !input.bytes().any(|x| x == b'<' || x == b'>')
+	{
+		return Err(ParseError::at_end(input, "missing jet pattern"));
+	}
+	Ok(input.as_bytes().to_vec())
+}
+
+pub fn generate(rng: &mut Rng, num_jets: usize) -> String
+{
+	let mut jets: String = (0..num_jets.max(1))
+		.map(|_| char::from(*rng.pick(b"<>")))
+		.collect();
+	jets.push('\n');
+	jets
+}
+
+// Some patterns leave a gap that never closes, so that no part of the tower
+// can be cut off, and then there is no telling how tall it gets. Once stuck,
+// the simulation stays that way, so the last state it is measured in says
+// whether it got stuck on the way.
+fn tower_height(wind_data: &[u8], number_of_rocks: usize) -> Option<usize>
+{
+	let mut is_stuck = false;
+	let height = cycle::extrapolate(
+		Simulation::new(wind_data),
+		number_of_rocks,
+		Simulation::drop_rock,
+		key,
+		|simulation| {
+			is_stuck = simulation.is_stuck;
+			simulation.cave.height_from_floor
+		},
+	);
+	(!is_stuck).then_some(height)
+}
+
+// Everything that determines where the next rocks end up, that is,
+// everything except how far the cave has been cut off.
+fn key(simulation: &Simulation) -> (usize, usize, bool, [u8; MAX_HEIGHT])
+{
+	(
+		simulation.shape_offset,
+		simulation.wind_offset,
+		simulation.is_stuck,
+		simulation.cave.grid,
+	)
+}
+
+const NUM_SHAPES: usize = 5;
+const SHAPE_HEIGHT: usize = 4;
+const SHAPE_WIDTH: [usize; NUM_SHAPES] = [4, 3, 3, 1, 2];
+// Shape data is stored with the left-most pixel in least-significant position,
+// and the bottom row as 0.
+const SHAPE_DATA: [[u8; SHAPE_HEIGHT]; NUM_SHAPES] = [
+	// 0000
+	// 0000
+	// 0000
+	// 1111
+	[0b1111, 0b0000, 0b0000, 0b0000],
+	// 0000
+	// 0100
+	// 1110
+	// 0100
+	[0b0010, 0b0111, 0b0010, 0b0000],
+	// 0000
+	// 0010
+	// 0010
+	// 1110
+	[0b0111, 0b0100, 0b0100, 0b0000],
+	// 1000
+	// 1000
+	// 1000
+	// 1000
+	[0b0001, 0b0001, 0b0001, 0b0001],
+	// 0000
+	// 0000
+	// 1100
+	// 1100
+	[0b0011, 0b0011, 0b0000, 0b0000],
+];
+
+const CAVE_WIDTH: usize = 7;
+const MAX_HEIGHT: usize = 128;
+const FRAME_HEIGHT: usize = 40;
+
+struct Cave
+{
+	height_from_floor: usize,
+	height_from_cutoff: usize,
+	y_of_cutoff: usize,
+	grid: [u8; MAX_HEIGHT],
+}
+
+impl Default for Cave
+{
+	fn default() -> Cave
+	{
+		Cave {
+			height_from_floor: 0,
+			height_from_cutoff: 0,
+			y_of_cutoff: 0,
+			grid: [0; MAX_HEIGHT],
+		}
+	}
+}
+
+impl Cave
+{
+	fn fits(&self, shape: [u8; SHAPE_HEIGHT], x: usize, y: usize) -> bool
+	{
+		(0..SHAPE_HEIGHT).all(|i| ((shape[i] << x) & self.grid[y + i]) == 0)
+	}
+
+	fn place(&mut self, shape: [u8; SHAPE_HEIGHT], x: usize, y: usize)
+	{
+		for i in 0..SHAPE_HEIGHT
+		{
+			let slice = shape[i] << x;
+			if slice != 0
+			{
+				self.grid[y + i] |= slice;
+				let h = y + i + 1;
+				if h > self.height_from_cutoff
+				{
+					self.height_from_cutoff = h;
+					self.height_from_floor = self.y_of_cutoff + h;
+				}
+			}
+		}
+	}
+
+	fn find_cutoff(&self) -> usize
+	{
+		let cave_mask = ((1 << CAVE_WIDTH) - 1) as u8;
+		let mut water_mask = cave_mask;
+		for y in (1..self.height_from_cutoff).rev()
+		{
+			let gap_mask = !self.grid[y];
+			water_mask &= gap_mask;
+
+			for _i in 0..CAVE_WIDTH
+			{
+				let left_mask = (water_mask >> 1) & cave_mask & gap_mask;
+				let right_mask = (water_mask << 1) & cave_mask & gap_mask;
+				let merged_mask = water_mask | left_mask | right_mask;
+				if merged_mask == water_mask
+				{
+					break;
+				}
+				water_mask = merged_mask;
+			}
+
+			if water_mask == 0
+			{
+				return y;
+			}
+		}
+		0
+	}
+
+	fn perform_cutoff(&mut self)
+	{
+		let y = self.find_cutoff();
+		assert!(y <= self.height_from_cutoff);
+		self.grid.copy_within(y.., 0);
+		self.height_from_cutoff -= y;
+		self.y_of_cutoff += y;
+		self.grid[self.height_from_cutoff..].fill(0);
+	}
+}
+
+struct Simulation<'a>
+{
+	cave: Cave,
+	wind_data: &'a [u8],
+	wind_offset: usize,
+	shape_offset: usize,
+	// Whether the tower got too tall to keep in the cave.
+	is_stuck: bool,
+}
+
+impl Simulation<'_>
+{
+	fn new(wind_data: &[u8]) -> Simulation<'_>
+	{
+		Simulation {
+			cave: Cave::default(),
+			wind_data,
+			wind_offset: 0,
+			shape_offset: 0,
+			is_stuck: false,
+		}
+	}
+
+	fn drop_rock(&mut self)
+	{
+		if self.is_stuck
+		{
+			return;
+		}
+		let cave = &mut self.cave;
+		let shape = SHAPE_DATA[self.shape_offset];
+		let shape_width = SHAPE_WIDTH[self.shape_offset];
+		let mut x = 2;
+		let mut y = cave.height_from_cutoff + 3;
+
+		if y + SHAPE_HEIGHT >= MAX_HEIGHT
+		{
+			cave.perform_cutoff();
+			y = cave.height_from_cutoff + 3;
+		}
+		if y + SHAPE_HEIGHT >= MAX_HEIGHT
+		{
+			self.is_stuck = true;
+			return;
+		}
+
+		loop
+		{
+			let wind = self.wind_data[self.wind_offset];
+			self.wind_offset = (self.wind_offset + 1) % self.wind_data.len();
+
+			match wind
+			{
+				b'>' if x + shape_width >= CAVE_WIDTH => (),
+				b'>' =>
+				{
+					if cave.fits(shape, x + 1, y)
+					{
+						x += 1;
+					}
+				}
+				b'<' if x == 0 => (),
+				b'<' =>
+				{
+					if cave.fits(shape, x - 1, y)
+					{
+						x -= 1;
+					}
+				}
+				b'=' => (),
+				b'\n' => continue,
+				_ => unreachable!(),
+			}
+
+			if y == 0
+			{
+				break;
+			}
+
+			if !cave.fits(shape, x, y - 1)
+			{
+				break;
+			}
+
+			y -= 1;
+		}
+
+		cave.place(shape, x, y);
+		crate::trace!(
+			"cave after placing a rock:\n{}",
+			render_cave(&cave.grid[..cave.height_from_cutoff])
+		);
+		// Show the top of the cave, with room for the next rock above it.
+		crate::frames::record(|| {
+			let top = cave.height_from_cutoff;
+			let bottom = top.saturating_sub(FRAME_HEIGHT - SHAPE_HEIGHT);
+			let mut view = cave.grid[bottom..top].to_vec();
+			view.resize(FRAME_HEIGHT, 0);
+			render_cave(&view)
+		});
+
+		self.shape_offset = (self.shape_offset + 1) % NUM_SHAPES;
+	}
+}
+
+fn render_cave(data: &[u8]) -> String
+{
+	let mut text = String::new();
+	// The cave is stored with the floor at 0.
+	for row in data.iter().rev()
+	{
+		text.push('|');
+		for x in 0..CAVE_WIDTH
+		{
+			if (row >> x) & 0b1 != 0
+			{
+				text.push('#');
+			}
+			else
+			{
+				text.push(' ');
+			}
+		}
+		text.push_str("|\n");
+	}
+	text
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 3068);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 1514285714288);
+	}
+
+	#[test]
+	fn gap_that_never_closes()
+	{
+		let wind_data = parse(">>><<><>>\n").unwrap();
+		assert_eq!(tower_height(&wind_data, 2022), None);
+		assert_eq!(tower_height(&wind_data, 1000000000000), None);
+	}
+}
@@ -0,0 +1,424 @@
+/**/
+
+use crate::bits::BitGrid;
+use crate::bits::Words;
+use crate::generate::Rng;
+use crate::parse::parse_line;
+use crate::vector::Vec3;
+use crate::Answer;
+use crate::ParseError;
+
+use std::collections::HashSet;
+
+pub fn one(input: &str) -> u32
+{
+	surface_area(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> u32
+{
+	exterior_surface_area(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Vec3>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Vec3>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, pixels: &Vec<Vec3>) -> Option<Answer>
+	{
+		Some(surface_area(pixels).into())
+	}
+
+	fn two(&self, pixels: &Vec<Vec3>) -> Option<Answer>
+	{
+		Some(exterior_surface_area(pixels).into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Vec3>, ParseError>
+{
+	let mut pixels = Vec::new();
+	for (i, line) in input.lines().enumerate()
+	{
+		let pixel: Vec3 = parse_line(i, line)?;
+		if pixel.x < 0 || pixel.y < 0 || pixel.z < 0
+		{
+			return Err(ParseError::at_line(i, line, "negative coordinate"));
+		}
+		pixels.push(pixel);
+	}
+	Ok(pixels)
+}
+
+// Fills about a third of a box with cubes, which leaves pockets of air.
+pub fn generate(rng: &mut Rng, num_cubes: usize) -> String
+{
+	let mut size = 1;
+	while size * size * size < 3 * num_cubes
+	{
+		size += 1;
+	}
+	let mut seen = HashSet::new();
+	let mut input = String::new();
+	while seen.len() < num_cubes
+	{
+		let cube = Vec3::new(
+			rng.below(size) as i32,
+			rng.below(size) as i32,
+			rng.below(size) as i32,
+		);
+		if seen.insert(cube)
+		{
+			input.push_str(&format!("{}\n", cube));
+		}
+	}
+	input
+}
+
+fn surface_area(pixels: &[Vec3]) -> u32
+{
+	crate::with_words!(plane_width(pixels), count_sides(pixels))
+}
+
+fn exterior_surface_area(pixels: &[Vec3]) -> u32
+{
+	crate::with_words!(plane_width(pixels), count_exterior_sides(pixels))
+}
+
+// The planes are as wide as the box with steam on both sides.
+fn plane_width(pixels: &[Vec3]) -> usize
+{
+	let max = pixels.iter().map(|p| p.x.max(p.y).max(p.z)).max();
+	max.unwrap_or(0) as usize + 2
+}
+
+fn count_sides<W: Words>(pixels: &[Vec3]) -> u32
+{
+	let mut sides: Sides<W> = Sides::around(pixels);
+	for &pixel in pixels
+	{
+		sides.insert(pixel);
+	}
+	sides.count()
+}
+
+fn count_exterior_sides<W: Words>(pixels: &[Vec3]) -> u32
+{
+	let mut sides: Sides<W> = Sides::around(pixels);
+	for &pixel in pixels
+	{
+		sides.insert(pixel);
+	}
+	let total = sides.count();
+	sides.erase_exterior();
+	total - sides.count()
+}
+
+// Each plane is a grid of sides, where x_planes[x] holds the sides between
+// x - 1 and x, with one row for every y and one bit for every z.
+#[derive(Debug)]
+struct Sides<W: Words>
+{
+	x_planes: Vec<BitGrid<W>>,
+	y_planes: Vec<BitGrid<W>>,
+	z_planes: Vec<BitGrid<W>>,
+	max_x: i32,
+	max_y: i32,
+	max_z: i32,
+}
+
+impl<W: Words> Sides<W>
+{
+	fn around(pixels: &[Vec3]) -> Sides<W>
+	{
+		let max_x = pixels.iter().map(|p| p.x).max().unwrap_or(0);
+		let max_y = pixels.iter().map(|p| p.y).max().unwrap_or(0);
+		let max_z = pixels.iter().map(|p| p.z).max().unwrap_or(0);
+		// Leave room for the steam around the box, and for the far sides of
+		// that steam.
+		let x = max_x as usize + 2;
+		let y = max_y as usize + 2;
+		let z = max_z as usize + 2;
+		Sides {
+			x_planes: vec![BitGrid::new(z, y); x + 1],
+			y_planes: vec![BitGrid::new(x, z); y + 1],
+			z_planes: vec![BitGrid::new(y, x); z + 1],
+			max_x,
+			max_y,
+			max_z,
+		}
+	}
+
+	fn insert(&mut self, p: Vec3)
+	{
+		let (x, y, z) = (p.x as usize, p.y as usize, p.z as usize);
+		self.x_planes[x].toggle_bit((y, z));
+		self.x_planes[x + 1].toggle_bit((y, z));
+		self.y_planes[y].toggle_bit((z, x));
+		self.y_planes[y + 1].toggle_bit((z, x));
+		self.z_planes[z].toggle_bit((x, y));
+		self.z_planes[z + 1].toggle_bit((x, y));
+	}
+
+	fn erase(&mut self, p: Vec3)
+	{
+		let (x, y, z) = (p.x as usize, p.y as usize, p.z as usize);
+		self.x_planes[x].clear_bit((y, z));
+		self.x_planes[x + 1].clear_bit((y, z));
+		self.y_planes[y].clear_bit((z, x));
+		self.y_planes[y + 1].clear_bit((z, x));
+		self.z_planes[z].clear_bit((x, y));
+		self.z_planes[z + 1].clear_bit((x, y));
+	}
+
+	fn erase_exterior(&mut self)
+	{
+		let mut steam = Steam::around(self);
+		// Surround the box with steam.
+		crate::debug!(
+			"bounding box up to {}",
+			Vec3::new(self.max_x, self.max_y, self.max_z)
+		);
+		for x in [0, self.max_x + 1]
+		{
+			for y in 0..=self.max_y
+			{
+				for z in 0..=self.max_z
+				{
+					let side = (y as usize, z as usize);
+					if x > 0 || !self.x_planes[x as usize].get_bit(side)
+					{
+						steam.push(Vec3 { x, y, z });
+					}
+				}
+			}
+		}
+		for y in [0, self.max_y + 1]
+		{
+			for z in 0..=self.max_z
+			{
+				for x in 0..=self.max_x
+				{
+					let side = (z as usize, x as usize);
+					if y > 0 || !self.y_planes[y as usize].get_bit(side)
+					{
+						steam.push(Vec3 { x, y, z });
+					}
+				}
+			}
+		}
+		for z in [0, self.max_z + 1]
+		{
+			for x in 0..=self.max_x
+			{
+				for y in 0..=self.max_y
+				{
+					let side = (x as usize, y as usize);
+					if z > 0 || !self.z_planes[z as usize].get_bit(side)
+					{
+						steam.push(Vec3 { x, y, z });
+					}
+				}
+			}
+		}
+		// Do a floodfill with steam.
+		crate::debug!("{} cubes of steam around the box", steam.stack.len());
+		while let Some(from) = steam.stack.pop()
+		{
+			expand(self, &mut steam, from);
+		}
+		// Erase all the sides that touch steam.
+		self.x_planes[0].clear();
+		self.y_planes[0].clear();
+		self.z_planes[0].clear();
+		for (x, plane) in steam.xyz.iter().enumerate()
+		{
+			for (y, line) in plane.rows().iter().enumerate()
+			{
+				for z in line.ones()
+				{
+					let (x, y, z) = (x as i32, y as i32, z as i32);
+					self.erase(Vec3 { x, y, z });
+				}
+			}
+		}
+	}
+
+	fn count(&self) -> u32
+	{
+		count_planes(&self.x_planes)
+			+ count_planes(&self.y_planes)
+			+ count_planes(&self.z_planes)
+	}
+}
+
+fn count_planes<W: Words>(planes: &[BitGrid<W>]) -> u32
+{
+	planes.iter().map(|plane| plane.count_ones()).sum()
+}
+
+#[derive(Debug)]
+struct Steam<W: Words>
+{
+	xyz: Vec<BitGrid<W>>,
+	stack: Vec<Vec3>,
+}
+
+impl<W: Words> Steam<W>
+{
+	fn around(sides: &Sides<W>) -> Steam<W>
+	{
+		let x = sides.max_x as usize + 2;
+		let y = sides.max_y as usize + 2;
+		let z = sides.max_z as usize + 2;
+		Steam {
+			xyz: vec![BitGrid::new(z, y); x],
+			stack: Vec::new(),
+		}
+	}
+
+	fn push(&mut self, p: Vec3)
+	{
+		let plane = &mut self.xyz[p.x as usize];
+		let side = (p.y as usize, p.z as usize);
+		if plane.get_bit(side)
+		{
+			return;
+		}
+		plane.set_bit(side);
+		self.stack.push(p);
+	}
+}
+
+fn expand<W: Words>(data: &Sides<W>, steam: &mut Steam<W>, p: Vec3)
+{
+	let (x, y, z) = (p.x as usize, p.y as usize, p.z as usize);
+	if p.x > 1 && !data.x_planes[x].get_bit((y, z))
+	{
+		steam.push(Vec3 { x: p.x - 1, ..p });
+	}
+	if p.x < data.max_x && !data.x_planes[x + 1].get_bit((y, z))
+	{
+		steam.push(Vec3 { x: p.x + 1, ..p });
+	}
+	if p.y > 1 && !data.y_planes[y].get_bit((z, x))
+	{
+		steam.push(Vec3 { y: p.y - 1, ..p });
+	}
+	if p.y < data.max_y && !data.y_planes[y + 1].get_bit((z, x))
+	{
+		steam.push(Vec3 { y: p.y + 1, ..p });
+	}
+	if p.z > 1 && !data.z_planes[z].get_bit((x, y))
+	{
+		steam.push(Vec3 { z: p.z - 1, ..p });
+	}
+	if p.z < data.max_z && !data.z_planes[z + 1].get_bit((x, y))
+	{
+		steam.push(Vec3 { z: p.z + 1, ..p });
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+	const CUBE_2X2X2: &str = include_str!("cube_2x2x2.txt");
+	const CUBE_3X3X3: &str = include_str!("cube_3x3x3.txt");
+	const CUBE_WITH_HOLE: &str = include_str!("cube_with_hole.txt");
+	const CATBERT: &str = include_str!("catbert.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 64);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 58);
+	}
+
+	#[test]
+	fn one_cube_pixel()
+	{
+		assert_eq!(one("0,0,0"), 6);
+	}
+
+	#[test]
+	fn two_cube_pixel()
+	{
+		assert_eq!(two("0,0,0"), 6);
+	}
+
+	#[test]
+	fn one_cube_1x1x1()
+	{
+		assert_eq!(one("1,1,1"), 6);
+	}
+
+	#[test]
+	fn two_cube_1x1x1()
+	{
+		assert_eq!(two("1,1,1"), 6);
+	}
+
+	#[test]
+	fn one_cube_2x2x2()
+	{
+		assert_eq!(one(CUBE_2X2X2), 24);
+	}
+
+	#[test]
+	fn two_cube_2x2x2()
+	{
+		assert_eq!(two(CUBE_2X2X2), 24);
+	}
+
+	#[test]
+	fn one_cube_3x3x3()
+	{
+		assert_eq!(one(CUBE_3X3X3), 54);
+	}
+
+	#[test]
+	fn two_cube_3x3x3()
+	{
+		assert_eq!(two(CUBE_3X3X3), 54);
+	}
+
+	#[test]
+	fn one_cube_with_hole()
+	{
+		assert_eq!(one(CUBE_WITH_HOLE), 60);
+	}
+
+	#[test]
+	fn two_cube_with_hole()
+	{
+		assert_eq!(two(CUBE_WITH_HOLE), 54);
+	}
+
+	#[test]
+	fn one_catbert()
+	{
+		assert_eq!(one(CATBERT), 108);
+	}
+
+	#[test]
+	fn two_catbert()
+	{
+		assert_eq!(two(CATBERT), 90);
+	}
+}
@@ -1,26 +1,65 @@
 /**/
 
+use crate::parse::parse_lines;
+use crate::search;
+use crate::search::BranchAndBound;
+use crate::Answer;
+use crate::ParseError;
+
 use smallvec::SmallVec;
 
-const INPUT: &str = include_str!("input.txt");
+pub fn one(input: &str) -> i32
+{
+	sum_of_quality_levels(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> i32
+{
+	product_of_first_three(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Blueprint>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Blueprint>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, blueprints: &Vec<Blueprint>) -> Option<Answer>
+	{
+		Some(sum_of_quality_levels(blueprints).into())
+	}
+
+	fn two(&self, blueprints: &Vec<Blueprint>) -> Option<Answer>
+	{
+		Some(product_of_first_three(blueprints).into())
+	}
+}
 
-pub fn main()
+fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError>
 {
-	println!("Part One: {}", one(INPUT));
-	println!("Part Two: {}", two(INPUT));
+	parse_lines(input)
 }
 
-fn one(input: &str) -> i32
+fn sum_of_quality_levels(blueprints: &[Blueprint]) -> i32
 {
-	let blueprints = input.lines().map(|line| line.parse().unwrap());
-	blueprints.map(determine_quality_level).sum()
+	blueprints
+		.iter()
+		.copied()
+		.map(determine_quality_level)
+		.sum()
 }
 
-fn two(input: &str) -> i32
+fn product_of_first_three(blueprints: &[Blueprint]) -> i32
 {
-	let blueprints = input.lines().take(3).map(|line| line.parse().unwrap());
 	blueprints
-		.map(|blueprint| optimize_num_geodes(blueprint, MAX_TIME_PART_TWO))
+		.iter()
+		.take(3)
+		.map(|&blueprint| optimize_num_geodes(blueprint, MAX_TIME_PART_TWO))
 		.product()
 }
 
@@ -33,7 +72,7 @@ fn two(input: &str) -> i32
 	 Each geode robot costs {geode_robot_ore_cost} ore and \
 	 {geode_robot_obsidian_cost} obsidian."
 )]
-struct Blueprint
+pub struct Blueprint
 {
 	blueprint_id: i32,
 	ore_robot_ore_cost: i32,
@@ -46,8 +85,13 @@ struct Blueprint
 
 fn determine_quality_level(blueprint: Blueprint) -> i32
 {
-	dbg!(blueprint.blueprint_id)
-		* dbg!(optimize_num_geodes(blueprint, MAX_TIME_PART_ONE))
+	let num_geodes = optimize_num_geodes(blueprint, MAX_TIME_PART_ONE);
+	crate::info!(
+		"blueprint {} opens {} geodes",
+		blueprint.blueprint_id,
+		num_geodes
+	);
+	blueprint.blueprint_id * num_geodes
 }
 
 fn optimize_num_geodes(blueprint: Blueprint, time_allowed: i32) -> i32
@@ -55,20 +99,37 @@ fn optimize_num_geodes(blueprint: Blueprint, time_allowed: i32) -> i32
 	let mut starting_state = State::default();
 	starting_state.world.num_ore_robots = 1;
 	starting_state.time_remaining = time_allowed;
-	let mut max_num_geodes = 0;
-	let mut stack = Vec::new();
-	stack.push(starting_state);
-	while let Some(state) = stack.pop()
+	let mut factory = Factory { blueprint };
+	std::cmp::max(search::depth_first(&mut factory, starting_state), 0)
+}
+
+struct Factory
+{
+	blueprint: Blueprint,
+}
+
+impl BranchAndBound for Factory
+{
+	type Node = State;
+
+	fn bound(&self, state: &State) -> i32
 	{
-		//dbg!(&state);
-		let num_geodes =
-			run_simulation(blueprint, state, &mut stack, Some(max_num_geodes));
-		if num_geodes > max_num_geodes
-		{
-			max_num_geodes = num_geodes;
-		}
+		loose_upper_limit(state)
 	}
-	max_num_geodes
+
+	// Follow the strategy until the end, leaving the alternatives for later.
+	fn branch(&mut self, state: State, best: i32, stack: &mut Vec<State>)
+		-> i32
+	{
+		run_simulation(self.blueprint, state, stack, Some(best))
+	}
+}
+
+// As if we could build a geode robot every remaining minute.
+fn loose_upper_limit(state: &State) -> i32
+{
+	let t = state.time_remaining;
+	state.world.geodes + t * state.world.num_geode_robots + (t - 1) * t / 2
 }
 
 const MAX_TIME_PART_ONE: i32 = 24;
@@ -198,11 +259,7 @@ fn run_simulation(
 	{
 		if let Some(max_num_geodes) = max_num_geodes
 		{
-			let t = state.time_remaining;
-			let loose_upper_limit = state.world.geodes
-				+ t * state.world.num_geode_robots
-				+ (t - 1) * t / 2;
-			if loose_upper_limit < max_num_geodes
+			if loose_upper_limit(&state) < max_num_geodes
 			{
 				break;
 			}
@@ -0,0 +1,377 @@
+/**/
+
+use crate::generate::Rng;
+use crate::parse::parse_lines;
+use crate::solution::Variant;
+use crate::Answer;
+use crate::ParseError;
+use crate::Part;
+
+use std::collections::HashSet;
+
+pub fn one(input: &str) -> i64
+{
+	decrypt_once(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> i64
+{
+	decrypt_with_key(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<i64>;
+
+	fn parse(&self, input: &str) -> Result<Vec<i64>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, numbers: &Vec<i64>) -> Option<Answer>
+	{
+		Some(decrypt_once(numbers).into())
+	}
+
+	fn two(&self, numbers: &Vec<i64>) -> Option<Answer>
+	{
+		Some(decrypt_with_key(numbers).into())
+	}
+
+	fn variants(&self) -> Vec<Variant<Vec<i64>>>
+	{
+		vec![
+			Variant {
+				name: "naive",
+				part: Part::One,
+				solve: |numbers| Some(naive_decrypt(numbers, 1, 1).into()),
+			},
+			Variant {
+				name: "naive",
+				part: Part::Two,
+				solve: |numbers| {
+					Some(naive_decrypt(numbers, DECRYPTION_KEY, 10).into())
+				},
+			},
+		]
+	}
+}
+
+// The grove coordinates are counted from the only 0, and a number can only
+// move if there is another one.
+fn parse(input: &str) -> Result<Vec<i64>, ParseError>
+{
+	let numbers: Vec<i64> = parse_lines(input)?;
+	let mut zeros = (0..numbers.len()).filter(|&i| numbers[i] == 0);
+	if zeros.next().is_none()
+	{
+		return Err(ParseError::at_end(input, "missing 0"));
+	}
+	if let Some(i) = zeros.next()
+	{
+		let line = input.lines().nth(i).unwrap_or_default();
+		return Err(ParseError::at_line(i, line, "more than one 0"));
+	}
+	if numbers.len() < 2
+	{
+		return Err(ParseError::at_end(input, "missing another number"));
+	}
+	Ok(numbers)
+}
+
+// With exactly one zero, from where the grove coordinates are counted.
+pub fn generate(rng: &mut Rng, count: usize) -> String
+{
+	let count = count.max(2);
+	let zero = rng.below(count);
+	(0..count)
+		.map(|i| {
+			let number = if i == zero
+			{
+				0
+			}
+			else if rng.chance(0.5)
+			{
+				rng.range(1..=10000)
+			}
+			else
+			{
+				rng.range(-10000..=-1)
+			};
+			format!("{}\n", number)
+		})
+		.collect()
+}
+
+fn decrypt_once(numbers: &[i64]) -> i64
+{
+	let mut data: Vec<i64> = numbers.to_vec();
+	let k = make_unique(&mut data);
+	let commands = data.clone();
+	mix(&mut data, &commands);
+	extract_answer(&data, k)
+}
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+fn decrypt_with_key(numbers: &[i64]) -> i64
+{
+	let mut data: Vec<i64> = numbers.to_vec();
+	for value in data.iter_mut()
+	{
+		*value *= DECRYPTION_KEY;
+	}
+	let k = make_unique(&mut data);
+	let commands = data.clone();
+	for _ in 0..10
+	{
+		mix(&mut data, &commands);
+	}
+	let answer = extract_answer(&data, k);
+	assert_eq!(answer % DECRYPTION_KEY, 0);
+	answer
+}
+
+// Moves each number by removing it and inserting it elsewhere, keeping track
+// of the original order by index instead of making values unique.
+fn naive_decrypt(numbers: &[i64], key: i64, rounds: usize) -> i64
+{
+	let values: Vec<i64> = numbers.iter().map(|x| x * key).collect();
+	let n = values.len();
+	let mut order: Vec<usize> = (0..n).collect();
+	for _ in 0..rounds
+	{
+		for i in 0..n
+		{
+			let from = order.iter().position(|&j| j == i).unwrap();
+			order.remove(from);
+			let to = (from as i64 + values[i]).rem_euclid(n as i64 - 1);
+			order.insert(to as usize, i);
+		}
+	}
+	let zero = order.iter().position(|&j| values[j] == 0).unwrap();
+	[1000, 2000, 3000]
+		.iter()
+		.map(|offset| values[order[(zero + offset) % n]])
+		.sum()
+}
+
+fn mix(data: &mut [i64], commands: &[i64])
+{
+	for value in commands
+	{
+		mix_1(data, *value);
+	}
+}
+
+fn mix_1(data: &mut [i64], value: i64)
+{
+	let n = data.len() - 1;
+	let i = data.iter().position(|v| *v == value).unwrap();
+	if value > 0
+	{
+		let shift: usize = usize::try_from(value).unwrap() % n;
+		if i + shift < n
+		{
+			let from = i;
+			let to = i + shift;
+			data[from..=to].rotate_left(1);
+		}
+		else
+		{
+			let from = (i + shift) % n;
+			let to = i;
+			data[from..=to].rotate_right(1);
+		}
+	}
+	else if value < 0
+	{
+		let shift: usize = usize::try_from(-value).unwrap() % n;
+		if i >= shift
+		{
+			let from = i - shift;
+			let to = i;
+			data[from..=to].rotate_right(1);
+		}
+		else
+		{
+			let from = i;
+			let to = n + i - shift;
+			assert!(to < n);
+			data[from..=to].rotate_left(1);
+		}
+	}
+}
+
+fn make_unique(data: &mut [i64]) -> i64
+{
+	let n = data.len() - 1;
+	// Force uniqueness while keeping equality modulo n.
+	let min_value = data.iter().min().unwrap();
+	let max_value = data.iter().max().unwrap();
+	crate::debug!("values range from {} to {}", min_value, max_value);
+	let largest_value = std::cmp::max(min_value.abs(), max_value.abs());
+	let k = largest_value * i64::try_from(n).unwrap();
+	let mut set: HashSet<i64> = HashSet::new();
+	for value in data.iter_mut()
+	{
+		while !set.insert(*value)
+		{
+			if *value > 0
+			{
+				*value += k;
+			}
+			else if *value < 0
+			{
+				*value -= k;
+			}
+			else
+			{
+				panic!("0 must be unique");
+			}
+		}
+	}
+	crate::debug!("made values unique modulo {}", k);
+	k
+}
+
+fn extract_answer(data: &[i64], k: i64) -> i64
+{
+	let start = data.iter().position(|v| *v == 0).unwrap();
+	let x = data[(start + 1000) % data.len()] % k;
+	let y = data[(start + 2000) % data.len()] % k;
+	let z = data[(start + 3000) % data.len()] % k;
+	crate::info!("grove coordinates {}, {} and {}", x, y, z);
+	x + y + z
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::property::agree;
+	use crate::property::check;
+	use crate::property::shrink_integer;
+	use crate::property::shrink_vec;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+	const DUPLICATE: &str = include_str!("duplicate.txt");
+	const NINE: &str = include_str!("nine.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 3);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 1623178306);
+	}
+
+	#[test]
+	fn one_duplicate()
+	{
+		// 1, 2, -3, 0, 3, -3, -2
+		// 1, 2, -3, -3, 0, 3, -2
+		assert_eq!(one(DUPLICATE), -4);
+	}
+
+	#[test]
+	fn one_nine()
+	{
+		assert_eq!(one(NINE), 0);
+	}
+
+	#[test]
+	fn parse_without_single_zero()
+	{
+		let error = parse("1\n2\n-3\n").unwrap_err();
+		assert_eq!(error, ParseError::new(4, 1, "", "missing 0"));
+		let error = parse("1\n0\n-3\n0\n").unwrap_err();
+		assert_eq!(error, ParseError::new(4, 1, "0", "more than one 0"));
+		let error = parse("0\n").unwrap_err();
+		assert_eq!(error, ParseError::new(2, 1, "", "missing another number"));
+	}
+
+	#[test]
+	fn various_shifts()
+	{
+		assert_eq!(shifted([1, 2, 3], 1), [2, 1, 3]);
+		assert_eq!(shifted([1, 2, 3], 2), [1, 2, 3]);
+		assert_eq!(shifted([1, 2, 3], 3), [1, 3, 2]);
+		assert_eq!(shifted([1, 4, 0], 4), [1, 4, 0]);
+		assert_eq!(shifted([1, 5, 0], 5), [5, 1, 0]);
+		assert_eq!(shifted([1, 6, 0], 6), [1, 6, 0]);
+		assert_eq!(shifted([1, 7, 0], 7), [7, 1, 0]);
+		assert_eq!(shifted([-1, 2, 3], -1), [2, -1, 3]);
+		assert_eq!(shifted([1, -2, 3], -2), [1, -2, 3]);
+		assert_eq!(shifted([1, 2, -3], -3), [1, -3, 2]);
+		assert_eq!(shifted([1, -4, 0], -4), [1, -4, 0]);
+		assert_eq!(shifted([1, -5, 0], -5), [-5, 1, 0]);
+		assert_eq!(shifted([1, -6, 0], -6), [1, -6, 0]);
+		assert_eq!(shifted([1, -7, 0], -7), [-7, 1, 0]);
+	}
+
+	fn shifted<const N: usize>(mut data: [i64; N], value: i64) -> [i64; N]
+	{
+		mix_1(&mut data, value);
+		data
+	}
+
+	// Small numbers in short lists, so that there are many duplicates and
+	// numbers that wrap around more than once.
+	fn random_numbers(rng: &mut Rng) -> Vec<i64>
+	{
+		let count = rng.range(2..=10) as usize;
+		let zero = rng.below(count);
+		(0..count)
+			.map(|i| {
+				if i == zero
+				{
+					0
+				}
+				else
+				{
+					rng.range(1..=12) * rng.pick(&[-1, 1])
+				}
+			})
+			.collect()
+	}
+
+	fn shrink_numbers(numbers: &[i64]) -> Vec<Vec<i64>>
+	{
+		let mut candidates = shrink_vec(numbers, |&x| shrink_integer(x));
+		candidates.retain(|numbers| {
+			numbers.len() >= 2
+				&& numbers.iter().filter(|&&x| x == 0).count() == 1
+		});
+		candidates
+	}
+
+	#[test]
+	fn mixing_matches_reference()
+	{
+		check(
+			500,
+			random_numbers,
+			|numbers| shrink_numbers(numbers),
+			|numbers| {
+				agree(
+					"part one",
+					decrypt_once(numbers),
+					naive_decrypt(numbers, 1, 1),
+				)?;
+				agree(
+					"part two",
+					decrypt_with_key(numbers),
+					naive_decrypt(numbers, DECRYPTION_KEY, 10),
+				)
+			},
+		);
+	}
+}
@@ -1,49 +1,201 @@
 /**/
 
+use crate::parse::parse_line;
+use crate::Answer;
+use crate::ParseError;
+
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::VecDeque;
 
 use vec_drain_where::VecDrainWhereExt;
 
-const INPUT: &str = include_str!("input.txt");
+pub fn one(input: &str) -> i64
+{
+	number_yelled_by_root(&parse(input).unwrap()).unwrap()
+}
+
+pub fn two(input: &str) -> i64
+{
+	number_to_yell(&parse(input).unwrap()).unwrap()
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Monkey>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Monkey>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, monkeys: &Vec<Monkey>) -> Option<Answer>
+	{
+		Some(number_yelled_by_root(monkeys)?.into())
+	}
+
+	fn two(&self, monkeys: &Vec<Monkey>) -> Option<Answer>
+	{
+		Some(number_to_yell(monkeys)?.into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Monkey>, ParseError>
+{
+	let monkeys: Vec<Monkey> = input
+		.lines()
+		.enumerate()
+		.map(|(i, line)| {
+			parse_line(i, line)
+				.map_err(|error| find_invalid_name(i, line).unwrap_or(error))
+		})
+		.collect::<Result<_, _>>()?;
+	check_tree(input, &monkeys)?;
+	Ok(monkeys)
+}
+
+// Both parts start from the root, and part two solves for the human by
+// working back down from the root. That only works if the monkeys that the
+// root waits for form a tree, with the human somewhere in it.
+fn check_tree(input: &str, monkeys: &[Monkey]) -> Result<(), ParseError>
+{
+	let lines: Vec<&str> = input.lines().collect();
+	let root: Name = "root".parse().unwrap();
+	let humn: Name = "humn".parse().unwrap();
+	let mut line_of_monkey: HashMap<Name, usize> = HashMap::new();
+	for (i, monkey) in monkeys.iter().enumerate()
+	{
+		if line_of_monkey.insert(monkey.name, i).is_some()
+		{
+			return Err(ParseError::at_line(i, lines[i], "duplicate monkey"));
+		}
+	}
+	let mut waited_on: HashSet<Name> = HashSet::new();
+	for (i, monkey) in monkeys.iter().enumerate()
+	{
+		let line = lines[i];
+		let (left, right) = match monkey.expr
+		{
+			Expression::Constant(_) => continue,
+			Expression::Operation { op: _, left, right } => (left, right),
+			_ =>
+			{
+				let reason = "expected a number or an operation";
+				return Err(ParseError::at_line(i, line, reason));
+			}
+		};
+		let columns = [MONKEY_NAME_LEN + 3, line.len() - MONKEY_NAME_LEN + 1];
+		for (name, column) in [left, right].into_iter().zip(columns)
+		{
+			let reason = if !line_of_monkey.contains_key(&name)
+			{
+				"no such monkey"
+			}
+			else if name == root || !waited_on.insert(name)
+			{
+				"monkey is already waited on"
+			}
+			else
+			{
+				continue;
+			};
+			return Err(ParseError::new(i + 1, column, line, reason));
+		}
+	}
+	let Some(&i) = line_of_monkey.get(&root)
+	else
+	{
+		return Err(ParseError::at_end(input, "missing root"));
+	};
+	if !matches!(monkeys[i].expr, Expression::Operation { .. })
+	{
+		let reason = "root does not combine two monkeys";
+		return Err(ParseError::at_line(i, lines[i], reason));
+	}
+	let Some(&i) = line_of_monkey.get(&humn)
+	else
+	{
+		return Err(ParseError::at_end(input, "missing humn"));
+	};
+	let mut stack = vec![root];
+	while let Some(name) = stack.pop()
+	{
+		if name == humn
+		{
+			return Ok(());
+		}
+		let monkey = &monkeys[line_of_monkey[&name]];
+		if let Expression::Operation { op: _, left, right } = monkey.expr
+		{
+			stack.extend([left, right]);
+		}
+	}
+	Err(ParseError::at_line(
+		i,
+		lines[i],
+		"root does not wait for humn",
+	))
+}
 
-pub fn main()
+// Points at the first character of a monkey's name that is not a lowercase
+// letter, if that is why the line did not parse.
+fn find_invalid_name(i: usize, line: &str) -> Option<ParseError>
 {
-	println!("Part One: {}", one(INPUT));
-	println!("Part Two: {}", two(INPUT));
+	let mut offset = 0;
+	for word in line.split(' ')
+	{
+		let name = word.strip_suffix(':').unwrap_or(word);
+		let is_number = name.parse::<i64>().is_ok();
+		let is_operator = name.parse::<Operator>().is_ok();
+		if !is_number && !is_operator
+		{
+			if let Err(InvalidName(j)) = name.parse::<Name>()
+			{
+				return Some(ParseError::at_byte(i, offset + j, line));
+			}
+		}
+		offset += word.len() + 1;
+	}
+	None
 }
 
-fn one(input: &str) -> i64
+fn number_yelled_by_root(monkeys: &[Monkey]) -> Option<i64>
 {
-	let monkeys = input.lines().map(|line| line.parse().unwrap());
 	let mut computation = Computation::default();
-	for monkey in monkeys
+	for &monkey in monkeys
 	{
 		computation.handle(monkey);
 		computation.resolve();
 	}
 	let root: Name = "root".parse().unwrap();
-	computation.answer(root).unwrap()
+	computation
+		.answer(root)
+		.filter(|_| !computation.is_inconsistent)
 }
 
-fn two(input: &str) -> i64
+fn number_to_yell(monkeys: &[Monkey]) -> Option<i64>
 {
 	let root: Name = "root".parse().unwrap();
 	let humn: Name = "humn".parse().unwrap();
-	let monkeys = input.lines().map(|line| line.parse().unwrap());
-	let monkeys = monkeys.map(|monkey| correct(monkey, root, humn));
+	let monkeys = monkeys.iter().map(|&monkey| correct(monkey, root, humn));
 	let mut computation = Computation::default();
 	computation.store(root, 0);
+	computation.resolve();
 	for monkey in monkeys
 	{
 		computation.handle(monkey);
 		computation.resolve();
 	}
-	computation.answer(humn).unwrap_or_else(|| {
-		dbg!(&computation);
+	if computation.answer(humn).is_none()
+	{
+		crate::debug!("{:?}", computation);
 		computation.find_resolution_issue();
-		panic!("unresolved");
-	})
+	}
+	computation
+		.answer(humn)
+		.filter(|_| !computation.is_inconsistent)
 }
 
 fn correct(monkey: Monkey, root: Name, humn: Name) -> Monkey
@@ -95,6 +247,9 @@ struct Computation
 	waiting_for_two: Vec<WaitingMonkey>,
 	waiting_for_one: Vec<WaitingMonkey>,
 	resolved_names: VecDeque<Name>,
+	// Whether a monkey would have to yell two different numbers, or one
+	// that is not a whole number or too large.
+	is_inconsistent: bool,
 }
 
 impl Computation
@@ -135,8 +290,7 @@ impl Computation
 		{
 			QueryResult::Resolved { left, right } =>
 			{
-				let answer = calculate(monkey.op, left, right);
-				self.store(monkey.name, answer);
+				self.store_calculated(monkey.name, monkey.op, left, right);
 			}
 			QueryResult::WaitingForLeft | QueryResult::WaitingForRight =>
 			{
@@ -156,8 +310,7 @@ impl Computation
 		{
 			QueryResult::Resolved { left, right } =>
 			{
-				let answer = calculate(monkey.op, left, right);
-				self.store(monkey.name, answer);
+				self.store_calculated(monkey.name, monkey.op, left, right);
 			}
 			QueryResult::WaitingForLeft =>
 			{
@@ -207,8 +360,7 @@ impl Computation
 		{
 			QueryResult::Resolved { left, right } =>
 			{
-				let answer = calculate(monkey.op, left, right);
-				self.store(monkey.name, answer);
+				self.store_calculated(monkey.name, monkey.op, left, right);
 			}
 			_ => unreachable!(),
 		}
@@ -228,8 +380,12 @@ impl Computation
 				{
 					QueryResult::Resolved { left, right } =>
 					{
-						let answer = calculate(waiting.op, left, right);
-						self.store(waiting.name, answer);
+						self.store_calculated(
+							waiting.name,
+							waiting.op,
+							left,
+							right,
+						);
 					}
 					_ => unreachable!(),
 				};
@@ -244,18 +400,33 @@ impl Computation
 
 	fn store(&mut self, name: Name, answer: i64)
 	{
-		let old = self.answers.insert(name.into(), answer);
-		if let Some(old_answer) = old
+		let old = self.answers.insert(name, answer);
+		if old.is_some_and(|old_answer| old_answer != answer)
 		{
-			assert_eq!(old_answer, answer);
+			self.is_inconsistent = true;
 		}
 		self.resolved_names.push_back(name);
 	}
 
+	fn store_calculated(
+		&mut self,
+		name: Name,
+		op: Operator,
+		left: i64,
+		right: i64,
+	)
+	{
+		match calculate(op, left, right)
+		{
+			Some(answer) => self.store(name, answer),
+			None => self.is_inconsistent = true,
+		}
+	}
+
 	fn query(&self, left: Name, right: Name) -> QueryResult
 	{
-		let left = self.answers.get(&left.into()).cloned();
-		let right = self.answers.get(&right.into()).cloned();
+		let left = self.answers.get(&left).cloned();
+		let right = self.answers.get(&right).cloned();
 		match (left, right)
 		{
 			(Some(left), Some(right)) => QueryResult::Resolved { left, right },
@@ -267,7 +438,7 @@ impl Computation
 
 	fn answer(&self, name: Name) -> Option<i64>
 	{
-		self.answers.get(&name.into()).cloned()
+		self.answers.get(&name).cloned()
 	}
 
 	fn find_resolution_issue(&self)
@@ -372,7 +543,7 @@ enum QueryResult
 #[derive(Debug, Default, Clone, Copy)]
 #[derive(parse_display::Display, parse_display::FromStr)]
 #[display("{name}: {expr}")]
-struct Monkey
+pub struct Monkey
 {
 	name: Name,
 	expr: Expression,
@@ -432,14 +603,17 @@ impl Operator
 	}
 }
 
-fn calculate(op: Operator, left: i64, right: i64) -> i64
+fn calculate(op: Operator, left: i64, right: i64) -> Option<i64>
 {
 	match op
 	{
-		Operator::Add => left + right,
-		Operator::Sub => left - right,
-		Operator::Mul => left * right,
-		Operator::Div => left / right,
+		Operator::Add => left.checked_add(right),
+		Operator::Sub => left.checked_sub(right),
+		Operator::Mul => left.checked_mul(right),
+		Operator::Div => left
+			.checked_rem(right)
+			.filter(|&remainder| remainder == 0)
+			.and_then(|_| left.checked_div(right)),
 	}
 }
 
@@ -467,20 +641,26 @@ impl std::fmt::Display for Name
 
 impl std::str::FromStr for Name
 {
-	type Err = std::array::TryFromSliceError;
+	type Err = InvalidName;
 
-	fn from_str(input: &str) -> Result<Name, Self::Err>
+	fn from_str(input: &str) -> Result<Name, InvalidName>
 	{
-		let data: [u8; MONKEY_NAME_LEN] = input.as_bytes().try_into()?;
-		for x in &data
+		let bytes = input.as_bytes();
+		if let Some(j) = bytes.iter().position(|x| !x.is_ascii_lowercase())
 		{
-			let letter: u8 = *x;
-			assert!(letter >= b'a' && letter <= b'z');
+			return Err(InvalidName(j.min(MONKEY_NAME_LEN)));
 		}
+		let data: [u8; MONKEY_NAME_LEN] = bytes
+			.try_into()
+			.map_err(|_| InvalidName(bytes.len().min(MONKEY_NAME_LEN)))?;
 		Ok(Name(data))
 	}
 }
 
+// The offset of the first character that does not belong in a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct InvalidName(usize);
+
 impl From<Name> for u32
 {
 	fn from(name: Name) -> u32
@@ -534,4 +714,38 @@ mod tests
 	{
 		assert_eq!(two(MINI), 50);
 	}
+
+	#[test]
+	fn parse_invalid_name()
+	{
+		let error = parse("root: humn + sjmn\nhUmn: 5\n").unwrap_err();
+		assert_eq!(error, ParseError::new(2, 2, "hUmn: 5", "unexpected 'U'"));
+	}
+
+	#[test]
+	fn parse_broken_tree()
+	{
+		let error = parse(&MINI[..MINI.len() - 10]).unwrap_err();
+		let reason = "no such monkey";
+		assert_eq!(error, ParseError::new(2, 7, "root: humn + sjmn", reason));
+		let input = MINI.replace("sjmn: 50", "sjmn: humn - humn");
+		let reason = "monkey is already waited on";
+		let error = parse(&input).unwrap_err();
+		assert_eq!(error, ParseError::new(1, 14, "sjmn: humn - humn", reason));
+		let input = "root: abcd + abcd\nabcd: 1\n";
+		let error = parse(input).unwrap_err();
+		assert_eq!(error, ParseError::new(1, 14, "root: abcd + abcd", reason));
+	}
+
+	#[test]
+	fn numbers_that_do_not_work_out()
+	{
+		let input = PROVIDED.replace("lfqf: 4", "lfqf: 0");
+		let monkeys = parse(&input).unwrap();
+		assert_eq!(number_yelled_by_root(&monkeys), None);
+		let input = PROVIDED.replace("ljgn: 2", "ljgn: 8");
+		let monkeys = parse(&input).unwrap();
+		assert_eq!(number_yelled_by_root(&monkeys), Some(155));
+		assert_eq!(number_to_yell(&monkeys), None);
+	}
 }
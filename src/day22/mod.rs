@@ -1,61 +1,162 @@
 /**/
 
-const INPUT: &str = include_str!("input.txt");
-const INPUT_RADIUS: usize = 50;
+use crate::grid::Grid;
+use crate::parse::split_at_blank_line;
+use crate::Answer;
+use crate::ParseError;
 
-pub fn main()
+use std::fmt::Write;
+
+pub const INPUT_RADIUS: usize = 50;
+
+const INSTRUCTION_REGEX: &str = "(?P<steps>[0-9]+)|(?P<letter>[LR])";
+
+pub fn one(input: &str, radius: usize) -> i32
 {
-	println!("Part One: {}", one(INPUT, INPUT_RADIUS));
-	println!("Part Two: {}", two(INPUT, INPUT_RADIUS));
+	let notes = parse(input, radius).unwrap();
+	solve(&notes.flat, &notes.instructions)
 }
 
-const INSTRUCTION_REGEX: &str = "(?P<steps>[0-9]+)|(?P<letter>[LR])";
+pub fn two(input: &str, radius: usize) -> i32
+{
+	let notes = parse(input, radius).unwrap();
+	solve(&notes.cube, &notes.instructions)
+}
 
-fn one(input: &str, radius: usize) -> i32
+pub struct Solver
 {
-	let (grid_input, instruction_input) = input.split_once("\n\n").unwrap();
-	one_1(radius, grid_input, instruction_input)
+	pub radius: usize,
 }
 
-fn one_1(radius: usize, grid_input: &str, instruction_input: &str) -> i32
+impl crate::Solution for Solver
 {
-	let puzzle = Puzzle::parse(grid_input, radius, false);
-	solve(puzzle, instruction_input)
+	type Input = Notes;
+
+	fn parse(&self, input: &str) -> Result<Notes, ParseError>
+	{
+		parse(input, self.radius)
+	}
+
+	fn one(&self, notes: &Notes) -> Option<Answer>
+	{
+		Some(solve(&notes.flat, &notes.instructions).into())
+	}
+
+	fn two(&self, notes: &Notes) -> Option<Answer>
+	{
+		Some(solve(&notes.cube, &notes.instructions).into())
+	}
 }
 
-fn solve(puzzle: Puzzle, instruction_input: &str) -> i32
+// The map is folded in two different ways, so keep both.
+pub struct Notes
+{
+	flat: Puzzle,
+	cube: Puzzle,
+	instructions: Vec<Instruction>,
+}
+
+fn parse(input: &str, radius: usize) -> Result<Notes, ParseError>
+{
+	let (grid_input, instruction_input) = split_at_blank_line(input)?;
+	let instructions = parse_instructions(instruction_input)
+		.map_err(|error| error.below(grid_input.lines().count() + 1))?;
+	Ok(Notes {
+		flat: Puzzle::parse(grid_input, radius, false)?,
+		cube: Puzzle::parse(grid_input, radius, true)?,
+		instructions,
+	})
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction
+{
+	Walk(u32),
+	TurnLeft,
+	TurnRight,
+}
+
+fn parse_instructions(
+	instruction_input: &str,
+) -> Result<Vec<Instruction>, ParseError>
 {
-	let mut finger = Finger::default();
 	let instruction_regex = regex::Regex::new(INSTRUCTION_REGEX).unwrap();
-	for captured in instruction_regex.captures_iter(instruction_input)
+	let mut instructions = Vec::new();
+	for (i, line) in instruction_input.lines().enumerate()
+	{
+		let mut end = 0;
+		for captured in instruction_regex.captures_iter(line)
+		{
+			let whole = captured.get(0).unwrap();
+			if whole.start() != end
+			{
+				return Err(ParseError::at_byte(i, end, line));
+			}
+			end = whole.end();
+			let instruction = match captured.name("letter").map(|x| x.as_str())
+			{
+				None =>
+				{
+					let steps_str = whole.as_str();
+					let steps = steps_str.parse().map_err(|_| {
+						ParseError::new(
+							i + 1,
+							whole.start() + 1,
+							line,
+							"too many steps",
+						)
+					})?;
+					Instruction::Walk(steps)
+				}
+				Some("L") => Instruction::TurnLeft,
+				Some(_) => Instruction::TurnRight,
+			};
+			instructions.push(instruction);
+		}
+		if end != line.len()
+		{
+			return Err(ParseError::at_byte(i, end, line));
+		}
+	}
+	Ok(instructions)
+}
+
+fn solve(puzzle: &Puzzle, instructions: &[Instruction]) -> i32
+{
+	let mut finger = Finger::default();
+	// Where the finger has been, drawn on the map as in the puzzle.
+	let mut trail = crate::frames::is_recording().then(|| puzzle.unfold());
+	for instruction in instructions
 	{
-		match captured.name("letter").map(|x| x.as_str())
+		match instruction
 		{
-			None =>
+			Instruction::Walk(steps) =>
 			{
-				let steps_str = captured.name("steps").unwrap().as_str();
-				let steps: u32 = steps_str.parse().unwrap();
-				finger.walk(&puzzle, steps);
+				for _step in 0..*steps
+				{
+					if let Some(trail) = &mut trail
+					{
+						finger.mark(puzzle, trail);
+					}
+					finger.step(puzzle);
+				}
 			}
-			Some("L") =>
+			Instruction::TurnLeft =>
 			{
 				finger.facing = finger.facing.turned_counterclockwise();
 			}
-			Some("R") =>
+			Instruction::TurnRight =>
 			{
 				finger.facing = finger.facing.turned_clockwise();
 			}
-			Some(_) => unreachable!(),
+		}
+		if let Some(trail) = &mut trail
+		{
+			finger.mark(puzzle, trail);
+			crate::frames::record(|| trail.to_string());
 		}
 	}
-	finger.password(&puzzle)
-}
-
-fn two(input: &str, radius: usize) -> i32
-{
-	let (grid_input, instruction_input) = input.split_once("\n\n").unwrap();
-	let puzzle = Puzzle::parse(grid_input, radius, true);
-	solve(puzzle, instruction_input)
+	finger.password(puzzle)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -82,12 +183,16 @@ impl Default for Finger
 
 impl Finger
 {
-	fn walk(&mut self, puzzle: &Puzzle, steps: u32)
+	fn mark(&self, puzzle: &Puzzle, trail: &mut Grid<u8>)
 	{
-		for _step in 0..steps
+		let (r, c) = puzzle.get_absolute_rc(self.side, self.row, self.col);
+		trail[(r - 1, c - 1)] = match self.facing
 		{
-			self.step(puzzle)
-		}
+			Facing::Right => b'>',
+			Facing::Down => b'v',
+			Facing::Left => b'<',
+			Facing::Up => b'^',
+		};
 	}
 
 	fn step(&mut self, puzzle: &Puzzle)
@@ -99,7 +204,7 @@ impl Finger
 			Facing::Left => (self.row, self.col - 1),
 			Facing::Up => (self.row - 1, self.col),
 		};
-		match puzzle.sides[self.side][r][c]
+		match puzzle.sides[self.side][(r, c)]
 		{
 			b'.' =>
 			{
@@ -138,7 +243,7 @@ impl Finger
 					(Facing::Left, Facing::Up) => (max, max + 1 - r),
 					(Facing::Up, Facing::Up) => (max, c),
 				};
-				match puzzle.sides[new_side][new_row][new_col]
+				match puzzle.sides[new_side][(new_row, new_col)]
 				{
 					b'.' =>
 					{
@@ -150,9 +255,12 @@ impl Finger
 					b'#' => (),
 					_ => unreachable!(),
 				}
-				//dbg!(code);
-				//dbg!(self.side);
-				//dbg!(self.facing);
+				crate::trace!(
+					"side {} at {:?} facing {:?}",
+					self.side,
+					(self.row, self.col),
+					self.facing
+				);
 			}
 		}
 	}
@@ -160,7 +268,13 @@ impl Finger
 	fn password(&self, puzzle: &Puzzle) -> i32
 	{
 		let (r, c) = puzzle.get_absolute_rc(self.side, self.row, self.col);
-		dbg!((self.side, self.row, self.col, r, c, self.facing));
+		crate::info!(
+			"ended on side {} at row {} column {} facing {:?}",
+			self.side,
+			r,
+			c,
+			self.facing
+		);
 		1000 * (r as i32) + 4 * (c as i32) + i32::from(u8::from(self.facing))
 	}
 }
@@ -215,43 +329,60 @@ impl From<Facing> for u8
 
 // Each side grid is surrounded by bytes that indicate the wrap-around.
 // This also means we do not have to do any bounds checking.
-const GRID_SIZE: usize = 64;
 const NUM_SIDES: usize = 6;
 
 #[derive(Debug)]
 struct Puzzle
 {
-	sides: [[[u8; GRID_SIZE]; GRID_SIZE]; NUM_SIDES],
+	sides: Vec<Grid<u8>>,
 	chunk_rc_of_side: [(usize, usize); NUM_SIDES],
 	radius: usize,
 }
 
 impl Puzzle
 {
-	fn parse(input: &str, radius: usize, is_cube: bool) -> Puzzle
+	fn parse(
+		input: &str,
+		radius: usize,
+		is_cube: bool,
+	) -> Result<Puzzle, ParseError>
 	{
 		assert!(radius >= 2);
-		assert!(radius + 2 <= GRID_SIZE);
-		let mut sides = [[[0; GRID_SIZE]; GRID_SIZE]; NUM_SIDES];
+		let mut sides = vec![Grid::new(radius + 2, radius + 2, 0); NUM_SIDES];
 		let mut chunk_rc_of_side = [(0, 0); NUM_SIDES];
+		let mut num_sides = 0;
 		for (i, line) in input.lines().enumerate()
 		{
 			let chunk_r = i / radius;
 			let row = 1 + i % radius;
-			assert!(row + 1 < GRID_SIZE);
 			for (chunk_c, chunk) in line.as_bytes().chunks(radius).enumerate()
 			{
 				if chunk.iter().all(|x| *x == b' ')
 				{
 					continue;
 				}
-				assert!(chunk.len() + 2 < GRID_SIZE);
-				let side = chunk_rc_of_side
+				let side = match chunk_rc_of_side[..num_sides]
 					.iter()
-					.position(|&(r, c)| {
-						(r, c) == (chunk_r, chunk_c) || (r, c) == (0, 0)
-					})
-					.unwrap();
+					.position(|&rc| rc == (chunk_r, chunk_c))
+				{
+					Some(side) => side,
+					None if num_sides < NUM_SIDES =>
+					{
+						num_sides += 1;
+						num_sides - 1
+					}
+					None =>
+					{
+						let column = chunk_c * radius + 1;
+						let reason = "too many sides";
+						return Err(ParseError::new(
+							i + 1,
+							column,
+							line,
+							reason,
+						));
+					}
+				};
 				chunk_rc_of_side[side] = (chunk_r, chunk_c);
 				for (j, x) in chunk.iter().enumerate()
 				{
@@ -260,27 +391,57 @@ impl Puzzle
 					match glyph
 					{
 						b' ' => (),
-						b'.' | b'#' => sides[side][row][col] = glyph,
-						_ => unreachable!(),
+						b'.' | b'#' => sides[side][(row, col)] = glyph,
+						_ =>
+						{
+							let j = chunk_c * radius + j;
+							return Err(ParseError::at_byte(i, j, line));
+						}
 					}
 				}
 			}
 		}
-		//dbg!(chunk_rc_of_side);
-		if is_cube
+		crate::debug!("sides at chunks {:?}", chunk_rc_of_side);
+		if num_sides < NUM_SIDES
 		{
-			// Part two: cube.
-			for s in 0..NUM_SIDES
+			return Err(ParseError::at_end(input, "missing sides"));
+		}
+		// Every side has to be a square of the given size.
+		for (s, &(chunk_r, chunk_c)) in chunk_rc_of_side.iter().enumerate()
+		{
+			for row in 1..=radius
 			{
-				// Hardcoded for now:
-				let conf = if radius == 50
+				if let Some(col) =
+					(1..=radius).find(|&col| sides[s][(row, col)] == 0)
 				{
-					&INPUT_CUBE_CONFIGURATION[s]
+					let i = chunk_r * radius + row - 1;
+					let j = chunk_c * radius + col - 1;
+					let line = input.lines().nth(i).unwrap_or_default();
+					return Err(ParseError::at_byte(i, j, line));
 				}
-				else
-				{
-					&PROVIDED_CUBE_CONFIGURATION[s]
-				};
+			}
+		}
+		if is_cube
+		{
+			// Part two: cube. Only the ways that the example and the actual
+			// input are folded are known.
+			let configuration = if chunk_rc_of_side == INPUT_CUBE_NET
+			{
+				&INPUT_CUBE_CONFIGURATION
+			}
+			else if chunk_rc_of_side == PROVIDED_CUBE_NET
+			{
+				&PROVIDED_CUBE_CONFIGURATION
+			}
+			else
+			{
+				let line = input.lines().next().unwrap_or_default();
+				let reason = "cannot fold the map into a cube";
+				return Err(ParseError::at_line(0, line, reason));
+			};
+			for s in 0..NUM_SIDES
+			{
+				let conf = &configuration[s];
 				// Get edges based on determined configuration.
 				let left = conf.left.encode();
 				let right = conf.right.encode();
@@ -288,16 +449,16 @@ impl Puzzle
 				let down = conf.down.encode();
 				for r in 1..=radius
 				{
-					sides[s][r][0] = left;
-					sides[s][r][radius + 1] = right;
+					sides[s][(r, 0)] = left;
+					sides[s][(r, radius + 1)] = right;
 				}
 				for c in 1..=radius
 				{
-					sides[s][0][c] = up;
+					sides[s][(0, c)] = up;
 				}
 				for c in 1..=radius
 				{
-					sides[s][radius + 1][c] = down;
+					sides[s][(radius + 1, c)] = down;
 				}
 			}
 		}
@@ -371,25 +532,47 @@ impl Puzzle
 					.unwrap();
 				for r in 1..=radius
 				{
-					sides[s][r][0] = left;
-					sides[s][r][radius + 1] = right;
+					sides[s][(r, 0)] = left;
+					sides[s][(r, radius + 1)] = right;
 				}
 				for c in 1..=radius
 				{
-					sides[s][0][c] = up;
+					sides[s][(0, c)] = up;
 				}
 				for c in 1..=radius
 				{
-					sides[s][radius + 1][c] = down;
+					sides[s][(radius + 1, c)] = down;
 				}
 			}
 		}
-		dbg_print_sides(&sides, radius);
-		Puzzle {
+		crate::trace!("{}", render_sides(&sides));
+		Ok(Puzzle {
 			sides,
 			chunk_rc_of_side,
 			radius,
+		})
+	}
+
+	// The map as it was given, before it was cut into sides.
+	fn unfold(&self) -> Grid<u8>
+	{
+		let rows = self.chunk_rc_of_side.iter().map(|&(rr, _)| rr).max();
+		let cols = self.chunk_rc_of_side.iter().map(|&(_, cc)| cc).max();
+		let height = (rows.unwrap_or(0) + 1) * self.radius;
+		let width = (cols.unwrap_or(0) + 1) * self.radius;
+		let mut map = Grid::new(width, height, b' ');
+		for (s, side) in self.sides.iter().enumerate()
+		{
+			for row in 1..=self.radius
+			{
+				for col in 1..=self.radius
+				{
+					let (r, c) = self.get_absolute_rc(s, row, col);
+					map[(r - 1, c - 1)] = side[(row, col)];
+				}
+			}
 		}
+		map
 	}
 
 	fn get_absolute_rc(
@@ -440,6 +623,9 @@ impl Edge
 // 34
 // 5
 //
+const INPUT_CUBE_NET: [(usize, usize); NUM_SIDES] =
+	[(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+
 #[rustfmt::skip]
 const INPUT_CUBE_CONFIGURATION: [Configuration; NUM_SIDES] = [
 	Configuration {
@@ -485,6 +671,9 @@ const INPUT_CUBE_CONFIGURATION: [Configuration; NUM_SIDES] = [
 // 123
 //   45
 //
+const PROVIDED_CUBE_NET: [(usize, usize); NUM_SIDES] =
+	[(0, 2), (1, 0), (1, 1), (1, 2), (2, 2), (2, 3)];
+
 #[rustfmt::skip]
 const PROVIDED_CUBE_CONFIGURATION: [Configuration; NUM_SIDES] = [
 	Configuration {
@@ -525,29 +714,27 @@ const PROVIDED_CUBE_CONFIGURATION: [Configuration; NUM_SIDES] = [
 	},
 ];
 
-#[allow(unused)]
-fn dbg_print_sides(
-	sides: &[[[u8; GRID_SIZE]; GRID_SIZE]; NUM_SIDES],
-	radius: usize,
-)
+fn render_sides(sides: &[Grid<u8>]) -> String
 {
-	for s in 0..NUM_SIDES
+	let mut text = String::new();
+	for (s, side) in sides.iter().enumerate()
 	{
-		println!("Side {}:", char::from(b'A' + s as u8));
-		for r in 0..=(radius + 1)
+		writeln!(text, "Side {}:", char::from(b'A' + s as u8)).unwrap();
+		for row in side.rows()
 		{
-			for x in &sides[s][r][0..=(radius + 1)]
+			for x in row
 			{
 				match x
 				{
-					b'.' => print!(" __"),
-					b'#' => print!(" ##"),
-					x => print!(" {:02X}", x),
+					b'.' => text.push_str(" __"),
+					b'#' => text.push_str(" ##"),
+					x => write!(text, " {:02X}", x).unwrap(),
 				}
 			}
-			println!();
+			text.push('\n');
 		}
 	}
+	text
 }
 
 #[cfg(test)]
@@ -560,6 +747,12 @@ mod tests
 	const PROVIDED_GRID: &str = include_str!("provided_grid.txt");
 	const PROVIDED_RADIUS: usize = 4;
 
+	fn one_1(radius: usize, grid_input: &str, instruction_input: &str) -> i32
+	{
+		let puzzle = Puzzle::parse(grid_input, radius, false).unwrap();
+		solve(&puzzle, &parse_instructions(instruction_input).unwrap())
+	}
+
 	#[test]
 	fn one_provided()
 	{
@@ -595,4 +788,20 @@ mod tests
 	{
 		assert_eq!(one_1(PROVIDED_RADIUS, PROVIDED_GRID, "1L1L1L1L"), 1036);
 	}
+
+	#[test]
+	fn parse_wrong_size()
+	{
+		let error = parse(PROVIDED, INPUT_RADIUS).err().unwrap();
+		assert_eq!(error, ParseError::new(13, 1, "", "missing sides"));
+		let truncated: String = PROVIDED_GRID
+			.lines()
+			.take(11)
+			.map(|line| line.to_owned() + "\n")
+			.collect();
+		let error =
+			Puzzle::parse(&truncated, PROVIDED_RADIUS, true).unwrap_err();
+		let reason = "unexpected end of line";
+		assert_eq!(error, ParseError::new(12, 9, "", reason));
+	}
 }
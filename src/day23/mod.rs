@@ -0,0 +1,707 @@
+/**/
+
+use crate::bits::BitRow;
+use crate::bits::Words;
+use crate::generate::Rng;
+use crate::solution::Variant;
+use crate::Answer;
+use crate::ParseError;
+use crate::Part;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+// Instead of east and west, use LESS significant and MORE significant.
+const PROPOSAL_SEQUENCE_LEN: usize = 4;
+const PROPOSAL_SEQUENCE: [u8; PROPOSAL_SEQUENCE_LEN] = [b'N', b'S', b'L', b'M'];
+
+pub fn one(input: &str) -> usize
+{
+	count_empty_spaces_after_ten_rounds(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> usize
+{
+	count_rounds_until_settled(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Grid;
+
+	fn parse(&self, input: &str) -> Result<Grid, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, grid: &Grid) -> Option<Answer>
+	{
+		Some(count_empty_spaces_after_ten_rounds(grid).into())
+	}
+
+	fn two(&self, grid: &Grid) -> Option<Answer>
+	{
+		Some(count_rounds_until_settled(grid).into())
+	}
+
+	fn variants(&self) -> Vec<Variant<Grid>>
+	{
+		vec![
+			Variant {
+				name: "naive",
+				part: Part::One,
+				solve: |grid| Some(naive_diffuse(&grid.elves()).0.into()),
+			},
+			Variant {
+				name: "naive",
+				part: Part::Two,
+				solve: |grid| Some(naive_diffuse(&grid.elves()).1.into()),
+			},
+		]
+	}
+}
+
+fn parse(input: &str) -> Result<Grid, ParseError>
+{
+	Grid::parse(input)
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String
+{
+	(0..size)
+		.map(|_| {
+			let mut row: String =
+				(0..size).map(|_| char::from(*rng.pick(b"#."))).collect();
+			row.push('\n');
+			row
+		})
+		.collect()
+}
+
+fn count_empty_spaces_after_ten_rounds(grid: &Grid) -> usize
+{
+	let mut grid = simulate(grid, 10);
+	grid.collapse();
+	crate::debug!("after ten rounds:\n{}", grid.render());
+	grid.count_empty_spaces()
+}
+
+fn count_rounds_until_settled(grid: &Grid) -> usize
+{
+	let mut grid = simulate(grid, usize::MAX);
+	grid.collapse();
+	crate::debug!("settled after {} rounds:\n{}", grid.round, grid.render());
+	grid.round
+}
+
+// Rows that fit in a few words are much faster to combine, so start out with
+// the narrowest words that fit and widen them as the elves spread out.
+fn simulate(grid: &Grid, max_rounds: usize) -> Grid
+{
+	let mut grid = grid.clone();
+	loop
+	{
+		let is_done;
+		(grid, is_done) =
+			crate::with_words!(grid.capacity(), simulate_in(&grid, max_rounds));
+		if is_done
+		{
+			return grid;
+		}
+		let capacity = grid.capacity() + 10;
+		grid.widen(capacity);
+	}
+}
+
+fn simulate_in<W: Words>(grid: &Grid, max_rounds: usize) -> (Grid, bool)
+{
+	let mut narrow: Grid<W> = grid.repack();
+	let is_done = narrow.run(max_rounds);
+	(narrow.repack(), is_done)
+}
+
+fn propose<W: Words>(
+	proposal: &mut Proposal<W>,
+	current: &BitRow<W>,
+	above: &BitRow<W>,
+	below: &BitRow<W>,
+	proposal_sequence: &[u8; PROPOSAL_SEQUENCE_LEN],
+)
+{
+	if current.is_empty()
+	{
+		return;
+	}
+	let l = current << 1;
+	let m = current >> 1;
+	let above3 = above | (above >> 1) | (above << 1);
+	let below3 = below | (below >> 1) | (below << 1);
+	let diagonal = above | below;
+	let crowded = &l | &m | &above3 | &below3;
+	let happy = current & !&crowded;
+	let mut unhappy = current & &crowded;
+	for proposed_direction in proposal_sequence.iter().copied()
+	{
+		match proposed_direction
+		{
+			b'N' =>
+			{
+				proposal.north = &unhappy & !&above3;
+				unhappy &= &above3;
+			}
+			b'S' =>
+			{
+				proposal.south = &unhappy & !&below3;
+				unhappy &= &below3;
+			}
+			b'L' =>
+			{
+				let blocked = &l | (&diagonal << 1);
+				let from = &unhappy & !&blocked;
+				unhappy &= &blocked;
+				proposal.less = from >> 1;
+			}
+			b'M' =>
+			{
+				let blocked = &m | (&diagonal >> 1);
+				let from = &unhappy & !&blocked;
+				unhappy &= &blocked;
+				proposal.more = from << 1;
+			}
+			_ => unreachable!(),
+		}
+	}
+	proposal.stay = happy | unhappy;
+}
+
+#[derive(Debug, Default, Clone)]
+struct Proposal<W: Words>
+{
+	stay: BitRow<W>,
+	north: BitRow<W>,
+	south: BitRow<W>,
+	less: BitRow<W>,
+	more: BitRow<W>,
+}
+
+impl<W: Words> Proposal<W>
+{
+	fn new(width: usize) -> Proposal<W>
+	{
+		let row = BitRow::new(width);
+		Proposal {
+			stay: row.clone(),
+			north: row.clone(),
+			south: row.clone(),
+			less: row.clone(),
+			more: row,
+		}
+	}
+}
+
+fn resolve_block<W: Words>(
+	blocked: &BitRow<W>,
+	proposed: &mut BitRow<W>,
+	backup: &mut BitRow<W>,
+)
+{
+	let canceled = blocked & &*proposed;
+	*proposed &= !&canceled;
+	*backup |= canceled;
+}
+
+fn resolve_block_l<W: Words>(
+	blocked: &BitRow<W>,
+	proposed: &mut BitRow<W>,
+	backup: &mut BitRow<W>,
+)
+{
+	let canceled = blocked & &*proposed;
+	*proposed &= !&canceled;
+	*backup |= canceled << 1;
+}
+
+fn resolve_block_m<W: Words>(
+	blocked: &BitRow<W>,
+	proposed: &mut BitRow<W>,
+	backup: &mut BitRow<W>,
+)
+{
+	let canceled = blocked & &*proposed;
+	*proposed &= !&canceled;
+	*backup |= canceled >> 1;
+}
+
+// The rows grow by this many columns whenever an elf reaches the edge.
+const EXTRA_COLS: usize = 64;
+
+type Elf = (i32, i32);
+
+// Moves one elf at a time, as described in the puzzle. Returns the number of
+// empty spaces after ten rounds and the first round in which no elf moves.
+fn naive_diffuse(elves: &[Elf]) -> (usize, usize)
+{
+	let mut elves: HashSet<Elf> = elves.iter().copied().collect();
+	let mut empty_after_ten = None;
+	let mut round = 0;
+	loop
+	{
+		if round == 10
+		{
+			empty_after_ten = Some(count_empty(&elves));
+		}
+		round += 1;
+		let mut proposals: HashMap<Elf, Vec<Elf>> = HashMap::new();
+		for &(r, c) in &elves
+		{
+			let is_occupied =
+				|dr: i32, dc: i32| elves.contains(&(r + dr, c + dc));
+			let neighbors = (-1..=1)
+				.flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+				.filter(|&(dr, dc)| (dr, dc) != (0, 0))
+				.filter(|&(dr, dc)| is_occupied(dr, dc))
+				.count();
+			if neighbors == 0
+			{
+				continue;
+			}
+			let target = (0..4).find_map(|i| {
+				let (dr, dc) =
+					[(-1, 0), (1, 0), (0, -1), (0, 1)][(round - 1 + i) % 4];
+				let is_free = (-1..=1).all(|side| {
+					if dr == 0
+					{
+						!is_occupied(side, dc)
+					}
+					else
+					{
+						!is_occupied(dr, side)
+					}
+				});
+				is_free.then_some((r + dr, c + dc))
+			});
+			if let Some(target) = target
+			{
+				proposals.entry(target).or_default().push((r, c));
+			}
+		}
+		let mut has_moved = false;
+		for (target, from) in proposals
+		{
+			if from.len() == 1
+			{
+				elves.remove(&from[0]);
+				elves.insert(target);
+				has_moved = true;
+			}
+		}
+		if !has_moved
+		{
+			let empty_after_ten =
+				empty_after_ten.unwrap_or_else(|| count_empty(&elves));
+			return (empty_after_ten, round);
+		}
+	}
+}
+
+fn count_empty(elves: &HashSet<Elf>) -> usize
+{
+	let rows = elves.iter().map(|&(r, _)| r);
+	let cols = elves.iter().map(|&(_, c)| c);
+	let height = rows.clone().max().unwrap() - rows.min().unwrap() + 1;
+	let width = cols.clone().max().unwrap() - cols.min().unwrap() + 1;
+	(height * width) as usize - elves.len()
+}
+
+#[derive(Clone)]
+pub struct Grid<W: Words = Vec<u64>>
+{
+	// The rows are all equally wide, with room to spare on both sides.
+	data: Vec<BitRow<W>>,
+	width: usize,
+	proposal_sequence: [u8; PROPOSAL_SEQUENCE_LEN],
+	is_active: bool,
+	round: usize,
+}
+
+impl Grid
+{
+	fn parse(input: &str) -> Result<Grid, ParseError>
+	{
+		let width = input.lines().map(|line| line.len()).max().unwrap_or(0);
+		let mut data = Vec::new();
+		for (r, line) in input.lines().enumerate()
+		{
+			let mut row = BitRow::new(width + EXTRA_COLS);
+			for (c, byte) in line.bytes().enumerate()
+			{
+				match byte
+				{
+					b'#' => row.set_bit(c),
+					b'.' => (),
+					_ => return Err(ParseError::at_byte(r, c, line)),
+				}
+			}
+			data.push(row);
+		}
+		// Trailing empty rows do not count towards the height.
+		while data.last().is_some_and(|row| row.is_empty())
+		{
+			data.pop();
+		}
+		if data.is_empty()
+		{
+			return Err(ParseError::at_end(input, "missing elves"));
+		}
+		Ok(Grid {
+			data,
+			width,
+			proposal_sequence: PROPOSAL_SEQUENCE,
+			is_active: true,
+			round: 0,
+		})
+	}
+}
+
+impl<W: Words> Grid<W>
+{
+	fn repack<V: Words>(&self) -> Grid<V>
+	{
+		Grid {
+			data: self.data.iter().map(|row| row.repack()).collect(),
+			width: self.width,
+			proposal_sequence: self.proposal_sequence,
+			is_active: self.is_active,
+			round: self.round,
+		}
+	}
+
+	// Stops early if the rows are too narrow for the elves to spread out,
+	// and returns whether it made it to the last round.
+	fn run(&mut self, max_rounds: usize) -> bool
+	{
+		let count = self.count();
+		while self.is_active && self.round < max_rounds
+		{
+			if !self.expand()
+			{
+				return false;
+			}
+			self.diffuse();
+			self.round += 1;
+			crate::trace!("after round {}:\n{}", self.round, self.render());
+			crate::frames::record(|| self.render());
+			debug_assert_eq!(self.count(), count);
+		}
+		true
+	}
+
+	fn height(&self) -> usize
+	{
+		self.data.len()
+	}
+
+	fn capacity(&self) -> usize
+	{
+		self.data[0].width()
+	}
+
+	// Returns false if the words are too narrow for the rows to grow.
+	fn widen(&mut self, capacity: usize) -> bool
+	{
+		if !W::fits(capacity)
+		{
+			return false;
+		}
+		for row in &mut self.data
+		{
+			row.resize(capacity);
+		}
+		true
+	}
+
+	// Returns false if the rows need to grow but cannot.
+	fn expand(&mut self) -> bool
+	{
+		// Make sure there is room for an empty column at the end, so that no
+		// elf is shifted out of the row.
+		if self.data.iter().any(|row| row.leading_zeros() <= 1)
+			&& !self.widen(self.capacity() + 10)
+		{
+			return false;
+		}
+		// Make sure there is an empty column at the start.
+		if self.data.iter().any(|row| row.trailing_zeros() == 0)
+		{
+			for row in &mut self.data
+			{
+				*row <<= 1;
+			}
+			self.width += 1;
+		}
+		// Add an empty row at the bottom and two at the top, if needed.
+		let empty: BitRow<W> = BitRow::new(self.capacity());
+		if !self.data[0].is_empty()
+		{
+			self.data.insert(0, empty.clone());
+		}
+		if !self.data[self.height() - 1].is_empty()
+		{
+			self.data.push(empty.clone());
+		}
+		if !self.data[self.height() - 2].is_empty()
+		{
+			self.data.push(empty);
+		}
+		true
+	}
+
+	fn diffuse(&mut self)
+	{
+		self.is_active = false;
+		let height = self.height();
+		let capacity = self.capacity();
+		debug_assert!(height >= 3);
+		let mut prev_south: BitRow<W> = BitRow::new(capacity);
+		let mut curr = Proposal::new(capacity);
+		propose(
+			&mut curr,
+			&self.data[1],
+			&self.data[0],
+			&self.data[2],
+			&self.proposal_sequence,
+		);
+		// Elves moving into the top row or into the row below the last one
+		// are not among the arrivals in the loop below.
+		if !curr.north.is_empty()
+		{
+			self.is_active = true;
+		}
+		self.data[0] = std::mem::take(&mut curr.north);
+		let mut next;
+		for r_of_prev in 0..(height - 3)
+		{
+			let r_of_current = r_of_prev + 1;
+			let r_of_next = r_of_prev + 2;
+			next = Proposal::new(capacity);
+			propose(
+				&mut next,
+				&self.data[r_of_next],
+				&self.data[r_of_next - 1],
+				&self.data[r_of_next + 1],
+				&self.proposal_sequence,
+			);
+			// Reddit user /u/dcclct13 noticed that blocks can only occur
+			// between two opposite elves, because they check diagonally.
+			// So we check north/south first and then east/west.
+			let blocked = &prev_south & &next.north;
+			resolve_block(&blocked, &mut prev_south, &mut self.data[r_of_prev]);
+			resolve_block(&blocked, &mut next.north, &mut next.stay);
+			let blocked = &curr.less & &curr.more;
+			resolve_block_l(&blocked, &mut curr.less, &mut curr.stay);
+			resolve_block_m(&blocked, &mut curr.more, &mut curr.stay);
+			let arrived = prev_south | &curr.less | &curr.more | &next.north;
+			if !arrived.is_empty()
+			{
+				self.is_active = true;
+			}
+			self.data[r_of_current] = std::mem::take(&mut curr.stay) | arrived;
+			prev_south = std::mem::take(&mut curr.south);
+			curr = next;
+		}
+		if !prev_south.is_empty()
+		{
+			self.is_active = true;
+		}
+		self.data[height - 2] |= prev_south;
+		debug_assert!(curr.less.is_empty());
+		debug_assert!(curr.stay.is_empty());
+		debug_assert!(curr.more.is_empty());
+		self.proposal_sequence.rotate_left(1);
+	}
+
+	fn collapse(&mut self)
+	{
+		// Trim empty rows.
+		let start = self.data.iter().position(|x| !x.is_empty()).unwrap();
+		let end = self.data.iter().rposition(|x| !x.is_empty()).unwrap() + 1;
+		self.data.truncate(end);
+		self.data.drain(0..start);
+		// Trim empty columns.
+		let mask = self.data[1..]
+			.iter()
+			.fold(self.data[0].clone(), |a, row| a | row);
+		let n = mask.trailing_zeros() as usize;
+		for row in &mut self.data
+		{
+			*row >>= n;
+		}
+		self.width = self.capacity() - n - mask.leading_zeros() as usize;
+	}
+
+	fn count(&self) -> usize
+	{
+		self.data.iter().map(|row| row.count_ones() as usize).sum()
+	}
+
+	fn count_empty_spaces(&self) -> usize
+	{
+		self.width * self.height() - self.count()
+	}
+
+	fn elves(&self) -> Vec<Elf>
+	{
+		self.data
+			.iter()
+			.enumerate()
+			.flat_map(|(r, row)| row.ones().map(move |c| (r as i32, c as i32)))
+			.collect()
+	}
+
+	fn render(&self) -> String
+	{
+		let mut text = String::new();
+		for row in &self.data
+		{
+			text.push_str(&row.render('#', '.')[0..self.width]);
+			text.push('\n');
+		}
+		text
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::property::agree;
+	use crate::property::check;
+	use crate::property::shrink_vec;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+	const MINI: &str = include_str!("mini.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 110);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 20);
+	}
+
+	#[test]
+	fn naive_provided()
+	{
+		let grid = parse(PROVIDED).unwrap();
+		assert_eq!(naive_diffuse(&grid.elves()), (110, 20));
+	}
+
+	#[test]
+	fn one_mini()
+	{
+		assert_eq!(one(MINI), 25);
+	}
+
+	#[test]
+	fn single_row()
+	{
+		assert_eq!(one("##\n"), 2);
+		assert_eq!(two("##\n"), 4);
+	}
+
+	#[test]
+	fn one_assertions()
+	{
+		let _ = one("###\n###\n###\n");
+		let _ = one("###\n#.#\n###\n");
+		let _ = one("##.##\n##.##\n##.##\n");
+		let _ = one(&PROVIDED.repeat(4)[0..200]);
+	}
+
+	#[test]
+	fn widen_rows()
+	{
+		// Leave no room to spare, so that the rows have to grow from one
+		// word into more.
+		let input = generate(&mut Rng::new(23), 50);
+		let roomy = parse(&input).unwrap();
+		let mut cramped = parse(&input).unwrap();
+		assert!(cramped.widen(cramped.width));
+		assert_eq!(cramped.capacity(), 50);
+		assert_eq!(
+			count_empty_spaces_after_ten_rounds(&cramped),
+			count_empty_spaces_after_ten_rounds(&roomy)
+		);
+		assert_eq!(
+			count_rounds_until_settled(&cramped),
+			count_rounds_until_settled(&roomy)
+		);
+		let grid = simulate(&cramped, usize::MAX);
+		assert!(grid.capacity() > 64);
+	}
+
+	fn render(elves: &[Elf]) -> String
+	{
+		let height = elves.iter().map(|&(r, _)| r + 1).max().unwrap_or(0);
+		let width = elves.iter().map(|&(_, c)| c + 1).max().unwrap_or(0);
+		let mut text = String::new();
+		for r in 0..height
+		{
+			for c in 0..width
+			{
+				text.push(if elves.contains(&(r, c)) { '#' } else { '.' });
+			}
+			text.push('\n');
+		}
+		text
+	}
+
+	fn random_elves(rng: &mut Rng) -> Vec<Elf>
+	{
+		let size = rng.range(1..=10) as i32;
+		let density = *rng.pick(&[0.2, 0.5, 0.8]);
+		let mut elves: Vec<Elf> = (0..size)
+			.flat_map(|r| (0..size).map(move |c| (r, c)))
+			.filter(|_| rng.chance(density))
+			.collect();
+		if elves.is_empty()
+		{
+			elves.push((0, 0));
+		}
+		elves
+	}
+
+	fn shrink_elves(elves: &[Elf]) -> Vec<Vec<Elf>>
+	{
+		let mut candidates = shrink_vec(elves, |_| Vec::new());
+		candidates.retain(|elves| !elves.is_empty());
+		candidates
+	}
+
+	#[test]
+	fn diffusion_matches_reference()
+	{
+		check(
+			300,
+			random_elves,
+			|elves| shrink_elves(elves),
+			|elves| {
+				let grid = parse(&render(elves)).unwrap();
+				let (empty_after_ten, num_rounds) = naive_diffuse(elves);
+				agree(
+					"part one",
+					count_empty_spaces_after_ten_rounds(&grid),
+					empty_after_ten,
+				)?;
+				agree("part two", count_rounds_until_settled(&grid), num_rounds)
+			},
+		);
+	}
+}
@@ -0,0 +1,540 @@
+/**/
+
+use crate::bits::BitGrid;
+use crate::bits::BitRow;
+use crate::bits::Words;
+use crate::generate::Rng;
+use crate::grid::Position;
+use crate::search;
+use crate::search::Search;
+use crate::solution::Variant;
+use crate::Answer;
+use crate::ParseError;
+use crate::Part;
+
+pub fn one(input: &str) -> usize
+{
+	time_to_reach_exit(&Grid::parse(input).unwrap()).unwrap()
+}
+
+pub fn two(input: &str) -> usize
+{
+	time_to_fetch_snacks(&Grid::parse(input).unwrap()).unwrap()
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Grid;
+
+	fn parse(&self, input: &str) -> Result<Grid, ParseError>
+	{
+		Grid::parse(input)
+	}
+
+	fn one(&self, grid: &Grid) -> Option<Answer>
+	{
+		Some(time_to_reach_exit(grid)?.into())
+	}
+
+	fn two(&self, grid: &Grid) -> Option<Answer>
+	{
+		Some(time_to_fetch_snacks(grid)?.into())
+	}
+
+	fn variants(&self) -> Vec<Variant<Grid>>
+	{
+		vec![
+			Variant {
+				name: "astar",
+				part: Part::One,
+				solve: |grid| Some(search_for_exit(grid)?.into()),
+			},
+			Variant {
+				name: "astar",
+				part: Part::Two,
+				solve: |grid| Some(search_for_snacks(grid)?.into()),
+			},
+		]
+	}
+}
+
+// A valley about five times as wide as it is high, like the actual input,
+// with no blizzards blowing up or down into the entrance or the exit.
+// Blizzards can block the way for good, so keep trying until there is a
+// valley that the expedition can cross three times.
+pub fn generate(rng: &mut Rng, width: usize) -> String
+{
+	let width = width.max(3);
+	let height = std::cmp::max(width / 5, 3);
+	loop
+	{
+		let input = generate_valley(rng, width, height);
+		let grid = Grid::parse(&input).expect("the valley should be walled in");
+		if time_to_fetch_snacks(&grid).is_some()
+		{
+			return input;
+		}
+	}
+}
+
+fn generate_valley(rng: &mut Rng, width: usize, height: usize) -> String
+{
+	let mut input = String::new();
+	for r in 0..height
+	{
+		for c in 0..width
+		{
+			let is_opening =
+				(r, c) == (0, 1) || (r, c) == (height - 1, width - 2);
+			let is_wall = r == 0 || r == height - 1 || c == 0 || c == width - 1;
+			let glyph = if is_opening
+			{
+				'.'
+			}
+			else if is_wall
+			{
+				'#'
+			}
+			else if !rng.chance(0.3)
+			{
+				'.'
+			}
+			else if c == 1 || c == width - 2
+			{
+				*rng.pick(&['<', '>'])
+			}
+			else
+			{
+				*rng.pick(&['^', 'v', '<', '>'])
+			};
+			input.push(glyph);
+		}
+		input.push('\n');
+	}
+	input
+}
+
+// Blizzards can block the way for good.
+fn time_to_reach_exit(grid: &Grid) -> Option<usize>
+{
+	crate::with_words!(grid.width, cross(grid, 1))
+}
+
+fn time_to_fetch_snacks(grid: &Grid) -> Option<usize>
+{
+	crate::with_words!(grid.width, cross(grid, 3))
+}
+
+// Crosses the valley back and forth, and returns the time of arrival at the
+// end, or None if the blizzards block the way for good.
+fn cross<W: Words>(grid: &Grid, num_crossings: usize) -> Option<usize>
+{
+	let mut grid: Grid<W> = grid.repack();
+	let (mut from, mut to) = (grid.start(), grid.exit());
+	let mut t = 0;
+	for _ in 0..num_crossings
+	{
+		t = grid.travel(from, to, t)?;
+		(from, to) = (to, from);
+	}
+	Some(t)
+}
+
+// Searches for the quickest path one expedition at a time. This is much
+// slower than moving every possible expedition at once.
+fn search_for_exit(grid: &Grid) -> Option<usize>
+{
+	grid.search(grid.start(), grid.exit(), 0)
+}
+
+fn search_for_snacks(grid: &Grid) -> Option<usize>
+{
+	let t = grid.search(grid.start(), grid.exit(), 0)?;
+	let t = grid.search(grid.exit(), grid.start(), t)?;
+	grid.search(grid.start(), grid.exit(), t)
+}
+
+#[derive(Clone)]
+pub struct Grid<W: Words = Vec<u64>>
+{
+	walls: BitGrid<W>,
+	wind_north: BitGrid<W>,
+	wind_south: BitGrid<W>,
+	wind_less: BitGrid<W>,
+	wind_more: BitGrid<W>,
+	presence: BitGrid<W>,
+	width: usize,
+	height: usize,
+}
+
+impl Grid
+{
+	fn parse(input: &str) -> Result<Grid, ParseError>
+	{
+		let first_line = input.lines().next().unwrap_or_default();
+		let width = first_line.len();
+		let height = input.lines().count();
+		if height < 3
+		{
+			return Err(ParseError::at_end(input, "missing rows"));
+		}
+		if width < 3
+		{
+			return Err(ParseError::at_line(0, first_line, "too narrow"));
+		}
+		let mut walls = BitGrid::new(width, height);
+		let mut wind_north = BitGrid::new(width, height);
+		let mut wind_south = BitGrid::new(width, height);
+		let mut wind_less = BitGrid::new(width, height);
+		let mut wind_more = BitGrid::new(width, height);
+		for (r, line) in input.lines().enumerate()
+		{
+			if line.len() != width
+			{
+				return Err(ParseError::at_line(r, line, "uneven width"));
+			}
+			for (c, glyph) in line.bytes().enumerate()
+			{
+				// The valley is walled in, except for the entrance in the top
+				// left and the exit in the bottom right.
+				let is_border =
+					r == 0 || r == height - 1 || c == 0 || c == width - 1;
+				let is_opening =
+					(r, c) == (0, 1) || (r, c) == (height - 1, width - 2);
+				let expected = match (is_opening, is_border)
+				{
+					(true, _) => Some(b'.'),
+					(false, true) => Some(b'#'),
+					(false, false) => None,
+				};
+				if expected.is_some_and(|expected| glyph != expected)
+				{
+					return Err(ParseError::at_byte(r, c, line));
+				}
+				match glyph
+				{
+					b'#' => walls.set_bit((r, c)),
+					b'.' => (),
+					b'^' => wind_north.set_bit((r, c)),
+					b'v' => wind_south.set_bit((r, c)),
+					b'<' => wind_less.set_bit((r, c)),
+					b'>' => wind_more.set_bit((r, c)),
+					_ => return Err(ParseError::at_byte(r, c, line)),
+				}
+			}
+		}
+		Ok(Grid {
+			walls,
+			wind_north,
+			wind_south,
+			wind_less,
+			wind_more,
+			presence: BitGrid::new(width, height),
+			width,
+			height,
+		})
+	}
+
+	// Returns the time of arrival, if there is a way.
+	fn search(&self, from: Position, to: Position, t: usize) -> Option<usize>
+	{
+		let expedition = Expedition {
+			grid: self,
+			goal: to,
+			period: self.period(),
+		};
+		let path = search::astar(&expedition, (from, t % expedition.period))?;
+		Some(t + path.cost as usize)
+	}
+}
+
+impl<W: Words> Grid<W>
+{
+	fn repack<V: Words>(&self) -> Grid<V>
+	{
+		Grid {
+			walls: self.walls.repack(),
+			wind_north: self.wind_north.repack(),
+			wind_south: self.wind_south.repack(),
+			wind_less: self.wind_less.repack(),
+			wind_more: self.wind_more.repack(),
+			presence: self.presence.repack(),
+			width: self.width,
+			height: self.height,
+		}
+	}
+
+	fn start(&self) -> Position
+	{
+		(0, 1)
+	}
+
+	fn exit(&self) -> Position
+	{
+		(self.height - 1, self.width - 2)
+	}
+
+	// Returns the time of arrival, or None if the blizzards block the way
+	// for good.
+	fn travel(
+		&mut self,
+		from: Position,
+		to: Position,
+		t: usize,
+	) -> Option<usize>
+	{
+		self.presence.clear();
+		self.presence.set_bit(from);
+		// Nothing blows through the entrance or the exit, so the expedition
+		// can always wait there. Whatever it can reach is thus reachable
+		// again one period later, and if nothing new is reachable after a
+		// whole period then nothing ever will be.
+		let period = self.period();
+		let mut reachable = self.presence.clone();
+		let mut t = t;
+		let mut num_steps = 0;
+		while !self.presence.get_bit(to)
+		{
+			self.step();
+			t += 1;
+			num_steps += 1;
+			crate::trace!("minute {}:\n{}", t, self.render());
+			crate::frames::record(|| self.render());
+			if num_steps % period == 0
+			{
+				if self.presence == reachable
+				{
+					return None;
+				}
+				reachable = self.presence.clone();
+			}
+		}
+		crate::debug!("reached {:?} after minute {}", to, t);
+		Some(t)
+	}
+
+	fn step(&mut self)
+	{
+		let height = self.height;
+		self.wind_north.rows_mut()[1..(height - 1)].rotate_left(1);
+		self.wind_south.rows_mut()[1..(height - 1)].rotate_right(1);
+		for r in 1..(height - 1)
+		{
+			blow_l(&mut self.wind_less[r]);
+			blow_m(&mut self.wind_more[r]);
+		}
+
+		let mut above: BitRow<W> = BitRow::new(self.width);
+		for r in 0..height
+		{
+			// We can move in four directions or stay still.
+			let current = self.presence[r].clone();
+			self.presence[r] |= above | (&current >> 1) | (&current << 1);
+			if r + 1 < height
+			{
+				let below = self.presence[r + 1].clone();
+				self.presence[r] |= below;
+			}
+			above = current;
+			// The example makes it clear that you can brave the storm by
+			// moving right through it, as long as you don't end up in one.
+			let obstacle = &self.walls[r]
+				| &self.wind_north[r]
+				| &self.wind_south[r]
+				| &self.wind_less[r]
+				| &self.wind_more[r];
+			self.presence[r] &= !obstacle;
+		}
+	}
+
+	// The blizzards are back where they started after this many minutes.
+	fn period(&self) -> usize
+	{
+		let h = self.height.saturating_sub(2).max(1);
+		let w = self.width.saturating_sub(2).max(1);
+		let mut a = h;
+		let mut b = w;
+		while b != 0
+		{
+			(a, b) = (b, a % b);
+		}
+		h / a * w
+	}
+
+	// The blizzards wrap around, so instead of moving them we can look up
+	// where the ones that would be here at time t started out.
+	fn winds_at(&self, (r, c): Position, t: usize) -> [bool; 4]
+	{
+		if r == 0 || r + 1 >= self.height || c == 0 || c + 1 >= self.width
+		{
+			return [false; 4];
+		}
+		let h = self.height - 2;
+		let w = self.width - 2;
+		let (y, x) = (r - 1, c - 1);
+		[
+			self.wind_north.get_bit(((y + t) % h + 1, c)),
+			self.wind_south.get_bit(((y + h - t % h) % h + 1, c)),
+			self.wind_less.get_bit((r, (x + t) % w + 1)),
+			self.wind_more.get_bit((r, (x + w - t % w) % w + 1)),
+		]
+	}
+
+	fn is_clear(&self, position: Position, t: usize) -> bool
+	{
+		!self.walls.get_bit(position)
+			&& self.winds_at(position, t) == [false; 4]
+	}
+
+	fn render(&self) -> String
+	{
+		let mut text = String::new();
+		for r in 0..self.height
+		{
+			for c in 0..self.width
+			{
+				let [n, s, l, m] = self.winds_at((r, c), 0);
+				let num = [n, s, l, m].iter().filter(|&&x| x).count();
+				let glyph = match num
+				{
+					0 if self.walls.get_bit((r, c)) => '#',
+					0 if self.presence.get_bit((r, c)) => 'E',
+					0 => '.',
+					1 if n => '^',
+					1 if s => 'v',
+					1 if l => '<',
+					1 if m => '>',
+					num => char::from_digit(num as u32, 10).unwrap(),
+				};
+				text.push(glyph);
+			}
+			text.push('\n');
+		}
+		text
+	}
+}
+
+// Blizzards that reach a wall reappear on the other side of the valley.
+fn blow_l<W: Words>(wind: &mut BitRow<W>)
+{
+	*wind >>= 1;
+	if wind.get_bit(0)
+	{
+		wind.clear_bit(0);
+		wind.set_bit(wind.width() - 2);
+	}
+}
+
+fn blow_m<W: Words>(wind: &mut BitRow<W>)
+{
+	*wind <<= 1;
+	if wind.get_bit(wind.width() - 1)
+	{
+		wind.clear_bit(wind.width() - 1);
+		wind.set_bit(1);
+	}
+}
+
+struct Expedition<'a>
+{
+	grid: &'a Grid,
+	goal: Position,
+	// Time wraps around, so that the search ends if there is no way through.
+	period: usize,
+}
+
+impl Search for Expedition<'_>
+{
+	type State = (Position, usize);
+
+	fn is_goal(&self, &(position, _t): &(Position, usize)) -> bool
+	{
+		position == self.goal
+	}
+
+	// We can move in four directions or stay still, as long as we do not end
+	// up in a blizzard. The example makes it clear that you can brave the
+	// storm by moving right through it.
+	fn successors(
+		&self,
+		&((r, c), t): &(Position, usize),
+		next: &mut Vec<(Position, usize)>,
+	)
+	{
+		let moves = [
+			(r, c),
+			(r.wrapping_sub(1), c),
+			(r + 1, c),
+			(r, c.wrapping_sub(1)),
+			(r, c + 1),
+		];
+		next.extend(
+			moves
+				.into_iter()
+				.filter(|&position| self.grid.walls.contains(position))
+				.filter(|&position| self.grid.is_clear(position, t + 1))
+				.map(|position| (position, (t + 1) % self.period)),
+		);
+	}
+
+	fn heuristic(&self, &((r, c), _t): &(Position, usize)) -> u32
+	{
+		let (goal_r, goal_c) = self.goal;
+		(r.abs_diff(goal_r) + c.abs_diff(goal_c)) as u32
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 18);
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		assert_eq!(two(PROVIDED), 54);
+	}
+
+	#[test]
+	fn astar_provided()
+	{
+		let grid = Grid::parse(PROVIDED).unwrap();
+		assert_eq!(search_for_exit(&grid), Some(18));
+		assert_eq!(search_for_snacks(&grid), Some(54));
+	}
+
+	#[test]
+	fn parse_invalid_glyph()
+	{
+		let error = ParseError::new(2, 3, "#.?.#", "unexpected '?'");
+		assert_eq!(Grid::parse("#.###\n#.?.#\n###.#\n").err(), Some(error));
+	}
+
+	#[test]
+	fn parse_missing_exit()
+	{
+		let error = ParseError::new(3, 4, "#####", "unexpected '#'");
+		assert_eq!(Grid::parse("#.###\n#...#\n#####\n").err(), Some(error));
+	}
+
+	#[test]
+	fn impassable_valley()
+	{
+		let grid = Grid::parse("#.###\n#.#.#\n###.#\n").unwrap();
+		assert_eq!(time_to_reach_exit(&grid), None);
+		assert_eq!(time_to_fetch_snacks(&grid), None);
+		assert_eq!(search_for_exit(&grid), None);
+		assert_eq!(search_for_snacks(&grid), None);
+	}
+}
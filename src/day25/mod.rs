@@ -1,19 +1,69 @@
 /**/
 
-const INPUT: &str = include_str!("input.txt");
+use crate::generate::Rng;
+use crate::Answer;
+use crate::ParseError;
 
-pub fn main()
+pub fn one(input: &str) -> Snafu
 {
-	println!("Part One: {}", one(INPUT));
+	parse(input).unwrap().into_iter().sum()
 }
 
-fn one(input: &str) -> Snafu
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Snafu>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Snafu>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, numbers: &Vec<Snafu>) -> Option<Answer>
+	{
+		let total: Snafu = numbers.iter().copied().sum();
+		Some(Answer::Text(total.to_string()))
+	}
+
+	// There is no second puzzle on the last day.
+	fn two(&self, _numbers: &Vec<Snafu>) -> Option<Answer>
+	{
+		None
+	}
+
+	fn has_part_two(&self) -> bool
+	{
+		false
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Snafu>, ParseError>
 {
-	input.lines().map(|line| line.parse().unwrap()).sum()
+	input
+		.lines()
+		.enumerate()
+		.map(|(i, line)| {
+			line.parse()
+				.map_err(|InvalidDigit(j)| ParseError::at_byte(i, j, line))
+		})
+		.collect()
+}
+
+// Numbers of up to twenty SNAFU digits, like the actual input.
+pub fn generate(rng: &mut Rng, count: usize) -> String
+{
+	(0..count)
+		.map(|_| {
+			let num_digits = rng.range(1..=20) as u32;
+			let max = (5i64.pow(num_digits) - 1) / 2;
+			format!("{}\n", Snafu(rng.range(1..=max)))
+		})
+		.collect()
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-struct Snafu(i64);
+pub struct Snafu(i64);
 
 impl std::ops::Add<Snafu> for Snafu
 {
@@ -88,12 +138,16 @@ impl std::fmt::Display for Snafu
 
 impl std::str::FromStr for Snafu
 {
-	type Err = std::io::Error;
+	type Err = InvalidDigit;
 
-	fn from_str(input: &str) -> Result<Snafu, std::io::Error>
+	fn from_str(input: &str) -> Result<Snafu, InvalidDigit>
 	{
+		if input.is_empty()
+		{
+			return Err(InvalidDigit(0));
+		}
 		let mut value = 0;
-		for digit in input.chars()
+		for (j, digit) in input.char_indices()
 		{
 			let digit_value = match digit
 			{
@@ -102,7 +156,7 @@ impl std::str::FromStr for Snafu
 				'0' => 0,
 				'-' => -1,
 				'=' => -2,
-				_ => unreachable!(),
+				_ => return Err(InvalidDigit(j)),
 			};
 			value = value * 5 + digit_value;
 		}
@@ -110,6 +164,10 @@ impl std::str::FromStr for Snafu
 	}
 }
 
+// The offset of the first character that is not a SNAFU digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDigit(usize);
+
 #[cfg(test)]
 mod tests
 {
@@ -214,4 +272,11 @@ mod tests
 		let y: Snafu = text.parse().unwrap();
 		assert_eq!(y, x);
 	}
+
+	#[test]
+	fn parse_invalid_digit()
+	{
+		let error = super::parse("1=-0-2\n12131\n").unwrap_err();
+		assert_eq!(error, ParseError::new(2, 4, "12131", "unexpected '3'"));
+	}
 }
@@ -0,0 +1,506 @@
+/**/
+
+use crate::grid::Grid;
+use crate::json;
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+/// How recorded frames are saved: as an animated GIF, as an asciicast for
+/// asciinema, or as a directory with one PPM image per frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(parse_display::Display, parse_display::FromStr)]
+#[display(style = "lowercase")]
+pub enum Format
+{
+	#[default]
+	Gif,
+	Cast,
+	Ppm,
+}
+
+// Each character of a frame becomes a square of this many pixels.
+const SCALE: usize = 4;
+
+// In hundredths of a second, as GIF wants it.
+const FRAME_DELAY: u16 = 10;
+
+/// The frames that a puzzle renders while it runs. Only every so many frames
+/// are kept, to keep long simulations down to a manageable size.
+#[derive(Debug)]
+pub struct Recording
+{
+	frames: Vec<Grid<u8>>,
+	every: usize,
+	num_offered: usize,
+}
+
+impl Recording
+{
+	pub fn new(every: usize) -> Recording
+	{
+		Recording {
+			frames: Vec::new(),
+			every: every.max(1),
+			num_offered: 0,
+		}
+	}
+
+	/// Renders and keeps a frame, unless it is one of those skipped.
+	pub fn offer(&mut self, render: impl FnOnce() -> String)
+	{
+		if self.num_offered.is_multiple_of(self.every)
+		{
+			self.frames.push(from_text(&render()));
+		}
+		self.num_offered += 1;
+	}
+
+	pub fn frames(&self) -> &[Grid<u8>]
+	{
+		&self.frames
+	}
+}
+
+static IS_RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDING: Mutex<Option<Recording>> = Mutex::new(None);
+
+/// Starts recording the frames that the solvers render, keeping every
+/// `every`th frame.
+pub fn start(every: usize)
+{
+	*RECORDING.lock().unwrap() = Some(Recording::new(every));
+	IS_RECORDING.store(true, Ordering::Relaxed);
+}
+
+/// Stops recording, returning what was recorded since the start.
+pub fn stop() -> Option<Recording>
+{
+	IS_RECORDING.store(false, Ordering::Relaxed);
+	RECORDING.lock().unwrap().take()
+}
+
+pub fn is_recording() -> bool
+{
+	IS_RECORDING.load(Ordering::Relaxed)
+}
+
+/// Records a frame if a recording is running. The frame is a text render,
+/// one row per line, and is not rendered at all otherwise.
+pub fn record(render: impl FnOnce() -> String)
+{
+	if !is_recording()
+	{
+		return;
+	}
+	if let Some(recording) = RECORDING.lock().unwrap().as_mut()
+	{
+		recording.offer(render);
+	}
+}
+
+// Lines of different length are padded with spaces.
+fn from_text(text: &str) -> Grid<u8>
+{
+	let width = text.lines().map(str::len).max().unwrap_or(0);
+	let height = text.lines().count();
+	let mut frame = Grid::new(width, height, b' ');
+	for (r, line) in text.lines().enumerate()
+	{
+		for (c, byte) in line.bytes().enumerate()
+		{
+			frame[(r, c)] = byte;
+		}
+	}
+	frame
+}
+
+/// Saves the frames to `path` with the extension of the format added, or
+/// for PPM, to a directory of that name.
+pub fn save(
+	frames: &[Grid<u8>],
+	format: Format,
+	path: &Path,
+) -> std::io::Result<()>
+{
+	match format
+	{
+		Format::Gif =>
+		{
+			let file = std::fs::File::create(path.with_extension("gif"))?;
+			let mut out = std::io::BufWriter::new(file);
+			write_gif(frames, &mut out)?;
+			out.flush()
+		}
+		Format::Cast =>
+		{
+			let file = std::fs::File::create(path.with_extension("cast"))?;
+			let mut out = std::io::BufWriter::new(file);
+			write_asciicast(frames, &mut out)?;
+			out.flush()
+		}
+		Format::Ppm =>
+		{
+			std::fs::create_dir_all(path)?;
+			for (i, frame) in frames.iter().enumerate()
+			{
+				let filename = format!("frame{:05}.ppm", i);
+				let file = std::fs::File::create(path.join(filename))?;
+				let mut out = std::io::BufWriter::new(file);
+				write_ppm(frame, &mut out)?;
+				out.flush()?;
+			}
+			Ok(())
+		}
+	}
+}
+
+fn color(cell: u8) -> [u8; 3]
+{
+	match cell
+	{
+		b' ' | b'.' => [0x0f, 0x0f, 0x23],
+		b'#' => [0xcc, 0xcc, 0xcc],
+		b'|' | b'-' | b'+' | b'=' => [0x66, 0x66, 0x66],
+		b'o' => [0xff, 0xcc, 0x00],
+		b'@' | b'E' | b'H' => [0xff, 0x44, 0x44],
+		b'<' | b'>' | b'^' | b'v' => [0x66, 0xcc, 0xff],
+		b'0'..=b'9' | b'T' => [0xff, 0x99, 0x33],
+		// Anything else gets an arbitrary but fixed color.
+		_ =>
+		{
+			let x = cell.wrapping_mul(157);
+			[x, x.rotate_left(3) | 0x40, x.rotate_left(6)]
+		}
+	}
+}
+
+fn size_of(frames: &[Grid<u8>]) -> (usize, usize)
+{
+	let width = frames.iter().map(Grid::width).max().unwrap_or(0);
+	let height = frames.iter().map(Grid::height).max().unwrap_or(0);
+	(width, height)
+}
+
+/// Writes a binary PPM image, scaled up so that each cell is visible.
+pub fn write_ppm(frame: &Grid<u8>, out: &mut impl Write)
+	-> std::io::Result<()>
+{
+	let width = frame.width() * SCALE;
+	let height = frame.height() * SCALE;
+	write!(out, "P6\n{} {}\n255\n", width, height)?;
+	let mut line = Vec::with_capacity(3 * width);
+	for row in frame.rows()
+	{
+		line.clear();
+		for &cell in row
+		{
+			for _ in 0..SCALE
+			{
+				line.extend(color(cell));
+			}
+		}
+		for _ in 0..SCALE
+		{
+			out.write_all(&line)?;
+		}
+	}
+	Ok(())
+}
+
+/// Writes an asciicast (version 2) that replays the frames in a terminal.
+pub fn write_asciicast(
+	frames: &[Grid<u8>],
+	out: &mut impl Write,
+) -> std::io::Result<()>
+{
+	let (width, height) = size_of(frames);
+	writeln!(
+		out,
+		"{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+		width, height
+	)?;
+	for (i, frame) in frames.iter().enumerate()
+	{
+		let time = f64::from(FRAME_DELAY) / 100.0 * i as f64;
+		// Move to the top left and clear the screen before each frame.
+		let mut data = "\x1b[H\x1b[2J".to_string();
+		let lines: Vec<String> = frame
+			.rows()
+			.map(|row| String::from_utf8_lossy(row).into_owned())
+			.collect();
+		data.push_str(&lines.join("\r\n"));
+		writeln!(out, "[{:.3}, \"o\", \"{}\"]", time, json::escape(&data))?;
+	}
+	Ok(())
+}
+
+/// Writes an animated GIF that loops forever. Frames smaller than the
+/// largest one are padded at the bottom and right.
+pub fn write_gif(
+	frames: &[Grid<u8>],
+	out: &mut impl Write,
+) -> std::io::Result<()>
+{
+	let (width, height) = size_of(frames);
+	let too_large = || {
+		std::io::Error::new(
+			std::io::ErrorKind::InvalidInput,
+			"frames are too large for a GIF",
+		)
+	};
+	let screen_width = u16::try_from(width * SCALE).map_err(|_| too_large())?;
+	let screen_height =
+		u16::try_from(height * SCALE).map_err(|_| too_large())?;
+
+	out.write_all(b"GIF89a")?;
+	out.write_all(&screen_width.to_le_bytes())?;
+	out.write_all(&screen_height.to_le_bytes())?;
+	// A global color table with 256 entries, one for each byte that a cell
+	// can hold, with spaces as the background.
+	out.write_all(&[0xF7, b' ', 0])?;
+	for cell in 0..=255
+	{
+		out.write_all(&color(cell))?;
+	}
+	// Loop forever.
+	out.write_all(b"\x21\xFF\x0BNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+	let mut pixels = Vec::with_capacity(usize::from(screen_width) * 2);
+	for frame in frames
+	{
+		out.write_all(&[0x21, 0xF9, 0x04, 0x00])?;
+		out.write_all(&FRAME_DELAY.to_le_bytes())?;
+		out.write_all(&[0x00, 0x00])?;
+		out.write_all(&[0x2C, 0, 0, 0, 0])?;
+		out.write_all(&screen_width.to_le_bytes())?;
+		out.write_all(&screen_height.to_le_bytes())?;
+		out.write_all(&[0x00])?;
+
+		pixels.clear();
+		for y in 0..usize::from(screen_height)
+		{
+			for x in 0..usize::from(screen_width)
+			{
+				let cell = frame.get((y / SCALE, x / SCALE));
+				pixels.push(cell.copied().unwrap_or(b' '));
+			}
+		}
+		out.write_all(&[GIF_CODE_SIZE])?;
+		for block in compress(&pixels).chunks(255)
+		{
+			out.write_all(&[block.len() as u8])?;
+			out.write_all(block)?;
+		}
+		out.write_all(&[0x00])?;
+	}
+	out.write_all(&[0x3B])
+}
+
+const GIF_CODE_SIZE: u8 = 8;
+const CLEAR_CODE: u16 = 1 << GIF_CODE_SIZE;
+const END_CODE: u16 = CLEAR_CODE + 1;
+const MAX_CODE_BITS: u32 = 12;
+
+// The variable-length LZW compression of GIF, with codes packed starting
+// from the least significant bit. The table starts over when it is full.
+fn compress(pixels: &[u8]) -> Vec<u8>
+{
+	let mut bits = BitWriter::default();
+	let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+	let mut next_code = END_CODE + 1;
+	let mut code_bits = u32::from(GIF_CODE_SIZE) + 1;
+	bits.write(CLEAR_CODE, code_bits);
+	let mut prefix: Option<u16> = None;
+	for &pixel in pixels
+	{
+		let Some(code) = prefix
+		else
+		{
+			prefix = Some(u16::from(pixel));
+			continue;
+		};
+		if let Some(&longer) = table.get(&(code, pixel))
+		{
+			prefix = Some(longer);
+			continue;
+		}
+		bits.write(code, code_bits);
+		if next_code == 1 << MAX_CODE_BITS
+		{
+			bits.write(CLEAR_CODE, code_bits);
+			table.clear();
+			next_code = END_CODE + 1;
+			code_bits = u32::from(GIF_CODE_SIZE) + 1;
+		}
+		else
+		{
+			table.insert((code, pixel), next_code);
+			next_code += 1;
+			if next_code > 1 << code_bits && code_bits < MAX_CODE_BITS
+			{
+				code_bits += 1;
+			}
+		}
+		prefix = Some(u16::from(pixel));
+	}
+	if let Some(code) = prefix
+	{
+		bits.write(code, code_bits);
+	}
+	bits.write(END_CODE, code_bits);
+	bits.finish()
+}
+
+#[derive(Debug, Default)]
+struct BitWriter
+{
+	bytes: Vec<u8>,
+	buffer: u32,
+	num_bits: u32,
+}
+
+impl BitWriter
+{
+	fn write(&mut self, code: u16, num_bits: u32)
+	{
+		self.buffer |= u32::from(code) << self.num_bits;
+		self.num_bits += num_bits;
+		while self.num_bits >= 8
+		{
+			self.bytes.push(self.buffer as u8);
+			self.buffer >>= 8;
+			self.num_bits -= 8;
+		}
+	}
+
+	fn finish(mut self) -> Vec<u8>
+	{
+		if self.num_bits > 0
+		{
+			self.bytes.push(self.buffer as u8);
+		}
+		self.bytes
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	// A straightforward GIF decoder, to check the encoder against.
+	fn decompress(data: &[u8]) -> Vec<u8>
+	{
+		let mut pixels = Vec::new();
+		let mut table: Vec<Vec<u8>> = Vec::new();
+		let mut code_bits = u32::from(GIF_CODE_SIZE) + 1;
+		let mut previous: Option<Vec<u8>> = None;
+		let mut offset = 0usize;
+		loop
+		{
+			let mut code = 0;
+			for i in 0..code_bits as usize
+			{
+				let bit = (data[(offset + i) / 8] >> ((offset + i) % 8)) & 1;
+				code |= usize::from(bit) << i;
+			}
+			offset += code_bits as usize;
+			if code == usize::from(CLEAR_CODE)
+			{
+				table = (0..=255).map(|x| vec![x]).collect();
+				table.push(Vec::new());
+				table.push(Vec::new());
+				code_bits = u32::from(GIF_CODE_SIZE) + 1;
+				previous = None;
+				continue;
+			}
+			if code == usize::from(END_CODE)
+			{
+				return pixels;
+			}
+			let entry = match (table.get(code), &previous)
+			{
+				(Some(entry), _) => entry.clone(),
+				(None, Some(previous)) =>
+				{
+					let mut entry = previous.clone();
+					entry.push(previous[0]);
+					entry
+				}
+				(None, None) => panic!("unknown code {}", code),
+			};
+			if let Some(mut added) = previous.take()
+			{
+				added.push(entry[0]);
+				table.push(added);
+				if table.len() == 1 << code_bits && code_bits < MAX_CODE_BITS
+				{
+					code_bits += 1;
+				}
+			}
+			pixels.extend(&entry);
+			previous = Some(entry);
+		}
+	}
+
+	#[test]
+	fn compress_round_trip()
+	{
+		let mut state = 12345u32;
+		let noisy: Vec<u8> = (0..100000)
+			.map(|_| {
+				state = state.wrapping_mul(1103515245).wrapping_add(12345);
+				b"#. o"[(state >> 16) as usize % 4]
+			})
+			.collect();
+		let repetitive: Vec<u8> = b"#......#".repeat(5000);
+		for pixels in [noisy, repetitive, Vec::new(), vec![b'#']]
+		{
+			let compressed = compress(&pixels);
+			assert_eq!(decompress(&compressed), pixels);
+		}
+	}
+
+	#[test]
+	fn record_every_other_frame()
+	{
+		let mut recording = Recording::new(2);
+		for i in 0..5
+		{
+			recording.offer(|| format!("{}\n#", i));
+		}
+		let frames = recording.frames();
+		assert_eq!(frames.len(), 3);
+		assert_eq!(frames[2].to_string(), "4\n#\n");
+		let mut recording = Recording::new(1);
+		recording.offer(|| "ab\nc\n".to_string());
+		assert_eq!(recording.frames()[0].to_string(), "ab\nc \n");
+	}
+
+	#[test]
+	fn write_frames()
+	{
+		let frames = [from_text("#.\n.#\n"), from_text("\"\\\n")];
+		let mut ppm = Vec::new();
+		write_ppm(&frames[0], &mut ppm).unwrap();
+		assert!(ppm.starts_with(b"P6\n8 8\n255\n"));
+		assert_eq!(ppm.len(), 11 + 8 * 8 * 3);
+		let mut cast = Vec::new();
+		write_asciicast(&frames, &mut cast).unwrap();
+		let expected = "{\"version\": 2, \"width\": 2, \"height\": \
+		                2}\n[0.000, \"o\", \
+		                \"\\u001b[H\\u001b[2J#.\\r\\n.#\"]\n[0.100, \"o\", \
+		                \"\\u001b[H\\u001b[2J\\\"\\\\\"]\n";
+		assert_eq!(String::from_utf8(cast).unwrap(), expected);
+		let mut gif = Vec::new();
+		write_gif(&frames, &mut gif).unwrap();
+		assert!(gif.starts_with(b"GIF89a\x08\x00\x08\x00"));
+		assert_eq!(gif.last(), Some(&0x3B));
+	}
+}
@@ -0,0 +1,183 @@
+/**/
+
+use std::ops::RangeInclusive;
+
+/// A small seeded pseudo-random number generator (SplitMix64). Unlike the
+/// std hashers, it gives the same numbers for the same seed everywhere.
+#[derive(Debug, Clone)]
+pub struct Rng
+{
+	state: u64,
+}
+
+impl Rng
+{
+	pub fn new(seed: u64) -> Rng
+	{
+		Rng { state: seed }
+	}
+
+	pub fn next_u64(&mut self) -> u64
+	{
+		self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		z ^ (z >> 31)
+	}
+
+	/// A number from 0 up to but not including `n`, which must not be zero.
+	pub fn below(&mut self, n: usize) -> usize
+	{
+		assert!(n > 0);
+		((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+	}
+
+	pub fn range(&mut self, range: RangeInclusive<i64>) -> i64
+	{
+		let (low, high) = range.into_inner();
+		let width = (high - low) as u64 + 1;
+		low + ((u128::from(self.next_u64()) * u128::from(width)) >> 64) as i64
+	}
+
+	/// True with the given probability.
+	pub fn chance(&mut self, probability: f64) -> bool
+	{
+		((self.next_u64() >> 11) as f64) < probability * (1u64 << 53) as f64
+	}
+
+	pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T
+	{
+		&items[self.below(items.len())]
+	}
+
+	pub fn shuffle<T>(&mut self, items: &mut [T])
+	{
+		for i in (1..items.len()).rev()
+		{
+			items.swap(i, self.below(i + 1));
+		}
+	}
+}
+
+/// Writes random but valid inputs for a puzzle, where the scale is roughly
+/// the number of lines or the width of the grid.
+pub struct Generator
+{
+	pub name: &'static str,
+	// About the size of the actual puzzle input.
+	pub default_scale: usize,
+	pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator
+{
+	pub fn generate(&self, seed: u64, scale: usize) -> String
+	{
+		(self.generate)(&mut Rng::new(seed), scale)
+	}
+}
+
+macro_rules! generator {
+	($day:ident, $default_scale:expr) => {
+		Generator {
+			name: stringify!($day),
+			default_scale: $default_scale,
+			generate: crate::$day::generate,
+		}
+	};
+}
+
+pub static GENERATORS: [Generator; 11] = [
+	generator!(day01, 250),
+	generator!(day09, 2000),
+	generator!(day13, 150),
+	generator!(day14, 150),
+	generator!(day16, 60),
+	generator!(day17, 10091),
+	generator!(day18, 2800),
+	generator!(day20, 5000),
+	generator!(day23, 73),
+	generator!(day24, 120),
+	generator!(day25, 120),
+];
+
+/// Finds the generator for a puzzle, by name or day number.
+pub fn find_generator(query: &str) -> Option<&'static Generator>
+{
+	let puzzle = crate::find_puzzle(query)?;
+	GENERATORS
+		.iter()
+		.find(|generator| generator.name == puzzle.name)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::Part;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn random_numbers_stay_in_range()
+	{
+		let mut rng = Rng::new(2022);
+		let mut counts = [0; 6];
+		for _ in 0..6000
+		{
+			counts[rng.below(6)] += 1;
+			let x = rng.range(-3..=3);
+			assert!((-3..=3).contains(&x));
+		}
+		assert!(counts.iter().all(|&count| count > 900), "{:?}", counts);
+		assert!(!rng.chance(0.0));
+		assert!(rng.chance(1.0));
+		let mut items = [1, 2, 3, 4, 5];
+		rng.shuffle(&mut items);
+		items.sort();
+		assert_eq!(items, [1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn same_seed_same_input()
+	{
+		for generator in GENERATORS.iter()
+		{
+			assert_eq!(
+				generator.generate(7, 20),
+				generator.generate(7, 20),
+				"{}",
+				generator.name
+			);
+			assert_ne!(generator.generate(7, 20), generator.generate(8, 20));
+		}
+	}
+
+	#[test]
+	fn generated_inputs_can_be_solved()
+	{
+		for generator in GENERATORS.iter()
+		{
+			let puzzle = crate::find_puzzle(generator.name).unwrap();
+			for seed in 0..3
+			{
+				let input = generator.generate(seed, 20);
+				let parsed = puzzle.parse(&input).unwrap_or_else(|error| {
+					panic!("{} with seed {}: {}", generator.name, seed, error)
+				});
+				for part in [Part::One, Part::Two]
+				{
+					let name = crate::solution::DEFAULT_VARIANT;
+					assert_eq!(
+						puzzle.solve(part, &parsed).is_some(),
+						puzzle.has_variant(name, part),
+						"{} part {} with seed {}",
+						generator.name,
+						part,
+						seed
+					);
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,313 @@
+/**/
+
+use crate::ParseError;
+
+/// A row and column, counting from the top left.
+pub type Position = (usize, usize);
+
+/// Offsets to the neighbours above, right, below and left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets to all eight neighbours, clockwise from above.
+pub const ALL_AROUND: [(isize, isize); 8] = [
+	(-1, 0),
+	(-1, 1),
+	(0, 1),
+	(1, 1),
+	(1, 0),
+	(1, -1),
+	(0, -1),
+	(-1, -1),
+];
+
+/// A dynamically sized two-dimensional grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T>
+{
+	data: Vec<T>,
+	width: usize,
+	height: usize,
+}
+
+impl<T> Grid<T>
+{
+	pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+	where
+		T: Clone,
+	{
+		Grid {
+			data: vec![value; width * height],
+			width,
+			height,
+		}
+	}
+
+	/// Parses one row per line, with one cell per byte. Lines must be of
+	/// equal length, and `f` returns None for bytes that are not allowed.
+	pub fn parse_with(
+		input: &str,
+		mut f: impl FnMut(u8) -> Option<T>,
+	) -> Result<Grid<T>, ParseError>
+	{
+		let width = input.lines().next().unwrap_or_default().len();
+		let mut data = Vec::with_capacity(input.len());
+		let mut height = 0;
+		for (i, line) in input.lines().enumerate()
+		{
+			if line.len() != width
+			{
+				let j = line.len().min(width);
+				return Err(ParseError::new(
+					i + 1,
+					j + 1,
+					line,
+					"uneven width",
+				));
+			}
+			for (j, byte) in line.bytes().enumerate()
+			{
+				let value =
+					f(byte).ok_or_else(|| ParseError::at_byte(i, j, line))?;
+				data.push(value);
+			}
+			height += 1;
+		}
+		if width == 0
+		{
+			return Err(ParseError::at_end(input, "missing grid"));
+		}
+		Ok(Grid {
+			data,
+			width,
+			height,
+		})
+	}
+
+	pub fn width(&self) -> usize
+	{
+		self.width
+	}
+
+	pub fn height(&self) -> usize
+	{
+		self.height
+	}
+
+	pub fn contains(&self, (r, c): Position) -> bool
+	{
+		r < self.height && c < self.width
+	}
+
+	pub fn get(&self, (r, c): Position) -> Option<&T>
+	{
+		if self.contains((r, c))
+		{
+			Some(&self.data[r * self.width + c])
+		}
+		else
+		{
+			None
+		}
+	}
+
+	pub fn get_mut(&mut self, (r, c): Position) -> Option<&mut T>
+	{
+		if self.contains((r, c))
+		{
+			Some(&mut self.data[r * self.width + c])
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Moves from a position by an offset, unless that leaves the grid.
+	pub fn offset(
+		&self,
+		(r, c): Position,
+		(dr, dc): (isize, isize),
+	) -> Option<Position>
+	{
+		let r = r.checked_add_signed(dr)?;
+		let c = c.checked_add_signed(dc)?;
+		Some((r, c)).filter(|&position| self.contains(position))
+	}
+
+	/// The up to four orthogonal neighbours that lie inside the grid.
+	pub fn neighbours4(
+		&self,
+		position: Position,
+	) -> impl Iterator<Item = Position> + '_
+	{
+		ORTHOGONAL
+			.iter()
+			.filter_map(move |&offset| self.offset(position, offset))
+	}
+
+	/// The up to eight neighbours, including diagonals, inside the grid.
+	pub fn neighbours8(
+		&self,
+		position: Position,
+	) -> impl Iterator<Item = Position> + '_
+	{
+		ALL_AROUND
+			.iter()
+			.filter_map(move |&offset| self.offset(position, offset))
+	}
+
+	pub fn row(&self, r: usize) -> &[T]
+	{
+		&self.data[r * self.width..(r + 1) * self.width]
+	}
+
+	pub fn rows(&self) -> std::slice::Chunks<'_, T>
+	{
+		self.data.chunks(self.width)
+	}
+
+	pub fn column(&self, c: usize) -> impl DoubleEndedIterator<Item = &T>
+	{
+		assert!(c < self.width);
+		self.data.iter().skip(c).step_by(self.width)
+	}
+
+	/// All positions, row by row.
+	pub fn positions(&self) -> impl Iterator<Item = Position>
+	{
+		let width = self.width;
+		(0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+	}
+
+	pub fn iter(&self) -> std::slice::Iter<'_, T>
+	{
+		self.data.iter()
+	}
+
+	pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T>
+	{
+		self.data.iter_mut()
+	}
+
+	/// Finds the position of the first cell, row by row, that matches.
+	pub fn position(&self, f: impl FnMut(&T) -> bool) -> Option<Position>
+	{
+		let i = self.data.iter().position(f)?;
+		Some((i / self.width, i % self.width))
+	}
+
+	pub fn fill_row(&mut self, r: usize, value: T)
+	where
+		T: Clone,
+	{
+		let width = self.width;
+		self.data[r * width..(r + 1) * width].fill(value);
+	}
+
+	pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U>
+	{
+		Grid {
+			data: self.data.iter().map(f).collect(),
+			width: self.width,
+			height: self.height,
+		}
+	}
+
+	/// Renders one line per row, with every row ending in a newline.
+	pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String
+	{
+		let mut text = String::with_capacity(self.height * (self.width + 1));
+		for row in self.rows()
+		{
+			text.extend(row.iter().map(&mut f));
+			text.push('\n');
+		}
+		text
+	}
+}
+
+impl Grid<u8>
+{
+	/// Parses one row per line, keeping the bytes as they are.
+	pub fn parse(input: &str) -> Result<Grid<u8>, ParseError>
+	{
+		Grid::parse_with(input, Some)
+	}
+}
+
+impl<T> std::ops::Index<Position> for Grid<T>
+{
+	type Output = T;
+
+	fn index(&self, (r, c): Position) -> &T
+	{
+		assert!(c < self.width);
+		&self.data[r * self.width + c]
+	}
+}
+
+impl<T> std::ops::IndexMut<Position> for Grid<T>
+{
+	fn index_mut(&mut self, (r, c): Position) -> &mut T
+	{
+		assert!(c < self.width);
+		&mut self.data[r * self.width + c]
+	}
+}
+
+impl std::fmt::Display for Grid<u8>
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		write!(f, "{}", self.render(|&x| char::from(x)))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const SAMPLE: &str = "abc\ndef\n";
+
+	#[test]
+	fn parse_and_render()
+	{
+		let grid = Grid::parse(SAMPLE).unwrap();
+		assert_eq!(grid.width(), 3);
+		assert_eq!(grid.height(), 2);
+		assert_eq!(grid[(1, 0)], b'd');
+		assert_eq!(grid.get((2, 0)), None);
+		assert_eq!(grid.row(1), b"def");
+		assert_eq!(grid.column(2).copied().collect::<Vec<u8>>(), b"cf");
+		assert_eq!(grid.position(|&x| x == b'e'), Some((1, 1)));
+		assert_eq!(grid.to_string(), SAMPLE);
+	}
+
+	#[test]
+	fn parse_uneven_width()
+	{
+		let error = Grid::parse("abc\nde\n").unwrap_err();
+		assert_eq!(error, ParseError::new(2, 3, "de", "uneven width"));
+	}
+
+	#[test]
+	fn parse_with_invalid_byte()
+	{
+		let digit = |x: u8| Some(x).filter(u8::is_ascii_digit);
+		let error = Grid::parse_with("123\n4x6\n", digit).unwrap_err();
+		assert_eq!(error, ParseError::new(2, 2, "4x6", "unexpected 'x'"));
+	}
+
+	#[test]
+	fn neighbours_stay_inside()
+	{
+		let grid = Grid::new(3, 2, 0u8);
+		let corner: Vec<Position> = grid.neighbours4((0, 0)).collect();
+		assert_eq!(corner, vec![(0, 1), (1, 0)]);
+		let middle: Vec<Position> = grid.neighbours8((0, 1)).collect();
+		assert_eq!(middle, vec![(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]);
+		assert_eq!(grid.offset((1, 2), (0, 1)), None);
+	}
+}
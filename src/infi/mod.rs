@@ -0,0 +1,180 @@
+/**/
+
+use crate::grid::Grid;
+use crate::parse::parse_lines;
+use crate::vector::Heading;
+use crate::vector::Vec2;
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> i32
+{
+	distance_walked(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> String
+{
+	draw_in_snow(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<Instruction>;
+
+	fn parse(&self, input: &str) -> Result<Vec<Instruction>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, instructions: &Vec<Instruction>) -> Option<Answer>
+	{
+		Some(distance_walked(instructions).into())
+	}
+
+	fn two(&self, instructions: &Vec<Instruction>) -> Option<Answer>
+	{
+		Some(Answer::from_art(draw_in_snow(instructions), ' '))
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError>
+{
+	parse_lines(input)
+}
+
+fn distance_walked(instructions: &[Instruction]) -> i32
+{
+	let mut santa = Santa::new();
+	for &instruction in instructions
+	{
+		santa.follow(instruction);
+	}
+	santa.position.manhattan_distance(Vec2::ZERO)
+}
+
+#[derive(
+	Debug, Clone, Copy, parse_display::Display, parse_display::FromStr,
+)]
+pub enum Instruction
+{
+	#[display("draai {clockwise_angle}")]
+	Turn
+	{
+		clockwise_angle: i32
+	},
+	#[display("loop {distance}")]
+	Walk
+	{
+		distance: i32
+	},
+	#[display("spring {distance}")]
+	Jump
+	{
+		distance: i32
+	},
+}
+
+#[derive(Debug)]
+struct Santa
+{
+	position: Vec2,
+	heading: Heading,
+	footprints: Vec<Vec2>,
+}
+
+impl Santa
+{
+	fn new() -> Santa
+	{
+		Santa {
+			position: Vec2::ZERO,
+			heading: Heading::North,
+			footprints: Vec::new(),
+		}
+	}
+
+	fn follow(&mut self, instruction: Instruction)
+	{
+		match instruction
+		{
+			Instruction::Turn { clockwise_angle } =>
+			{
+				self.heading =
+					self.heading.turn_clockwise(clockwise_angle / 45);
+			}
+			Instruction::Walk { distance } =>
+			{
+				let offset = self.heading.offset();
+				if distance >= 1
+				{
+					for i in 1..=distance
+					{
+						self.step(self.position + offset * i);
+					}
+				}
+				else
+				{
+					for i in distance..0
+					{
+						self.step(self.position + offset * i);
+					}
+				}
+				self.position += offset * distance;
+			}
+			Instruction::Jump { distance } =>
+			{
+				self.position += self.heading.offset() * distance;
+				self.step(self.position);
+			}
+		}
+	}
+
+	fn step(&mut self, position: Vec2)
+	{
+		self.footprints.push(position);
+	}
+}
+
+// Leave some untouched snow around the footprints.
+const MARGIN: i32 = 1;
+
+fn draw_in_snow(instructions: &[Instruction]) -> String
+{
+	let mut santa = Santa::new();
+	for &instruction in instructions
+	{
+		santa.follow(instruction);
+	}
+	let footprints = &santa.footprints;
+	let min_x = footprints.iter().map(|p| p.x).min().unwrap_or(0) - MARGIN;
+	let max_x = footprints.iter().map(|p| p.x).max().unwrap_or(0) + MARGIN;
+	let min_y = footprints.iter().map(|p| p.y).min().unwrap_or(0) - MARGIN;
+	let max_y = footprints.iter().map(|p| p.y).max().unwrap_or(0) + MARGIN;
+	let width = (max_x - min_x + 1) as usize;
+	let height = (max_y - min_y + 1) as usize;
+	let mut snow = Grid::new(width, height, b'#');
+	let corner = Vec2::new(min_x, min_y);
+	for &footprint in footprints
+	{
+		let pos = (footprint - corner).to_position().unwrap();
+		snow[pos] = b' ';
+	}
+	snow.to_string()
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		assert_eq!(one(PROVIDED), 12);
+	}
+}
@@ -0,0 +1,121 @@
+/**/
+
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Environment variable naming a directory that holds the puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source
+{
+	File(PathBuf),
+	Stdin,
+	// Either "<dir>/day16.txt" or "<dir>/day16/input.txt".
+	Directory(PathBuf),
+}
+
+impl Source
+{
+	/// Parses the value of an `--input` argument, where "-" means stdin.
+	pub fn from_arg(arg: &str) -> Source
+	{
+		match arg
+		{
+			"-" => Source::Stdin,
+			path => Source::File(PathBuf::from(path)),
+		}
+	}
+
+	/// Uses the directory from `AOC_INPUT_DIR` if it is set, and otherwise
+	/// the `input.txt` files next to the solvers in the source tree.
+	pub fn from_env() -> Source
+	{
+		match std::env::var_os(INPUT_DIR_VAR)
+		{
+			Some(dir) if !dir.is_empty() => Source::Directory(dir.into()),
+			_ => Source::Directory(default_dir()),
+		}
+	}
+
+	pub fn load(&self, name: &str) -> Result<String, std::io::Error>
+	{
+		match self
+		{
+			Source::File(path) => read_file(path),
+			Source::Stdin =>
+			{
+				let mut input = String::new();
+				std::io::stdin().read_to_string(&mut input)?;
+				Ok(input)
+			}
+			Source::Directory(dir) => read_file(&input_path(dir, name)),
+		}
+	}
+
+	/// Like `load`, but for reading the input a line at a time.
+	pub fn open(&self, name: &str) -> Result<Box<dyn BufRead>, std::io::Error>
+	{
+		match self
+		{
+			Source::File(path) => open_file(path),
+			Source::Stdin => Ok(Box::new(std::io::stdin().lock())),
+			Source::Directory(dir) => open_file(&input_path(dir, name)),
+		}
+	}
+}
+
+fn input_path(dir: &Path, name: &str) -> PathBuf
+{
+	let flat = dir.join(format!("{}.txt", name));
+	if flat.is_file()
+	{
+		flat
+	}
+	else
+	{
+		dir.join(name).join("input.txt")
+	}
+}
+
+impl std::fmt::Display for Source
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		match self
+		{
+			Source::File(path) => write!(f, "{}", path.display()),
+			Source::Stdin => write!(f, "stdin"),
+			Source::Directory(dir) => write!(f, "{}", dir.display()),
+		}
+	}
+}
+
+/// The directory of the solvers in the source tree.
+pub fn default_dir() -> PathBuf
+{
+	Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+fn read_file(path: &Path) -> Result<String, std::io::Error>
+{
+	std::fs::read_to_string(path).map_err(|error| cannot_read(path, error))
+}
+
+fn open_file(path: &Path) -> Result<Box<dyn BufRead>, std::io::Error>
+{
+	let file =
+		std::fs::File::open(path).map_err(|error| cannot_read(path, error))?;
+	Ok(Box::new(BufReader::new(file)))
+}
+
+fn cannot_read(path: &Path, error: std::io::Error) -> std::io::Error
+{
+	std::io::Error::new(
+		error.kind(),
+		format!("cannot read {}: {}", path.display(), error),
+	)
+}
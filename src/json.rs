@@ -0,0 +1,155 @@
+/**/
+
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value
+{
+	Null,
+	Bool(bool),
+	Number(i128),
+	Float(f64),
+	String(String),
+}
+
+impl std::fmt::Display for Value
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		match self
+		{
+			Value::Null => write!(f, "null"),
+			Value::Bool(x) => write!(f, "{}", x),
+			Value::Number(x) => write!(f, "{}", x),
+			// JSON has no infinity or NaN.
+			Value::Float(x) if !x.is_finite() => write!(f, "null"),
+			Value::Float(x) => write!(f, "{}", x),
+			Value::String(x) => write!(f, "\"{}\"", escape(x)),
+		}
+	}
+}
+
+impl From<bool> for Value
+{
+	fn from(x: bool) -> Value
+	{
+		Value::Bool(x)
+	}
+}
+
+impl From<i64> for Value
+{
+	fn from(x: i64) -> Value
+	{
+		Value::Number(x.into())
+	}
+}
+
+impl From<usize> for Value
+{
+	fn from(x: usize) -> Value
+	{
+		Value::Number(x as i128)
+	}
+}
+
+impl From<f64> for Value
+{
+	fn from(x: f64) -> Value
+	{
+		Value::Float(x)
+	}
+}
+
+// In nanoseconds.
+impl From<Duration> for Value
+{
+	fn from(duration: Duration) -> Value
+	{
+		Value::Number(duration.as_nanos() as i128)
+	}
+}
+
+impl From<&str> for Value
+{
+	fn from(x: &str) -> Value
+	{
+		Value::String(x.to_string())
+	}
+}
+
+impl From<String> for Value
+{
+	fn from(x: String) -> Value
+	{
+		Value::String(x)
+	}
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value
+{
+	fn from(x: Option<T>) -> Value
+	{
+		x.map_or(Value::Null, Into::into)
+	}
+}
+
+/// A JSON object on a single line, with the fields in the given order.
+pub fn object(fields: &[(&str, Value)]) -> String
+{
+	let fields: Vec<String> = fields
+		.iter()
+		.map(|(key, value)| format!("\"{}\":{}", escape(key), value))
+		.collect();
+	format!("{{{}}}", fields.join(","))
+}
+
+/// Escapes the contents of a JSON string, without the quotes.
+pub fn escape(text: &str) -> String
+{
+	let mut escaped = String::with_capacity(text.len());
+	for x in text.chars()
+	{
+		match x
+		{
+			'"' => escaped.push_str("\\\""),
+			'\\' => escaped.push_str("\\\\"),
+			'\r' => escaped.push_str("\\r"),
+			'\n' => escaped.push_str("\\n"),
+			x if x.is_control() =>
+			{
+				escaped.push_str(&format!("\\u{:04x}", u32::from(x)));
+			}
+			x => escaped.push(x),
+		}
+	}
+	escaped
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn write_object()
+	{
+		let line = object(&[
+			("day", "day10".into()),
+			("part", 2usize.into()),
+			("answer", "#.\n\"#\"\t".into()),
+			("expected", None::<i64>.into()),
+			("time_ns", Duration::from_micros(15).into()),
+			("pass", true.into()),
+			("mean", 2.5.into()),
+			("ratio", f64::NAN.into()),
+		]);
+		let expected = concat!(
+			r##"{"day":"day10","part":2,"answer":"#.\n\"#\"\u0009","##,
+			r##""expected":null,"time_ns":15000,"pass":true,"mean":2.5,"##,
+			r##""ratio":null}"##,
+		);
+		assert_eq!(line, expected);
+	}
+}
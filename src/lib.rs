@@ -1 +1,213 @@
 /**/
+
+// Index loops over fixed-size grids are the house style.
+#![allow(clippy::needless_range_loop)]
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod infi;
+
+pub mod bench;
+pub mod bits;
+pub mod cycle;
+pub mod frames;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod ocr;
+pub mod parallel;
+pub mod parse;
+pub mod property;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod trace;
+pub mod vector;
+pub mod verify;
+
+// Not a puzzle, but compiled along with the tests, so that the days that
+// new-day copies from it build from the start. Nothing registers its Solver.
+#[cfg(test)]
+#[allow(dead_code)]
+mod template;
+
+pub use parse::ParseError;
+pub use solution::Answer;
+pub use solution::Solution;
+
+use solution::AnySolution;
+use solution::Parsed;
+
+#[derive(Clone, Copy)]
+pub struct Puzzle
+{
+	pub name: &'static str,
+	pub solution: &'static dyn AnySolution,
+}
+
+impl Puzzle
+{
+	pub fn parse(&self, input: &str) -> Result<Parsed, ParseError>
+	{
+		self.solution
+			.parse(input)
+			.map_err(|error| error.in_day(self.name))
+	}
+
+	pub fn solve(&self, part: Part, parsed: &Parsed) -> Option<Answer>
+	{
+		match part
+		{
+			Part::One => self.solution.one(parsed),
+			Part::Two => self.solution.two(parsed),
+		}
+	}
+
+	/// The names of the implementations of a part, the default one first.
+	pub fn variants(&self, part: Part) -> Vec<&'static str>
+	{
+		let mut names = vec![solution::DEFAULT_VARIANT];
+		names.extend(self.solution.variant_names(part));
+		names
+	}
+
+	/// Whether the part has an implementation of that name, so that solving
+	/// it without an answer means the input has none.
+	pub fn has_variant(&self, name: &str, part: Part) -> bool
+	{
+		let has_part = part == Part::One || self.solution.has_part_two();
+		has_part && self.variants(part).contains(&name)
+	}
+
+	/// Solves a part with the implementation of that name, if there is one.
+	pub fn solve_variant(
+		&self,
+		name: &str,
+		part: Part,
+		parsed: &Parsed,
+	) -> Option<Answer>
+	{
+		if name == solution::DEFAULT_VARIANT
+		{
+			self.solve(part, parsed)
+		}
+		else
+		{
+			self.solution.solve_variant(name, part, parsed)
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part
+{
+	One,
+	Two,
+}
+
+impl Part
+{
+	pub fn number(self) -> usize
+	{
+		match self
+		{
+			Part::One => 1,
+			Part::Two => 2,
+		}
+	}
+}
+
+impl std::fmt::Display for Part
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		match self
+		{
+			Part::One => write!(f, "One"),
+			Part::Two => write!(f, "Two"),
+		}
+	}
+}
+
+macro_rules! puzzle {
+	($day:ident) => {
+		Puzzle {
+			name: stringify!($day),
+			solution: &$day::Solver,
+		}
+	};
+}
+
+pub static PUZZLES: [Puzzle; 26] = [
+	puzzle!(day01),
+	puzzle!(day02),
+	puzzle!(day03),
+	puzzle!(day04),
+	puzzle!(day05),
+	puzzle!(day06),
+	puzzle!(day07),
+	puzzle!(day08),
+	puzzle!(day09),
+	puzzle!(day10),
+	puzzle!(day11),
+	puzzle!(day12),
+	puzzle!(day13),
+	puzzle!(day14),
+	Puzzle {
+		name: "day15",
+		solution: &day15::Solver {
+			scale: day15::REAL_SCALE,
+		},
+	},
+	puzzle!(day16),
+	puzzle!(day17),
+	puzzle!(day18),
+	puzzle!(day19),
+	puzzle!(day20),
+	puzzle!(day21),
+	Puzzle {
+		name: "day22",
+		solution: &day22::Solver {
+			radius: day22::INPUT_RADIUS,
+		},
+	},
+	puzzle!(day23),
+	puzzle!(day24),
+	puzzle!(day25),
+	puzzle!(infi),
+];
+
+/// Finds a puzzle by its name ("day16", "infi") or day number ("16").
+pub fn find_puzzle(query: &str) -> Option<&'static Puzzle>
+{
+	let name = match query.parse::<u32>()
+	{
+		Ok(number) => format!("day{:02}", number),
+		Err(_) => query.to_string(),
+	};
+	PUZZLES.iter().find(|puzzle| puzzle.name == name)
+}
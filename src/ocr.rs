@@ -0,0 +1,141 @@
+/**/
+
+const HEIGHT: usize = 6;
+
+// The block letters that the puzzles draw, four pixels wide (Y is five) and
+// six high. Not every letter of the alphabet has turned up yet.
+const FONT: [(char, [&str; HEIGHT]); 18] = [
+	('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+	('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+	('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+	('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+	('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+	('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+	('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+	('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+	('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+	('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+	('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+	('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+	('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+	('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+	('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+	('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+	('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+	('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the block letters in a render, where `ink` is the character that the
+/// letters are drawn with and anything else is background. Returns None
+/// unless the render holds a single line of known letters.
+pub fn recognize(art: &str, ink: char) -> Option<String>
+{
+	let mut rows: Vec<Vec<bool>> = art
+		.lines()
+		.map(|line| line.chars().map(|x| x == ink).collect())
+		.collect();
+	while rows.last().is_some_and(|row| !row.contains(&true))
+	{
+		rows.pop();
+	}
+	let top = rows.iter().position(|row| row.contains(&true))?;
+	let rows = &rows[top..];
+	if rows.len() != HEIGHT
+	{
+		return None;
+	}
+	let width = rows.iter().map(Vec::len).max()?;
+	let is_inked = |r: usize, c: usize| rows[r].get(c) == Some(&true);
+	let is_blank = |c: usize| (0..HEIGHT).all(|r| !is_inked(r, c));
+
+	// Letters are separated by at least one blank column.
+	let mut text = String::new();
+	let mut c = 0;
+	while c < width
+	{
+		if is_blank(c)
+		{
+			c += 1;
+			continue;
+		}
+		let start = c;
+		while c < width && !is_blank(c)
+		{
+			c += 1;
+		}
+		let glyph: Vec<String> = (0..HEIGHT)
+			.map(|r| {
+				(start..c)
+					.map(|i| {
+						if is_inked(r, i)
+						{
+							'#'
+						}
+						else
+						{
+							'.'
+						}
+					})
+					.collect()
+			})
+			.collect();
+		text.push(lookup(&glyph)?);
+	}
+	Some(text)
+}
+
+fn lookup(glyph: &[String]) -> Option<char>
+{
+	FONT.iter()
+		.find(|(_, rows)| trim_blank_columns(rows) == glyph)
+		.map(|&(letter, _)| letter)
+}
+
+// Some letters, such as I, do not use the leftmost column of their cell.
+fn trim_blank_columns(rows: &[&str; HEIGHT]) -> Vec<String>
+{
+	let first = rows.iter().filter_map(|row| row.find('#')).min();
+	let last = rows.iter().filter_map(|row| row.rfind('#')).max();
+	match (first, last)
+	{
+		(Some(first), Some(last)) => rows
+			.iter()
+			.map(|row| row[first..=last].to_string())
+			.collect(),
+		_ => Vec::new(),
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const SCREEN: &str = include_str!("ocr/screen.txt");
+
+	#[test]
+	fn recognize_screen()
+	{
+		assert_eq!(recognize(SCREEN, '#'), Some("EHPZPJGL".to_string()));
+	}
+
+	#[test]
+	fn recognize_footprints_in_snow()
+	{
+		let snow = "################\n##  ##    ##  ##\n# ## # #### ## #\n# \
+		            ## #   ## ## #\n#    # #### ## #\n# ## # #### ## #\n# ## \
+		            #    ##  ##\n################\n";
+		assert_eq!(recognize(snow, ' '), Some("AEO".to_string()));
+	}
+
+	#[test]
+	fn reject_unknown_shapes()
+	{
+		let screen = "##..##..\n###...##\n";
+		assert_eq!(recognize(screen, '#'), None);
+		let blot = SCREEN.replacen('.', "#", 1);
+		assert_eq!(recognize(&blot, '#'), None);
+		assert_eq!(recognize("....\n....\n", '#'), None);
+	}
+}
@@ -0,0 +1,163 @@
+/**/
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status<R>
+{
+	Done(R),
+	Panicked(String),
+	TimedOut,
+}
+
+/// Applies `work` to each of the items on a pool of `num_threads` workers,
+/// returning the results in the same order as the items. Each item gets its
+/// own thread, so that a worker can stop waiting for it after the timeout.
+/// Such a thread cannot be killed, so it keeps running in the background
+/// until it finishes or the program exits, while its worker moves on to the
+/// next item. More than `num_threads` items can thus be running at once.
+pub fn map<T, R, F>(
+	items: Vec<T>,
+	num_threads: usize,
+	timeout: Option<Duration>,
+	work: F,
+) -> Vec<Status<R>>
+where
+	T: Send + 'static,
+	R: Send + 'static,
+	F: Fn(T) -> R + Send + Sync + 'static,
+{
+	assert!(num_threads > 0);
+	let num_items = items.len();
+	let queue: VecDeque<(usize, T)> = items.into_iter().enumerate().collect();
+	let queue = Arc::new(Mutex::new(queue));
+	let work = Arc::new(work);
+	let (results_tx, results_rx) = mpsc::channel();
+	let workers: Vec<_> = (0..num_threads.min(num_items))
+		.map(|_| {
+			let queue = Arc::clone(&queue);
+			let work = Arc::clone(&work);
+			let results_tx = results_tx.clone();
+			std::thread::spawn(move || {
+				while let Some((i, item)) = pop(&queue)
+				{
+					let status = run_one(item, &work, timeout);
+					if results_tx.send((i, status)).is_err()
+					{
+						break;
+					}
+				}
+			})
+		})
+		.collect();
+	drop(results_tx);
+	let mut results: Vec<Option<Status<R>>> =
+		(0..num_items).map(|_| None).collect();
+	for (i, status) in results_rx
+	{
+		results[i] = Some(status);
+	}
+	for worker in workers
+	{
+		worker.join().unwrap();
+	}
+	results.into_iter().map(Option::unwrap).collect()
+}
+
+fn pop<T>(queue: &Mutex<VecDeque<(usize, T)>>) -> Option<(usize, T)>
+{
+	queue.lock().unwrap().pop_front()
+}
+
+fn run_one<T, R, F>(
+	item: T,
+	work: &Arc<F>,
+	timeout: Option<Duration>,
+) -> Status<R>
+where
+	T: Send + 'static,
+	R: Send + 'static,
+	F: Fn(T) -> R + Send + Sync + 'static,
+{
+	let (tx, rx) = mpsc::channel();
+	let work = Arc::clone(work);
+	let handle = std::thread::spawn(move || {
+		// The receiver is gone if it timed out.
+		let _ = tx.send(work(item));
+	});
+	let result = match timeout
+	{
+		Some(timeout) => rx.recv_timeout(timeout),
+		None => rx.recv().map_err(mpsc::RecvTimeoutError::from),
+	};
+	match result
+	{
+		Ok(result) =>
+		{
+			let _ = handle.join();
+			Status::Done(result)
+		}
+		Err(mpsc::RecvTimeoutError::Timeout) => Status::TimedOut,
+		Err(mpsc::RecvTimeoutError::Disconnected) =>
+		{
+			let payload = handle.join().unwrap_err();
+			Status::Panicked(panic_message(payload.as_ref()))
+		}
+	}
+}
+
+/// The message that a thread panicked with, if it was a string.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String
+{
+	payload
+		.downcast_ref::<&str>()
+		.map(|x| x.to_string())
+		.or_else(|| payload.downcast_ref::<String>().cloned())
+		.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn keep_results_in_order()
+	{
+		let items: Vec<u64> = (0..20).collect();
+		let results = map(items, 3, None, |x| x * x);
+		let expected: Vec<Status<u64>> =
+			(0..20).map(|x| Status::Done(x * x)).collect();
+		assert_eq!(results, expected);
+	}
+
+	#[test]
+	fn report_panics_and_timeouts()
+	{
+		let items = vec![0, 1, 2];
+		let timeout = Some(Duration::from_millis(200));
+		let results = map(items, 2, timeout, |x| {
+			match x
+			{
+				1 => panic!("one"),
+				2 => std::thread::sleep(Duration::from_secs(2)),
+				_ => (),
+			}
+			x
+		});
+		assert_eq!(
+			results,
+			[
+				Status::Done(0),
+				Status::Panicked("one".to_string()),
+				Status::TimedOut
+			]
+		);
+	}
+}
@@ -0,0 +1,151 @@
+/**/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError
+{
+	pub day: Option<&'static str>,
+	// Both line and column start at 1.
+	pub line: usize,
+	pub column: usize,
+	pub text: String,
+	pub reason: String,
+}
+
+impl ParseError
+{
+	pub fn new(
+		line: usize,
+		column: usize,
+		text: &str,
+		reason: impl Into<String>,
+	) -> ParseError
+	{
+		ParseError {
+			day: None,
+			line,
+			column,
+			text: text.to_string(),
+			reason: reason.into(),
+		}
+	}
+
+	/// An error about line `i` (counting from 0) as a whole.
+	pub fn at_line(
+		i: usize,
+		line: &str,
+		reason: impl Into<String>,
+	) -> ParseError
+	{
+		ParseError::new(i + 1, 1, line, reason)
+	}
+
+	/// An error about the byte at offset `j` of line `i` (counting from 0).
+	pub fn at_byte(i: usize, j: usize, line: &str) -> ParseError
+	{
+		let reason = match line.as_bytes().get(j)
+		{
+			Some(byte) => format!("unexpected {:?}", *byte as char),
+			None => "unexpected end of line".to_string(),
+		};
+		ParseError::new(i + 1, j + 1, line, reason)
+	}
+
+	/// An error about a missing line after the last line of `input`.
+	pub fn at_end(input: &str, reason: impl Into<String>) -> ParseError
+	{
+		ParseError::new(input.lines().count() + 1, 1, "", reason)
+	}
+
+	/// Moves the error down by some lines, for parsers that only got
+	/// a later section of the input.
+	pub fn below(mut self, lines: usize) -> ParseError
+	{
+		self.line += lines;
+		self
+	}
+
+	pub fn in_day(mut self, day: &'static str) -> ParseError
+	{
+		self.day = Some(day);
+		self
+	}
+}
+
+impl std::fmt::Display for ParseError
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		if let Some(day) = self.day
+		{
+			write!(f, "{}: ", day)?;
+		}
+		write!(
+			f,
+			"line {}, column {}: {}: {:?}",
+			self.line, self.column, self.reason, self.text
+		)
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line of the input with FromStr.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+	T: std::str::FromStr,
+{
+	input
+		.lines()
+		.enumerate()
+		.map(|(i, line)| parse_line(i, line))
+		.collect()
+}
+
+/// Parses line `i` (counting from 0) with FromStr.
+pub fn parse_line<T>(i: usize, line: &str) -> Result<T, ParseError>
+where
+	T: std::str::FromStr,
+{
+	line.parse()
+		.map_err(|_| ParseError::at_line(i, line, invalid::<T>()))
+}
+
+/// Splits the input at the first blank line.
+pub fn split_at_blank_line(input: &str) -> Result<(&str, &str), ParseError>
+{
+	input
+		.split_once("\n\n")
+		.ok_or_else(|| ParseError::at_end(input, "missing blank line"))
+}
+
+fn invalid<T>() -> String
+{
+	let name = std::any::type_name::<T>();
+	let name = name.rsplit("::").next().unwrap_or(name);
+	format!("invalid {}", name)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn parse_lines_reports_line()
+	{
+		let error = parse_lines::<i32>("1000\n2000\n3O00\n").unwrap_err();
+		assert_eq!(error, ParseError::new(3, 1, "3O00", "invalid i32"));
+		assert_eq!(
+			error.in_day("day01").to_string(),
+			"day01: line 3, column 1: invalid i32: \"3O00\""
+		);
+	}
+
+	#[test]
+	fn at_byte_reports_column()
+	{
+		let error = ParseError::at_byte(1, 2, "ab!d");
+		assert_eq!(error, ParseError::new(2, 3, "ab!d", "unexpected '!'"));
+	}
+}
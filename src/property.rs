@@ -0,0 +1,203 @@
+/**/
+
+use crate::generate::Rng;
+use crate::parallel::panic_message;
+
+use std::fmt::Debug;
+use std::panic::AssertUnwindSafe;
+
+// Shrinking stops after this many steps, in case a shrinker goes in circles.
+const MAX_SHRINK_STEPS: usize = 10000;
+
+/// Checks a property against random inputs, one for each seed. If it fails,
+/// the input is shrunk to a smaller one that still fails, and that one is
+/// reported along with the seed. A panic counts as a failure.
+pub fn check<T, G, S, P>(num_cases: u64, generate: G, shrink: S, property: P)
+where
+	T: Clone + Debug,
+	G: Fn(&mut Rng) -> T,
+	S: Fn(&T) -> Vec<T>,
+	P: Fn(&T) -> Result<(), String>,
+{
+	for seed in 0..num_cases
+	{
+		let input = generate(&mut Rng::new(seed));
+		if let Err(error) = holds(&property, &input)
+		{
+			let (input, error) = minimize(input, error, &shrink, &property);
+			panic!(
+				"property fails for seed {}: {}\nshrunk input: {:?}",
+				seed, error, input
+			);
+		}
+	}
+}
+
+/// Compares the answer of an optimized solver against that of a reference.
+pub fn agree<A>(what: &str, optimized: A, reference: A) -> Result<(), String>
+where
+	A: PartialEq + Debug,
+{
+	if optimized == reference
+	{
+		Ok(())
+	}
+	else
+	{
+		Err(format!(
+			"{}: got {:?} but the reference gives {:?}",
+			what, optimized, reference
+		))
+	}
+}
+
+fn holds<T, P>(property: &P, input: &T) -> Result<(), String>
+where
+	P: Fn(&T) -> Result<(), String>,
+{
+	match std::panic::catch_unwind(AssertUnwindSafe(|| property(input)))
+	{
+		Ok(result) => result,
+		Err(payload) =>
+		{
+			let message = panic_message(payload.as_ref());
+			Err(format!("panicked: {}", message))
+		}
+	}
+}
+
+/// Keeps replacing the failing input with the first smaller candidate that
+/// also fails, until none of the candidates fail.
+pub fn minimize<T, S, P>(
+	mut input: T,
+	mut error: String,
+	shrink: &S,
+	property: &P,
+) -> (T, String)
+where
+	S: Fn(&T) -> Vec<T>,
+	P: Fn(&T) -> Result<(), String>,
+{
+	for _ in 0..MAX_SHRINK_STEPS
+	{
+		let smaller = shrink(&input)
+			.into_iter()
+			.find_map(|x| holds(property, &x).err().map(|error| (x, error)));
+		match smaller
+		{
+			Some((x, e)) =>
+			{
+				input = x;
+				error = e;
+			}
+			None => break,
+		}
+	}
+	(input, error)
+}
+
+/// Smaller lists: without one of the halves, without a single item, or with
+/// a single item shrunk.
+pub fn shrink_vec<T, S>(items: &[T], shrink_item: S) -> Vec<Vec<T>>
+where
+	T: Clone,
+	S: Fn(&T) -> Vec<T>,
+{
+	let n = items.len();
+	let mut candidates = Vec::new();
+	if n >= 2
+	{
+		candidates.push(items[n / 2..].to_vec());
+		candidates.push(items[..n / 2].to_vec());
+	}
+	for i in 0..n
+	{
+		let mut smaller = items.to_vec();
+		smaller.remove(i);
+		candidates.push(smaller);
+	}
+	for i in 0..n
+	{
+		for item in shrink_item(&items[i])
+		{
+			let mut smaller = items.to_vec();
+			smaller[i] = item;
+			candidates.push(smaller);
+		}
+	}
+	candidates
+}
+
+/// Numbers closer to zero, keeping the sign.
+pub fn shrink_integer(x: i64) -> Vec<i64>
+{
+	let mut candidates = vec![0, x / 2, x - x.signum()];
+	candidates.retain(|&y| y != x);
+	candidates.dedup();
+	candidates
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	fn sum_below_100(numbers: &[i64]) -> Result<(), String>
+	{
+		let sum: i64 = numbers.iter().sum();
+		if sum < 100
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(format!("sum is {}", sum))
+		}
+	}
+
+	#[test]
+	fn shrink_to_smallest_failure()
+	{
+		let shrink =
+			|numbers: &Vec<i64>| shrink_vec(numbers, |&x| shrink_integer(x));
+		let input = vec![3, 80, -5, 70, 12];
+		let error = sum_below_100(&input).unwrap_err();
+		let property = |numbers: &Vec<i64>| sum_below_100(numbers);
+		let (input, error) = minimize(input, error, &shrink, &property);
+		assert_eq!(input.iter().sum::<i64>(), 100);
+		assert_eq!(error, "sum is 100");
+		for i in 0..input.len()
+		{
+			let mut smaller = input.clone();
+			smaller.remove(i);
+			assert!(sum_below_100(&smaller).is_ok());
+		}
+	}
+
+	#[test]
+	fn count_panics_as_failures()
+	{
+		let shrink = |&x: &i64| shrink_integer(x);
+		let property = |&x: &i64| {
+			assert!(x < 7, "too large");
+			Ok(())
+		};
+		let error = holds(&property, &30).unwrap_err();
+		let (input, error) = minimize(30, error, &shrink, &property);
+		assert_eq!(input, 7);
+		assert!(error.contains("too large"), "{}", error);
+	}
+
+	#[test]
+	#[should_panic(expected = "property fails for seed 0: sum is 100")]
+	fn report_shrunk_input()
+	{
+		let generate = |rng: &mut Rng| {
+			(0..10).map(|_| rng.range(0..=50)).collect::<Vec<i64>>()
+		};
+		let shrink =
+			|numbers: &Vec<i64>| shrink_vec(numbers, |&x| shrink_integer(x));
+		check(10, generate, shrink, |numbers| sum_below_100(numbers));
+	}
+}
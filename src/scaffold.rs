@@ -0,0 +1,232 @@
+/**/
+
+use std::path::Path;
+use std::path::PathBuf;
+
+const TEMPLATE_DIR: &str = "template";
+const TEMPLATE_FILES: [&str; 3] = ["mod.rs", "provided.txt", "input.txt"];
+
+/// The name of a new puzzle: "dayNN" for a day number, or the name of a side
+/// event such as "infi", which has to be a valid module name.
+pub fn puzzle_name(query: &str) -> Result<String, String>
+{
+	if let Ok(number) = query.parse::<u32>()
+	{
+		return Ok(format!("day{:02}", number));
+	}
+	let mut chars = query.chars();
+	let is_valid = chars.next().is_some_and(|x| x.is_ascii_lowercase())
+		&& chars
+			.all(|x| x.is_ascii_lowercase() || x.is_ascii_digit() || x == '_');
+	if is_valid
+	{
+		Ok(query.to_string())
+	}
+	else
+	{
+		Err(format!("invalid puzzle name '{}'", query))
+	}
+}
+
+/// Creates `<src>/<name>/` from `<src>/template/` and registers the new
+/// puzzle in `<src>/lib.rs`, returning the files that were written.
+pub fn new_puzzle(src: &Path, name: &str) -> Result<Vec<PathBuf>, String>
+{
+	let lib_path = src.join("lib.rs");
+	let lib = read(&lib_path)?;
+	let lib = register(&lib, name)?;
+	let dir = src.join(name);
+	if dir.exists()
+	{
+		return Err(format!("{} already exists", dir.display()));
+	}
+	let mut files = Vec::new();
+	for filename in TEMPLATE_FILES
+	{
+		let contents = read(&src.join(TEMPLATE_DIR).join(filename))?;
+		files.push((dir.join(filename), contents));
+	}
+	std::fs::create_dir(&dir).map_err(|error| {
+		format!("cannot create {}: {}", dir.display(), error)
+	})?;
+	let mut written = Vec::new();
+	for (path, contents) in files
+	{
+		write(&path, &contents)?;
+		written.push(path);
+	}
+	write(&lib_path, &lib)?;
+	written.push(lib_path);
+	Ok(written)
+}
+
+fn read(path: &Path) -> Result<String, String>
+{
+	std::fs::read_to_string(path)
+		.map_err(|error| format!("cannot read {}: {}", path.display(), error))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String>
+{
+	std::fs::write(path, contents)
+		.map_err(|error| format!("cannot write {}: {}", path.display(), error))
+}
+
+// Days come first, in order, and then the side events.
+fn sort_key(name: &str) -> (bool, &str)
+{
+	(!name.starts_with("day"), name)
+}
+
+/// Adds the module declaration and the entry in PUZZLES to the source of
+/// lib.rs, keeping both in order.
+fn register(lib: &str, name: &str) -> Result<String, String>
+{
+	let mut lines: Vec<String> = lib.lines().map(str::to_string).collect();
+
+	// The puzzles are the first block of modules.
+	let modules = lines
+		.iter()
+		.position(|line| line.starts_with("pub mod "))
+		.ok_or("no modules in lib.rs")?;
+	let mut at = modules;
+	while let Some(module) = lines
+		.get(at)
+		.and_then(|line| line.strip_prefix("pub mod "))
+		.and_then(|line| line.strip_suffix(';'))
+	{
+		if module == name
+		{
+			return Err(format!("{} is already registered", name));
+		}
+		if sort_key(module) > sort_key(name)
+		{
+			break;
+		}
+		at += 1;
+	}
+	lines.insert(at, format!("pub mod {};", name));
+
+	let header = lines
+		.iter()
+		.position(|line| line.starts_with("pub static PUZZLES: [Puzzle; "))
+		.ok_or("no PUZZLES in lib.rs")?;
+	let count: usize = lines[header]
+		.trim_start_matches("pub static PUZZLES: [Puzzle; ")
+		.trim_end_matches("] = [")
+		.parse()
+		.map_err(|_| "cannot count PUZZLES in lib.rs")?;
+	lines[header] = format!("pub static PUZZLES: [Puzzle; {}] = [", count + 1);
+	// Entries are either "puzzle!(day01)," or a struct with a "name" field
+	// on the next line.
+	let mut at = header + 1;
+	loop
+	{
+		let line = lines.get(at).ok_or("unterminated PUZZLES in lib.rs")?;
+		let entry = if line == "\tPuzzle {"
+		{
+			lines.get(at + 1).and_then(|line| {
+				line.trim()
+					.strip_prefix("name: \"")
+					.and_then(|line| line.strip_suffix("\","))
+			})
+		}
+		else
+		{
+			line.trim()
+				.strip_prefix("puzzle!(")
+				.and_then(|line| line.strip_suffix("),"))
+		};
+		let is_after =
+			entry.is_some_and(|entry| sort_key(entry) > sort_key(name));
+		if line == "];" || is_after
+		{
+			break;
+		}
+		at += 1;
+	}
+	lines.insert(at, format!("\tpuzzle!({}),", name));
+
+	let mut lib = lines.join("\n");
+	lib.push('\n');
+	Ok(lib)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const LIB: &str = "/**/
+
+pub mod day01;
+pub mod day03;
+pub mod infi;
+
+pub mod grid;
+
+pub static PUZZLES: [Puzzle; 3] = [
+	puzzle!(day01),
+	Puzzle {
+		name: \"day03\",
+		solution: &day03::Solver { scale: 2 },
+	},
+	puzzle!(infi),
+];
+";
+
+	#[test]
+	fn parse_names()
+	{
+		assert_eq!(puzzle_name("2"), Ok("day02".to_string()));
+		assert_eq!(puzzle_name("infi"), Ok("infi".to_string()));
+		assert!(puzzle_name("Infi").is_err());
+		assert!(puzzle_name("day-2").is_err());
+		assert!(puzzle_name("").is_err());
+	}
+
+	#[test]
+	fn register_days_and_events_in_order()
+	{
+		let lib = register(LIB, "day02").unwrap();
+		let lib = register(&lib, "day04").unwrap();
+		let lib = register(&lib, "snow").unwrap();
+		let expected = "/**/
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod infi;
+pub mod snow;
+
+pub mod grid;
+
+pub static PUZZLES: [Puzzle; 6] = [
+	puzzle!(day01),
+	puzzle!(day02),
+	Puzzle {
+		name: \"day03\",
+		solution: &day03::Solver { scale: 2 },
+	},
+	puzzle!(day04),
+	puzzle!(infi),
+	puzzle!(snow),
+];
+";
+		assert_eq!(lib, expected);
+		assert!(register(LIB, "infi").is_err());
+	}
+
+	#[test]
+	fn register_in_this_crate()
+	{
+		let lib = include_str!("lib.rs");
+		let lib = register(lib, "scaffolded").unwrap();
+		assert!(lib.contains("\npub mod scaffolded;\n"));
+		assert!(lib.contains("\tpuzzle!(scaffolded),\n];"));
+		let count = crate::PUZZLES.len() + 1;
+		assert!(lib.contains(&format!("[Puzzle; {}]", count)));
+	}
+}
@@ -0,0 +1,483 @@
+/**/
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// A graph in which to look for the cheapest path from a start to any goal.
+pub trait Search
+{
+	type State: Clone + Eq + Hash;
+
+	fn is_goal(&self, state: &Self::State) -> bool;
+
+	/// Adds the states that can be reached from this one in a single step.
+	fn successors(&self, state: &Self::State, next: &mut Vec<Self::State>);
+
+	/// The cost of a single step, which breadth-first search ignores.
+	fn cost(&self, _from: &Self::State, _to: &Self::State) -> u32
+	{
+		1
+	}
+
+	/// A lower bound on the cost from this state to the nearest goal. If it
+	/// ever overestimates, A* may not find the cheapest path.
+	fn heuristic(&self, _state: &Self::State) -> u32
+	{
+		0
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S>
+{
+	/// From the start up to and including the goal.
+	pub states: Vec<S>,
+	pub cost: u32,
+}
+
+/// Finds a path with the fewest steps.
+pub fn bfs<S: Search>(search: &S, start: S::State) -> Option<Path<S::State>>
+{
+	let mut came_from: HashMap<S::State, Option<S::State>> = HashMap::new();
+	came_from.insert(start.clone(), None);
+	let mut queue = VecDeque::from([start]);
+	let mut next = Vec::new();
+	while let Some(state) = queue.pop_front()
+	{
+		if search.is_goal(&state)
+		{
+			let states = reconstruct(state, |x| came_from[x].clone());
+			let cost = states.len() as u32 - 1;
+			return Some(Path { states, cost });
+		}
+		search.successors(&state, &mut next);
+		for to in next.drain(..)
+		{
+			if !came_from.contains_key(&to)
+			{
+				came_from.insert(to.clone(), Some(state.clone()));
+				queue.push_back(to);
+			}
+		}
+	}
+	None
+}
+
+/// Finds a cheapest path, ignoring the heuristic.
+pub fn dijkstra<S: Search>(
+	search: &S,
+	start: S::State,
+) -> Option<Path<S::State>>
+{
+	find_cheapest_path(search, start, |_| 0)
+}
+
+/// Finds a cheapest path, exploring the states that look closest first.
+pub fn astar<S: Search>(search: &S, start: S::State) -> Option<Path<S::State>>
+{
+	find_cheapest_path(search, start, |state| search.heuristic(state))
+}
+
+fn find_cheapest_path<S: Search>(
+	search: &S,
+	start: S::State,
+	heuristic: impl Fn(&S::State) -> u32,
+) -> Option<Path<S::State>>
+{
+	// The cheapest known cost of every state seen so far, and where from.
+	let mut best: HashMap<S::State, (u32, Option<S::State>)> = HashMap::new();
+	best.insert(start.clone(), (0, None));
+	let mut queue = PriorityQueue::new();
+	let estimate = heuristic(&start);
+	queue.push((0, start), Reverse(estimate));
+	let mut next = Vec::new();
+	while let Some((cost, state)) = queue.pop()
+	{
+		// Skip states that were found again at a lower cost.
+		if best[&state].0 < cost
+		{
+			continue;
+		}
+		if search.is_goal(&state)
+		{
+			let states = reconstruct(state, |x| best[x].1.clone());
+			return Some(Path { states, cost });
+		}
+		search.successors(&state, &mut next);
+		for to in next.drain(..)
+		{
+			let total = cost + search.cost(&state, &to);
+			if best.get(&to).is_some_and(|&(known, _)| known <= total)
+			{
+				continue;
+			}
+			best.insert(to.clone(), (total, Some(state.clone())));
+			let estimate = total + heuristic(&to);
+			queue.push((total, to), Reverse(estimate));
+		}
+	}
+	None
+}
+
+fn reconstruct<T>(goal: T, mut came_from: impl FnMut(&T) -> Option<T>)
+	-> Vec<T>
+{
+	let mut states = vec![goal];
+	while let Some(previous) = came_from(states.last().unwrap())
+	{
+		states.push(previous);
+	}
+	states.reverse();
+	states
+}
+
+/// A maximization problem that is solved by splitting it into smaller ones,
+/// skipping those that cannot beat the best solution found so far.
+pub trait BranchAndBound
+{
+	type Node;
+
+	/// An upper bound on the value of any solution within this node.
+	fn bound(&self, node: &Self::Node) -> i32;
+
+	/// Adds the children of a node, given the best value found so far, and
+	/// returns the value of the best solution that it came across.
+	fn branch(
+		&mut self,
+		node: Self::Node,
+		best: i32,
+		children: &mut Vec<Self::Node>,
+	) -> i32;
+}
+
+/// Explores the most recently added node first, and returns the best value.
+pub fn depth_first<B: BranchAndBound>(problem: &mut B, root: B::Node) -> i32
+{
+	let mut best = i32::MIN;
+	let mut stack = vec![root];
+	while let Some(node) = stack.pop()
+	{
+		if problem.bound(&node) <= best
+		{
+			continue;
+		}
+		// The children go straight onto the stack, and are bounded when they
+		// come off it.
+		let value = problem.branch(node, best, &mut stack);
+		if value > best
+		{
+			crate::debug!("best value so far is {}", value);
+			best = value;
+		}
+	}
+	best
+}
+
+/// Explores the node with the highest priority first, and returns the best
+/// value.
+pub fn best_first<B: BranchAndBound, P: Ord>(
+	problem: &mut B,
+	root: B::Node,
+	priority: impl Fn(&B::Node) -> P,
+) -> i32
+{
+	let mut best = i32::MIN;
+	let mut queue = PriorityQueue::new();
+	let p = priority(&root);
+	queue.push(root, p);
+	let mut children = Vec::new();
+	while let Some(node) = queue.pop()
+	{
+		if problem.bound(&node) <= best
+		{
+			continue;
+		}
+		let value = problem.branch(node, best, &mut children);
+		if value > best
+		{
+			crate::debug!("best value so far is {}", value);
+			best = value;
+		}
+		for child in children.drain(..)
+		{
+			if problem.bound(&child) > best
+			{
+				let p = priority(&child);
+				queue.push(child, p);
+			}
+		}
+	}
+	best
+}
+
+/// A max-heap of items that are ordered by a separate priority, so that the
+/// items themselves need not be ordered. Use `Reverse` for a min-heap.
+#[derive(Debug, Clone)]
+pub struct PriorityQueue<T, P>
+{
+	heap: BinaryHeap<Prioritized<T, P>>,
+}
+
+impl<T, P: Ord> PriorityQueue<T, P>
+{
+	pub fn new() -> PriorityQueue<T, P>
+	{
+		PriorityQueue {
+			heap: BinaryHeap::new(),
+		}
+	}
+
+	pub fn len(&self) -> usize
+	{
+		self.heap.len()
+	}
+
+	pub fn is_empty(&self) -> bool
+	{
+		self.heap.is_empty()
+	}
+
+	pub fn push(&mut self, item: T, priority: P)
+	{
+		self.heap.push(Prioritized { priority, item });
+	}
+
+	/// Removes an item with the highest priority.
+	pub fn pop(&mut self) -> Option<T>
+	{
+		self.heap.pop().map(|entry| entry.item)
+	}
+
+	pub fn peek_priority(&self) -> Option<&P>
+	{
+		self.heap.peek().map(|entry| &entry.priority)
+	}
+
+	pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool)
+	{
+		self.heap.retain(|entry| f(&entry.item));
+	}
+}
+
+impl<T, P: Ord> Default for PriorityQueue<T, P>
+{
+	fn default() -> PriorityQueue<T, P>
+	{
+		PriorityQueue::new()
+	}
+}
+
+#[derive(Debug, Clone)]
+struct Prioritized<T, P>
+{
+	priority: P,
+	item: T,
+}
+
+impl<T, P: Ord> Ord for Prioritized<T, P>
+{
+	fn cmp(&self, other: &Self) -> std::cmp::Ordering
+	{
+		self.priority.cmp(&other.priority)
+	}
+}
+
+impl<T, P: Ord> PartialOrd for Prioritized<T, P>
+{
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl<T, P: Ord> PartialEq for Prioritized<T, P>
+{
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.priority == other.priority
+	}
+}
+
+impl<T, P: Ord> Eq for Prioritized<T, P> {}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::grid::Grid;
+	use crate::grid::Position;
+	use pretty_assertions::assert_eq;
+
+	// A maze where walking through mud costs five steps.
+	const MAZE: &str = "S.~..\n#.#.#\n..~.E\n";
+
+	struct Maze
+	{
+		grid: Grid<u8>,
+	}
+
+	impl Search for Maze
+	{
+		type State = Position;
+
+		fn is_goal(&self, &position: &Position) -> bool
+		{
+			self.grid[position] == b'E'
+		}
+
+		fn successors(&self, &from: &Position, next: &mut Vec<Position>)
+		{
+			let open = |&to: &Position| self.grid[to] != b'#';
+			next.extend(self.grid.neighbours4(from).filter(open));
+		}
+
+		fn cost(&self, _from: &Position, &to: &Position) -> u32
+		{
+			if self.grid[to] == b'~'
+			{
+				5
+			}
+			else
+			{
+				1
+			}
+		}
+
+		fn heuristic(&self, &(r, c): &Position) -> u32
+		{
+			(r.abs_diff(2) + c.abs_diff(4)) as u32
+		}
+	}
+
+	#[test]
+	fn bfs_takes_fewest_steps()
+	{
+		let maze = Maze {
+			grid: Grid::parse(MAZE).unwrap(),
+		};
+		let path = bfs(&maze, (0, 0)).unwrap();
+		assert_eq!(path.cost, 6);
+		assert_eq!(path.states.first(), Some(&(0, 0)));
+		assert_eq!(path.states.last(), Some(&(2, 4)));
+		assert!(path.states.contains(&(0, 2)) || path.states.contains(&(2, 2)));
+	}
+
+	#[test]
+	fn cheapest_path_avoids_mud()
+	{
+		let maze = Maze {
+			grid: Grid::parse("S.~..\n#.#.#\n#...#\n###.E\n").unwrap(),
+		};
+		let expected = vec![
+			(0, 0),
+			(0, 1),
+			(1, 1),
+			(2, 1),
+			(2, 2),
+			(2, 3),
+			(3, 3),
+			(3, 4),
+		];
+		let path = dijkstra(&maze, (0, 0)).unwrap();
+		assert_eq!(path.cost, 7);
+		assert_eq!(path.states, expected);
+		let path = astar(&maze, (0, 0)).unwrap();
+		assert_eq!(path.cost, 7);
+		assert_eq!(path.states, expected);
+	}
+
+	#[test]
+	fn unreachable_goal()
+	{
+		let maze = Maze {
+			grid: Grid::parse("S#E\n").unwrap(),
+		};
+		assert_eq!(bfs(&maze, (0, 0)), None);
+		assert_eq!(astar(&maze, (0, 0)), None);
+	}
+
+	// Picks items of the given weights and values without exceeding the
+	// capacity, branching on whether to take the next item.
+	struct Knapsack
+	{
+		items: Vec<(i32, i32)>,
+		capacity: i32,
+	}
+
+	#[derive(Debug, Clone, Copy)]
+	struct Picked
+	{
+		next: usize,
+		weight: i32,
+		value: i32,
+	}
+
+	impl BranchAndBound for Knapsack
+	{
+		type Node = Picked;
+
+		fn bound(&self, node: &Picked) -> i32
+		{
+			let rest: i32 = self.items[node.next..].iter().map(|x| x.1).sum();
+			node.value + rest
+		}
+
+		fn branch(
+			&mut self,
+			node: Picked,
+			_best: i32,
+			children: &mut Vec<Picked>,
+		) -> i32
+		{
+			if let Some(&(weight, value)) = self.items.get(node.next)
+			{
+				let next = node.next + 1;
+				children.push(Picked { next, ..node });
+				if node.weight + weight <= self.capacity
+				{
+					children.push(Picked {
+						next,
+						weight: node.weight + weight,
+						value: node.value + value,
+					});
+				}
+			}
+			node.value
+		}
+	}
+
+	#[test]
+	fn branch_and_bound_knapsack()
+	{
+		let mut knapsack = Knapsack {
+			items: vec![(12, 4), (2, 2), (1, 1), (1, 2), (4, 10)],
+			capacity: 15,
+		};
+		let root = Picked {
+			next: 0,
+			weight: 0,
+			value: 0,
+		};
+		assert_eq!(depth_first(&mut knapsack, root), 15);
+		assert_eq!(best_first(&mut knapsack, root, |node| node.value), 15);
+	}
+
+	#[test]
+	fn priority_queue_pops_highest_first()
+	{
+		let mut queue = PriorityQueue::new();
+		queue.push("b", 2);
+		queue.push("d", 4);
+		queue.push("a", 1);
+		queue.push("c", 3);
+		queue.retain(|&x| x != "c");
+		assert_eq!(queue.len(), 3);
+		assert_eq!(queue.peek_priority(), Some(&4));
+		let order: Vec<&str> = std::iter::from_fn(|| queue.pop()).collect();
+		assert_eq!(order, vec!["d", "b", "a"]);
+		assert!(queue.is_empty());
+	}
+}
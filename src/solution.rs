@@ -0,0 +1,283 @@
+/**/
+
+use crate::ParseError;
+use crate::Part;
+
+use std::any::Any;
+
+pub trait Solution
+{
+	type Input: Send + 'static;
+
+	/// Checks the shape of the input, but leaves finding out whether it has
+	/// an answer to the parts.
+	fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+	/// None if the input has no answer, such as a maze without a way out.
+	fn one(&self, input: &Self::Input) -> Option<Answer>;
+	fn two(&self, input: &Self::Input) -> Option<Answer>;
+
+	/// Whether there is a second part at all, which there is not on the
+	/// last day.
+	fn has_part_two(&self) -> bool
+	{
+		true
+	}
+
+	/// Other ways to solve the parts, next to `one` and `two` themselves.
+	fn variants(&self) -> Vec<Variant<Self::Input>>
+	{
+		Vec::new()
+	}
+}
+
+/// The name under which `one` and `two` are listed among the variants.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// An alternative implementation of one of the parts, such as a naive
+/// solver that the optimized one can be checked against.
+pub struct Variant<I>
+{
+	pub name: &'static str,
+	pub part: Part,
+	pub solve: fn(&I) -> Option<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer
+{
+	Number(i64),
+	Text(String),
+	// Multi-line ASCII art, such as the screen of day 10.
+	Art(String),
+	// ASCII art that spells out letters, which are the actual answer.
+	Letters
+	{
+		text: String,
+		art: String,
+	},
+}
+
+impl Answer
+{
+	/// Reads the letters drawn with `ink` in some ASCII art, or keeps the art
+	/// as the answer if they cannot be read.
+	pub fn from_art(art: String, ink: char) -> Answer
+	{
+		match crate::ocr::recognize(&art, ink)
+		{
+			Some(text) => Answer::Letters { text, art },
+			None => Answer::Art(art),
+		}
+	}
+
+	/// The raw render of an answer that was drawn, even if it was read.
+	pub fn art(&self) -> Option<&str>
+	{
+		match self
+		{
+			Answer::Art(art) => Some(art),
+			Answer::Letters { art, .. } => Some(art),
+			Answer::Number(_) | Answer::Text(_) => None,
+		}
+	}
+
+	/// What kind of answer this is, for machine-readable output.
+	pub fn kind(&self) -> &'static str
+	{
+		match self
+		{
+			Answer::Number(_) => "number",
+			Answer::Text(_) => "text",
+			Answer::Art(_) => "art",
+			Answer::Letters { .. } => "letters",
+		}
+	}
+
+	pub fn is_multiline(&self) -> bool
+	{
+		matches!(self, Answer::Art(_))
+	}
+}
+
+impl std::fmt::Display for Answer
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		match self
+		{
+			Answer::Number(number) => write!(f, "{}", number),
+			Answer::Text(text) => write!(f, "{}", text),
+			Answer::Art(art) => write!(f, "{}", art.trim_matches('\n')),
+			Answer::Letters { text, .. } => write!(f, "{}", text),
+		}
+	}
+}
+
+impl From<i32> for Answer
+{
+	fn from(number: i32) -> Answer
+	{
+		Answer::Number(number.into())
+	}
+}
+
+impl From<u32> for Answer
+{
+	fn from(number: u32) -> Answer
+	{
+		Answer::Number(number.into())
+	}
+}
+
+impl From<i64> for Answer
+{
+	fn from(number: i64) -> Answer
+	{
+		Answer::Number(number)
+	}
+}
+
+impl From<u64> for Answer
+{
+	fn from(number: u64) -> Answer
+	{
+		Answer::Number(number.try_into().unwrap())
+	}
+}
+
+impl From<usize> for Answer
+{
+	fn from(number: usize) -> Answer
+	{
+		Answer::Number(number.try_into().unwrap())
+	}
+}
+
+impl From<String> for Answer
+{
+	fn from(text: String) -> Answer
+	{
+		Answer::Text(text)
+	}
+}
+
+pub type Parsed = Box<dyn Any + Send>;
+
+// Object-safe counterpart of Solution, so that the runner can keep all days
+// in a single table.
+pub trait AnySolution: Sync
+{
+	fn parse(&self, input: &str) -> Result<Parsed, ParseError>;
+	fn one(&self, parsed: &Parsed) -> Option<Answer>;
+	fn two(&self, parsed: &Parsed) -> Option<Answer>;
+	fn has_part_two(&self) -> bool;
+	fn variant_names(&self, part: Part) -> Vec<&'static str>;
+	fn solve_variant(
+		&self,
+		name: &str,
+		part: Part,
+		parsed: &Parsed,
+	) -> Option<Answer>;
+}
+
+impl<S> AnySolution for S
+where
+	S: Solution + Sync,
+{
+	fn parse(&self, input: &str) -> Result<Parsed, ParseError>
+	{
+		let parsed = Solution::parse(self, input)?;
+		Ok(Box::new(parsed))
+	}
+
+	fn one(&self, parsed: &Parsed) -> Option<Answer>
+	{
+		Solution::one(self, downcast::<S>(parsed))
+	}
+
+	fn two(&self, parsed: &Parsed) -> Option<Answer>
+	{
+		Solution::two(self, downcast::<S>(parsed))
+	}
+
+	fn has_part_two(&self) -> bool
+	{
+		Solution::has_part_two(self)
+	}
+
+	fn variant_names(&self, part: Part) -> Vec<&'static str>
+	{
+		self.variants()
+			.into_iter()
+			.filter(|variant| variant.part == part)
+			.map(|variant| variant.name)
+			.collect()
+	}
+
+	fn solve_variant(
+		&self,
+		name: &str,
+		part: Part,
+		parsed: &Parsed,
+	) -> Option<Answer>
+	{
+		let variant = self
+			.variants()
+			.into_iter()
+			.find(|variant| variant.part == part && variant.name == name)?;
+		(variant.solve)(downcast::<S>(parsed))
+	}
+}
+
+fn downcast<S: Solution>(parsed: &Parsed) -> &S::Input
+{
+	parsed
+		.downcast_ref()
+		.expect("input was parsed by a different solution")
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn display_numbers_and_text()
+	{
+		assert_eq!(Answer::from(24000).to_string(), "24000");
+		assert_eq!(Answer::from(1514285714288u64).to_string(), "1514285714288");
+		assert_eq!(Answer::from("CMZ".to_string()).to_string(), "CMZ");
+	}
+
+	#[test]
+	fn display_art_without_surrounding_newlines()
+	{
+		let answer = Answer::Art("\n##..\n#..#\n".to_string());
+		assert!(answer.is_multiline());
+		assert_eq!(answer.to_string(), "##..\n#..#");
+	}
+
+	#[test]
+	fn read_letters_from_art()
+	{
+		let art = ".##.\n#..#\n#..#\n####\n#..#\n#..#\n".to_string();
+		let answer = Answer::from_art(art.clone(), '#');
+		assert!(!answer.is_multiline());
+		assert_eq!(answer.to_string(), "A");
+		assert_eq!(answer.art(), Some(art.as_str()));
+		let answer = Answer::from_art("#.\n.#\n".to_string(), '#');
+		assert_eq!(answer, Answer::Art("#.\n.#\n".to_string()));
+	}
+
+	#[test]
+	fn solve_with_variants()
+	{
+		let solution: &dyn AnySolution = &crate::day20::Solver;
+		let parsed = solution.parse("1\n2\n-3\n3\n-2\n0\n4\n").unwrap();
+		assert_eq!(solution.variant_names(Part::One), ["naive"]);
+		let naive = solution.solve_variant("naive", Part::One, &parsed);
+		assert_eq!(naive, solution.one(&parsed));
+		assert_eq!(solution.solve_variant("fast", Part::One, &parsed), None);
+	}
+}
@@ -0,0 +1,95 @@
+/**/
+
+use crate::parse::parse_lines;
+use crate::Answer;
+use crate::ParseError;
+
+pub fn one(input: &str) -> i32
+{
+	part_one(&parse(input).unwrap())
+}
+
+pub fn two(input: &str) -> i32
+{
+	part_two(&parse(input).unwrap())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver
+{
+	type Input = Vec<String>;
+
+	fn parse(&self, input: &str) -> Result<Vec<String>, ParseError>
+	{
+		parse(input)
+	}
+
+	fn one(&self, lines: &Vec<String>) -> Option<Answer>
+	{
+		Some(part_one(lines).into())
+	}
+
+	fn two(&self, lines: &Vec<String>) -> Option<Answer>
+	{
+		Some(part_two(lines).into())
+	}
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError>
+{
+	parse_lines(input)
+}
+
+fn part_one(_lines: &[String]) -> i32
+{
+	0
+}
+
+fn part_two(_lines: &[String]) -> i32
+{
+	0
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::verify;
+	use crate::verify::Key;
+	use crate::verify::Verdict;
+	use crate::Part;
+	use pretty_assertions::assert_eq;
+
+	const PROVIDED: &str = include_str!("provided.txt");
+
+	#[test]
+	fn one_provided()
+	{
+		check_provided(Part::One, one(PROVIDED).into());
+	}
+
+	#[test]
+	fn two_provided()
+	{
+		check_provided(Part::Two, two(PROVIDED).into());
+	}
+
+	// Paste the example into provided.txt, and once the answers match the
+	// puzzle, record them with "aoc run NAME --input src/NAME/provided.txt
+	// --record --answers src/answers.txt". Until then, anything goes.
+	fn check_provided(part: Part, answer: Answer)
+	{
+		if PROVIDED.is_empty()
+		{
+			return;
+		}
+		let name = module_path!().split("::").nth(1).unwrap();
+		let key = Key::new(name, part, PROVIDED);
+		let verdict = verify::check_in_source_tree(&key, &answer).unwrap();
+		if let Verdict::Fail { expected } = verdict
+		{
+			assert_eq!(answer.to_string(), expected);
+		}
+	}
+}
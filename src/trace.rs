@@ -0,0 +1,118 @@
+/**/
+
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::Ordering;
+
+/// How much the solvers report on stderr while they work, from nothing at
+/// all to everything.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(parse_display::Display, parse_display::FromStr)]
+#[display(style = "lowercase")]
+pub enum Level
+{
+	#[default]
+	Off,
+	// A few lines per puzzle, such as intermediate results.
+	Info,
+	// Progress within a puzzle, such as every improvement of a bound.
+	Debug,
+	// Everything, including renders of the state after every step.
+	Trace,
+}
+
+/// The environment variable that sets the level, unless overridden.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+pub fn set_level(level: Level)
+{
+	LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level
+{
+	match LEVEL.load(Ordering::Relaxed)
+	{
+		0 => Level::Off,
+		1 => Level::Info,
+		2 => Level::Debug,
+		_ => Level::Trace,
+	}
+}
+
+pub fn is_enabled(level: Level) -> bool
+{
+	level != Level::Off && level <= self::level()
+}
+
+/// Reads the level from the environment, if it is set.
+pub fn level_from_env() -> Result<Option<Level>, String>
+{
+	match std::env::var(ENV_VAR)
+	{
+		Ok(value) => value
+			.parse()
+			.map(Some)
+			.map_err(|_| format!("invalid {} '{}'", ENV_VAR, value)),
+		Err(_) => Ok(None),
+	}
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, message: std::fmt::Arguments)
+{
+	let module = module.strip_prefix("aoc2022::").unwrap_or(module);
+	// Renders of grids end in a newline of their own.
+	let message = message.to_string();
+	eprintln!("[{} {}] {}", level, module, message.trim_end());
+}
+
+/// Writes a message to stderr if tracing is enabled at the given level. The
+/// arguments are not evaluated otherwise.
+#[macro_export]
+macro_rules! log {
+	($level:expr, $($arg:tt)+) => {
+		if $crate::trace::is_enabled($level)
+		{
+			$crate::trace::write($level, module_path!(), format_args!($($arg)+));
+		}
+	};
+}
+
+#[macro_export]
+macro_rules! info {
+	($($arg:tt)+) => {
+		$crate::log!($crate::trace::Level::Info, $($arg)+)
+	};
+}
+
+#[macro_export]
+macro_rules! debug {
+	($($arg:tt)+) => {
+		$crate::log!($crate::trace::Level::Debug, $($arg)+)
+	};
+}
+
+#[macro_export]
+macro_rules! trace {
+	($($arg:tt)+) => {
+		$crate::log!($crate::trace::Level::Trace, $($arg)+)
+	};
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn parse_levels()
+	{
+		assert_eq!("debug".parse().ok(), Some(Level::Debug));
+		assert_eq!(Level::Trace.to_string(), "trace");
+		assert!("verbose".parse::<Level>().is_err());
+		assert!(Level::Off < Level::Info && Level::Debug < Level::Trace);
+	}
+}
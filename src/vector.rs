@@ -0,0 +1,381 @@
+/**/
+
+use crate::grid::Position;
+
+/// A position or offset in the plane. As in the puzzle inputs, x grows to
+/// the right and y grows downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(parse_display::Display, parse_display::FromStr)]
+#[display("{x},{y}")]
+pub struct Vec2
+{
+	pub x: i32,
+	pub y: i32,
+}
+
+impl Vec2
+{
+	pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+	pub const fn new(x: i32, y: i32) -> Vec2
+	{
+		Vec2 { x, y }
+	}
+
+	pub fn manhattan_distance(self, other: Vec2) -> i32
+	{
+		(other.x - self.x).abs() + (other.y - self.y).abs()
+	}
+
+	/// The number of king moves, where diagonal steps count as one.
+	pub fn chebyshev_distance(self, other: Vec2) -> i32
+	{
+		std::cmp::max((other.x - self.x).abs(), (other.y - self.y).abs())
+	}
+
+	/// A step of at most one in each direction, towards this offset.
+	pub fn signum(self) -> Vec2
+	{
+		Vec2::new(self.x.signum(), self.y.signum())
+	}
+
+	/// Rotates a quarter turn clockwise around the origin, as seen on screen.
+	pub fn rotate_clockwise(self) -> Vec2
+	{
+		Vec2::new(-self.y, self.x)
+	}
+
+	pub fn rotate_counterclockwise(self) -> Vec2
+	{
+		Vec2::new(self.y, -self.x)
+	}
+
+	/// The row and column in a grid, unless either is negative.
+	pub fn to_position(self) -> Option<Position>
+	{
+		let r = usize::try_from(self.y).ok()?;
+		let c = usize::try_from(self.x).ok()?;
+		Some((r, c))
+	}
+}
+
+impl std::ops::Add for Vec2
+{
+	type Output = Vec2;
+
+	fn add(self, rhs: Vec2) -> Vec2
+	{
+		Vec2::new(self.x + rhs.x, self.y + rhs.y)
+	}
+}
+
+impl std::ops::Sub for Vec2
+{
+	type Output = Vec2;
+
+	fn sub(self, rhs: Vec2) -> Vec2
+	{
+		Vec2::new(self.x - rhs.x, self.y - rhs.y)
+	}
+}
+
+impl std::ops::Mul<i32> for Vec2
+{
+	type Output = Vec2;
+
+	fn mul(self, rhs: i32) -> Vec2
+	{
+		Vec2::new(self.x * rhs, self.y * rhs)
+	}
+}
+
+impl std::ops::Neg for Vec2
+{
+	type Output = Vec2;
+
+	fn neg(self) -> Vec2
+	{
+		Vec2::new(-self.x, -self.y)
+	}
+}
+
+impl std::ops::AddAssign for Vec2
+{
+	fn add_assign(&mut self, rhs: Vec2)
+	{
+		*self = *self + rhs;
+	}
+}
+
+impl std::ops::SubAssign for Vec2
+{
+	fn sub_assign(&mut self, rhs: Vec2)
+	{
+		*self = *self - rhs;
+	}
+}
+
+/// A position or offset in space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(parse_display::Display, parse_display::FromStr)]
+#[display("{x},{y},{z}")]
+pub struct Vec3
+{
+	pub x: i32,
+	pub y: i32,
+	pub z: i32,
+}
+
+impl Vec3
+{
+	pub const ZERO: Vec3 = Vec3 { x: 0, y: 0, z: 0 };
+
+	pub const fn new(x: i32, y: i32, z: i32) -> Vec3
+	{
+		Vec3 { x, y, z }
+	}
+
+	pub fn manhattan_distance(self, other: Vec3) -> i32
+	{
+		(other.x - self.x).abs()
+			+ (other.y - self.y).abs()
+			+ (other.z - self.z).abs()
+	}
+
+	pub fn chebyshev_distance(self, other: Vec3) -> i32
+	{
+		(other.x - self.x)
+			.abs()
+			.max((other.y - self.y).abs())
+			.max((other.z - self.z).abs())
+	}
+
+	pub fn signum(self) -> Vec3
+	{
+		Vec3::new(self.x.signum(), self.y.signum(), self.z.signum())
+	}
+}
+
+impl std::ops::Add for Vec3
+{
+	type Output = Vec3;
+
+	fn add(self, rhs: Vec3) -> Vec3
+	{
+		Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+	}
+}
+
+impl std::ops::Sub for Vec3
+{
+	type Output = Vec3;
+
+	fn sub(self, rhs: Vec3) -> Vec3
+	{
+		Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+	}
+}
+
+impl std::ops::Mul<i32> for Vec3
+{
+	type Output = Vec3;
+
+	fn mul(self, rhs: i32) -> Vec3
+	{
+		Vec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+	}
+}
+
+impl std::ops::Neg for Vec3
+{
+	type Output = Vec3;
+
+	fn neg(self) -> Vec3
+	{
+		Vec3::new(-self.x, -self.y, -self.z)
+	}
+}
+
+impl std::ops::AddAssign for Vec3
+{
+	fn add_assign(&mut self, rhs: Vec3)
+	{
+		*self = *self + rhs;
+	}
+}
+
+impl std::ops::SubAssign for Vec3
+{
+	fn sub_assign(&mut self, rhs: Vec3)
+	{
+		*self = *self - rhs;
+	}
+}
+
+/// One of the four orthogonal directions, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(parse_display::Display, parse_display::FromStr)]
+pub enum Direction
+{
+	#[display("U")]
+	Up,
+	#[display("R")]
+	Right,
+	#[display("D")]
+	Down,
+	#[display("L")]
+	Left,
+}
+
+impl Direction
+{
+	pub const ALL: [Direction; 4] = [
+		Direction::Up,
+		Direction::Right,
+		Direction::Down,
+		Direction::Left,
+	];
+
+	pub fn offset(self) -> Vec2
+	{
+		match self
+		{
+			Direction::Up => Vec2::new(0, -1),
+			Direction::Right => Vec2::new(1, 0),
+			Direction::Down => Vec2::new(0, 1),
+			Direction::Left => Vec2::new(-1, 0),
+		}
+	}
+
+	pub fn turn_clockwise(self) -> Direction
+	{
+		Direction::ALL[(self as usize + 1) % 4]
+	}
+
+	pub fn turn_counterclockwise(self) -> Direction
+	{
+		Direction::ALL[(self as usize + 3) % 4]
+	}
+
+	pub fn reverse(self) -> Direction
+	{
+		Direction::ALL[(self as usize + 2) % 4]
+	}
+}
+
+/// One of the eight compass directions, clockwise from north (up).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading
+{
+	North,
+	NorthEast,
+	East,
+	SouthEast,
+	South,
+	SouthWest,
+	West,
+	NorthWest,
+}
+
+impl Heading
+{
+	pub const ALL: [Heading; 8] = [
+		Heading::North,
+		Heading::NorthEast,
+		Heading::East,
+		Heading::SouthEast,
+		Heading::South,
+		Heading::SouthWest,
+		Heading::West,
+		Heading::NorthWest,
+	];
+
+	pub fn offset(self) -> Vec2
+	{
+		match self
+		{
+			Heading::North => Vec2::new(0, -1),
+			Heading::NorthEast => Vec2::new(1, -1),
+			Heading::East => Vec2::new(1, 0),
+			Heading::SouthEast => Vec2::new(1, 1),
+			Heading::South => Vec2::new(0, 1),
+			Heading::SouthWest => Vec2::new(-1, 1),
+			Heading::West => Vec2::new(-1, 0),
+			Heading::NorthWest => Vec2::new(-1, -1),
+		}
+	}
+
+	/// Turns by some number of eighths of a full turn, which may be negative.
+	pub fn turn_clockwise(self, eighths: i32) -> Heading
+	{
+		Heading::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+	}
+}
+
+impl From<Direction> for Heading
+{
+	fn from(direction: Direction) -> Heading
+	{
+		Heading::ALL[2 * direction as usize]
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn parse_and_display()
+	{
+		let a: Vec2 = "498,-4".parse().unwrap();
+		assert_eq!(a, Vec2::new(498, -4));
+		assert_eq!(a.to_string(), "498,-4");
+		let b: Vec3 = "2,2,5".parse().unwrap();
+		assert_eq!(b, Vec3::new(2, 2, 5));
+		assert_eq!(b.to_string(), "2,2,5");
+		assert!("1,2,3".parse::<Vec2>().is_err());
+	}
+
+	#[test]
+	fn distances()
+	{
+		let a = Vec2::new(8, 7);
+		let b = Vec2::new(2, 10);
+		assert_eq!(a.manhattan_distance(b), 9);
+		assert_eq!(a.chebyshev_distance(b), 6);
+		assert_eq!((b - a).signum(), Vec2::new(-1, 1));
+		let c = Vec3::new(1, 1, 1);
+		assert_eq!(c.manhattan_distance(Vec3::new(3, 0, 1)), 3);
+		assert_eq!(c.chebyshev_distance(Vec3::new(3, 0, 1)), 2);
+	}
+
+	#[test]
+	fn rotations_match_directions()
+	{
+		for direction in Direction::ALL
+		{
+			let offset = direction.offset();
+			assert_eq!(
+				offset.rotate_clockwise(),
+				direction.turn_clockwise().offset()
+			);
+			assert_eq!(
+				offset.rotate_counterclockwise(),
+				direction.turn_counterclockwise().offset()
+			);
+			assert_eq!(-offset, direction.reverse().offset());
+			assert_eq!(Heading::from(direction).offset(), offset);
+		}
+		assert_eq!(Heading::North.turn_clockwise(3), Heading::SouthEast);
+		assert_eq!(Heading::North.turn_clockwise(-10), Heading::West);
+	}
+
+	#[test]
+	fn grid_positions()
+	{
+		assert_eq!(Vec2::new(500, 3).to_position(), Some((3, 500)));
+		assert_eq!(Vec2::new(-1, 3).to_position(), None);
+	}
+}
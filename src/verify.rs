@@ -0,0 +1,293 @@
+/**/
+
+use crate::Answer;
+use crate::ParseError;
+use crate::Part;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Name of the answers file that is kept next to the puzzle inputs.
+pub const ANSWERS_FILENAME: &str = "answers.txt";
+
+// Answers are recorded per input, so that the answers for the examples and
+// for the real input of each day can live side by side.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key
+{
+	pub day: String,
+	pub part: Part,
+	pub input_hash: u64,
+}
+
+impl Key
+{
+	pub fn new(day: &str, part: Part, input: &str) -> Key
+	{
+		Key {
+			day: day.to_string(),
+			part,
+			input_hash: input_hash(input),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict
+{
+	Pass,
+	Fail
+	{
+		expected: String,
+	},
+	Unknown,
+}
+
+/// The known-correct answers, stored one per line as
+/// "day16<TAB>1<TAB><input hash><TAB><answer>".
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers
+{
+	recorded: BTreeMap<Key, String>,
+}
+
+impl Answers
+{
+	/// Loads the answers file, where a missing file has no answers.
+	pub fn load(path: &Path) -> Result<Answers, String>
+	{
+		match std::fs::read_to_string(path)
+		{
+			Ok(text) => text.parse().map_err(|error: ParseError| {
+				format!("{}: {}", path.display(), error)
+			}),
+			Err(error) if error.kind() == std::io::ErrorKind::NotFound =>
+			{
+				Ok(Answers::default())
+			}
+			Err(error) =>
+			{
+				Err(format!("cannot read {}: {}", path.display(), error))
+			}
+		}
+	}
+
+	pub fn save(&self, path: &Path) -> Result<(), String>
+	{
+		std::fs::write(path, self.to_string()).map_err(|error| {
+			format!("cannot write {}: {}", path.display(), error)
+		})
+	}
+
+	pub fn check(&self, key: &Key, answer: &Answer) -> Verdict
+	{
+		match self.recorded.get(key)
+		{
+			Some(expected) if *expected == escape(answer) => Verdict::Pass,
+			// Answers recorded before their letters could be read.
+			Some(expected)
+				if answer.art().is_some_and(|art| {
+					*expected == escape(&Answer::Art(art.to_string()))
+				}) =>
+			{
+				Verdict::Pass
+			}
+			Some(expected) => Verdict::Fail {
+				expected: unescape(expected),
+			},
+			None => Verdict::Unknown,
+		}
+	}
+
+	pub fn record(&mut self, key: Key, answer: &Answer)
+	{
+		self.recorded.insert(key, escape(answer));
+	}
+}
+
+impl std::str::FromStr for Answers
+{
+	type Err = ParseError;
+
+	fn from_str(input: &str) -> Result<Answers, ParseError>
+	{
+		let mut answers = Answers::default();
+		for (i, line) in input.lines().enumerate()
+		{
+			if line.is_empty() || line.starts_with('#')
+			{
+				continue;
+			}
+			let fields: Vec<&str> = line.splitn(4, '\t').collect();
+			if fields.len() != 4
+			{
+				return Err(ParseError::at_line(i, line, "expected 4 fields"));
+			}
+			let part = match fields[1]
+			{
+				"1" => Part::One,
+				"2" => Part::Two,
+				_ =>
+				{
+					let j = fields[0].len() + 1;
+					return Err(ParseError::at_byte(i, j, line));
+				}
+			};
+			let input_hash =
+				u64::from_str_radix(fields[2], 16).map_err(|_| {
+					let column = fields[0].len() + fields[1].len() + 3;
+					ParseError::new(i + 1, column, line, "invalid hash")
+				})?;
+			let key = Key {
+				day: fields[0].to_string(),
+				part,
+				input_hash,
+			};
+			answers.recorded.insert(key, fields[3].to_string());
+		}
+		Ok(answers)
+	}
+}
+
+impl std::fmt::Display for Answers
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+	{
+		for (key, answer) in self.recorded.iter()
+		{
+			writeln!(
+				f,
+				"{}\t{}\t{:016x}\t{}",
+				key.day,
+				key.part.number(),
+				key.input_hash,
+				answer
+			)?;
+		}
+		Ok(())
+	}
+}
+
+/// Checks an answer against the answers file in the source tree, which is
+/// where the tests of a new puzzle find the answers for its example.
+pub fn check_in_source_tree(
+	key: &Key,
+	answer: &Answer,
+) -> Result<Verdict, String>
+{
+	let path = crate::input::default_dir().join(ANSWERS_FILENAME);
+	let answers = Answers::load(&path)?;
+	Ok(answers.check(key, answer))
+}
+
+/// A 64-bit FNV-1a hash, which unlike the std hashers is stable across
+/// releases.
+pub fn input_hash(input: &str) -> u64
+{
+	let mut hash = 0xcbf29ce484222325u64;
+	for byte in input.bytes()
+	{
+		hash ^= u64::from(byte);
+		hash = hash.wrapping_mul(0x100000001b3);
+	}
+	hash
+}
+
+// Keep each answer on a single line, even the ASCII art.
+fn escape(answer: &Answer) -> String
+{
+	answer
+		.to_string()
+		.replace('\\', "\\\\")
+		.replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> String
+{
+	let mut unescaped = String::with_capacity(text.len());
+	let mut chars = text.chars();
+	while let Some(x) = chars.next()
+	{
+		match x
+		{
+			'\\' => match chars.next()
+			{
+				Some('n') => unescaped.push('\n'),
+				Some(y) => unescaped.push(y),
+				None => unescaped.push(x),
+			},
+			_ => unescaped.push(x),
+		}
+	}
+	unescaped
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn hash_is_fnv1a()
+	{
+		assert_eq!(input_hash(""), 0xcbf29ce484222325);
+		assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+	}
+
+	#[test]
+	fn check_recorded_answers()
+	{
+		let mut answers = Answers::default();
+		let key = Key::new("day01", Part::One, "1000\n2000\n");
+		let other = Key::new("day01", Part::One, "3000\n");
+		answers.record(key.clone(), &Answer::Number(3000));
+		assert_eq!(answers.check(&key, &Answer::Number(3000)), Verdict::Pass);
+		assert_eq!(
+			answers.check(&key, &Answer::Number(2000)),
+			Verdict::Fail {
+				expected: "3000".to_string()
+			}
+		);
+		assert_eq!(
+			answers.check(&other, &Answer::Number(3000)),
+			Verdict::Unknown
+		);
+	}
+
+	#[test]
+	fn round_trip_art()
+	{
+		let mut answers = Answers::default();
+		let key = Key::new("day10", Part::Two, "noop\n");
+		let art = Answer::Art("#..\\\n.##\n".to_string());
+		answers.record(key.clone(), &art);
+		let text = answers.to_string();
+		assert_eq!(text.lines().count(), 1);
+		let parsed: Answers = text.parse().unwrap();
+		assert_eq!(parsed, answers);
+		assert_eq!(parsed.check(&key, &art), Verdict::Pass);
+	}
+
+	#[test]
+	fn check_letters_against_recorded_art()
+	{
+		let mut answers = Answers::default();
+		let key = Key::new("day10", Part::Two, "noop\n");
+		let art = "#..#\n#..#\n####\n#..#\n#..#\n#..#\n".to_string();
+		answers.record(key.clone(), &Answer::Art(art.clone()));
+		let letters = Answer::from_art(art, '#');
+		assert_eq!(letters.to_string(), "H");
+		assert_eq!(answers.check(&key, &letters), Verdict::Pass);
+	}
+
+	#[test]
+	fn parse_invalid_part()
+	{
+		let error = "day01\t3\t0\t24000".parse::<Answers>().unwrap_err();
+		assert_eq!(
+			error,
+			ParseError::new(1, 7, "day01\t3\t0\t24000", "unexpected '3'")
+		);
+	}
+}